@@ -0,0 +1,279 @@
+// This file is part of `i18n_cli-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_cli-rizzen-yazston` crate.
+
+//! Conversion between the Sqlite3 catalogue format and the text catalogue format.
+//!
+//! The text catalogue format is line based, and is intended to be used with version control systems and ordinary
+//! text editors. The first line identifies the format and schema version, followed by a section for each table of
+//! the Sqlite3 schema. A section starts with the table name enclosed in square brackets, followed by a comment line
+//! listing the columns, then a line for each row. Columns are separated by tab characters (shown as `<TAB>`):
+//!
+//! ```text
+//! # i18n text catalogue: schema 1.0
+//! [language]
+//! # tag<TAB>englishName<TAB>added
+//! en-US<TAB>English (United States)<TAB>2023-11-13
+//! ```
+//!
+//! Within a column the backslash (`\`), tab, carriage return and line feed characters are escaped as `\\`, `\t`,
+//! `\r` and `\n` respectively, and a `NULL` value is written as `\N`. Empty lines and lines starting with `#` are
+//! ignored.
+
+use crate::CliError;
+use i18n_provider_sqlite3::verify_schema;
+use rusqlite::{params_from_iter, types::Value, Connection, OpenFlags};
+use std::error::Error;
+use std::fs::{read_to_string, remove_file, rename, write};
+use std::path::{Path, PathBuf};
+
+const HEADER: &str = "# i18n text catalogue: schema ";
+
+// Tables in the order satisfying the foreign key references.
+const TABLES: [(&str, &[&str]); 6] = [
+    ("language", &["tag", "englishName", "added"]),
+    (
+        "component",
+        &["identifier", "languageTag", "comment", "added"],
+    ),
+    (
+        "contributor",
+        &[
+            "component",
+            "languageTag",
+            "contributor",
+            "substituteFor",
+            "comment",
+            "verified",
+        ],
+    ),
+    (
+        "languageData",
+        &["component", "languageTag", "count", "ratio"],
+    ),
+    (
+        "metadata",
+        &["component", "key", "value", "comment", "verified"],
+    ),
+    (
+        "pattern",
+        &[
+            "component",
+            "identifier",
+            "languageTag",
+            "string",
+            "comment",
+            "verified",
+        ],
+    ),
+];
+
+// Schema 1.0, see `schema.sql` in the `l10n` directory of the `i18n_provider_sqlite3-rizzen-yazston` crate.
+const SCHEMA: &str = "\
+CREATE TABLE component (rowID INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, identifier TEXT UNIQUE NOT NULL, \
+languageTag TEXT REFERENCES language (tag) ON DELETE RESTRICT ON UPDATE NO ACTION NOT NULL, comment TEXT, \
+added DATE NOT NULL);
+CREATE TABLE contributor (rowID INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, component TEXT NOT NULL REFERENCES \
+component (identifier) ON DELETE RESTRICT ON UPDATE NO ACTION, languageTag TEXT NOT NULL REFERENCES language (tag) \
+ON DELETE RESTRICT ON UPDATE NO ACTION, contributor TEXT NOT NULL, substituteFor TEXT, comment TEXT, verified DATE, \
+UNIQUE (component, languageTag, contributor));
+CREATE TABLE language (rowID INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, tag TEXT UNIQUE NOT NULL, englishName TEXT, \
+added DATE NOT NULL);
+CREATE TABLE languageData (rowID INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, component TEXT NOT NULL REFERENCES \
+component (identifier) ON DELETE RESTRICT ON UPDATE NO ACTION, languageTag TEXT NOT NULL REFERENCES language (tag) \
+ON DELETE RESTRICT ON UPDATE NO ACTION, count INTEGER NOT NULL, ratio REAL NOT NULL, UNIQUE (component, languageTag));
+CREATE TABLE metadata (rowID INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, component TEXT NOT NULL REFERENCES \
+component (identifier) ON DELETE RESTRICT ON UPDATE NO ACTION, key TEXT NOT NULL, value TEXT NOT NULL, comment TEXT, \
+verified DATE, UNIQUE (component, key));
+CREATE TABLE pattern (rowID INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, component TEXT NOT NULL REFERENCES \
+component (identifier) ON DELETE RESTRICT ON UPDATE NO ACTION, identifier TEXT NOT NULL, languageTag TEXT NOT NULL \
+REFERENCES language (tag) ON DELETE RESTRICT ON UPDATE NO ACTION, string TEXT NOT NULL, comment TEXT, verified DATE, \
+CONSTRAINT u_IdentierLanguage UNIQUE (component, identifier, languageTag));
+";
+
+/// Exports the Sqlite3 file `sqlite3` into the text catalogue file `text`, which must not exist.
+pub fn export(sqlite3: &Path, text: &Path) -> Result<(), Box<dyn Error>> {
+    if text.exists() {
+        return Err(Box::new(CliError::FileExists(text.to_path_buf())));
+    }
+    let connection = Connection::open_with_flags(sqlite3, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let schema_version = verify_schema(&connection)?;
+    let mut catalogue = format!("{}{}\n", HEADER, schema_version);
+    for (table, columns) in TABLES.iter() {
+        catalogue.push_str(format!("[{}]\n# {}\n", table, columns.join("\t")).as_str());
+        let mut statement = connection.prepare(
+            format!(
+                "SELECT {} FROM {} ORDER BY rowID",
+                columns.join(", "),
+                table
+            )
+            .as_str(),
+        )?;
+        let mut rows = statement.query([])?;
+        while let Some(row) = rows.next()? {
+            let mut fields = Vec::<String>::new();
+            for index in 0..columns.len() {
+                fields.push(match row.get::<usize, Value>(index)? {
+                    Value::Null => "\\N".to_string(),
+                    Value::Integer(value) => value.to_string(),
+                    Value::Real(value) => value.to_string(),
+                    Value::Text(value) => escape(value.as_str()),
+                    Value::Blob(value) => escape(String::from_utf8_lossy(&value).as_ref()),
+                });
+            }
+            catalogue.push_str(fields.join("\t").as_str());
+            catalogue.push('\n');
+        }
+    }
+    write(text, catalogue)?;
+    Ok(())
+}
+
+/// Imports the text catalogue file `text` into the new Sqlite3 file `sqlite3`, which must not exist.
+///
+/// The catalogue is imported into a temporary file `<sqlite3>.partial` of the same directory, that is verified using
+/// the provider's `verify_schema()` function, then renamed to `sqlite3`. Thus should an error occur, no file is left
+/// behind.
+pub fn import(text: &Path, sqlite3: &Path) -> Result<(), Box<dyn Error>> {
+    if sqlite3.exists() {
+        return Err(Box::new(CliError::FileExists(sqlite3.to_path_buf())));
+    }
+    let mut partial = sqlite3.as_os_str().to_os_string();
+    partial.push(".partial");
+    let partial = PathBuf::from(partial);
+
+    // A temporary file left by an interrupted import is replaced.
+    if partial.exists() {
+        remove_file(&partial)?;
+    }
+    match import_file(text, &partial) {
+        Ok(()) => {
+            if sqlite3.exists() {
+                remove_file(&partial)?;
+                return Err(Box::new(CliError::FileExists(sqlite3.to_path_buf())));
+            }
+            if let Err(error) = rename(&partial, sqlite3) {
+                let _ = remove_file(&partial);
+                return Err(Box::new(error));
+            }
+            Ok(())
+        }
+        Err(error) => {
+            let _ = remove_file(&partial);
+            Err(error)
+        }
+    }
+}
+
+// Internal functions.
+
+// Import into the Sqlite3 file, where the connection is closed on returning.
+fn import_file(text: &Path, sqlite3: &Path) -> Result<(), Box<dyn Error>> {
+    let catalogue = read_to_string(text)?;
+    let mut lines = catalogue.lines().enumerate();
+    match lines.next() {
+        Some((_, line)) if line == format!("{}1.0", HEADER) => {}
+        _ => {
+            return Err(Box::new(CliError::TextFormat(
+                1,
+                format!("Expected the header ‘{}1.0’.", HEADER),
+            )))
+        }
+    }
+    let mut connection = Connection::open(sqlite3)?;
+    let transaction = connection.transaction()?;
+    transaction.execute_batch(SCHEMA)?;
+    let mut table: Option<(&str, &[&str])> = None;
+    for (index, line) in lines {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = line
+            .strip_prefix('[')
+            .and_then(|value| value.strip_suffix(']'))
+        {
+            let Some(found) = TABLES.iter().find(|(table, _)| *table == name) else {
+                return Err(Box::new(CliError::TextFormat(
+                    index + 1,
+                    format!("Unknown table ‘{}’.", name),
+                )));
+            };
+            table = Some(*found);
+            continue;
+        }
+        let Some((name, columns)) = table else {
+            return Err(Box::new(CliError::TextFormat(
+                index + 1,
+                "Row found before a table section.".to_string(),
+            )));
+        };
+        let mut values = Vec::<Option<String>>::new();
+        for field in line.split('\t') {
+            values.push(unescape(field).map_err(|reason| CliError::TextFormat(index + 1, reason))?);
+        }
+        if values.len() != columns.len() {
+            return Err(Box::new(CliError::TextFormat(
+                index + 1,
+                format!(
+                    "Expected {} columns for the table ‘{}’, found {}.",
+                    columns.len(),
+                    name,
+                    values.len()
+                ),
+            )));
+        }
+        let placeholders = (1..=columns.len())
+            .map(|position| format!("?{}", position))
+            .collect::<Vec<String>>();
+        transaction
+            .prepare_cached(
+                format!(
+                    "INSERT INTO {} ({}) VALUES ({})",
+                    name,
+                    columns.join(", "),
+                    placeholders.join(", ")
+                )
+                .as_str(),
+            )?
+            .execute(params_from_iter(values.iter()))?;
+    }
+    transaction.commit()?;
+    verify_schema(&connection)?;
+    Ok(())
+}
+
+fn escape(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for character in field.chars() {
+        match character {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+fn unescape(field: &str) -> Result<Option<String>, String> {
+    if field == "\\N" {
+        return Ok(None);
+    }
+    let mut unescaped = String::with_capacity(field.len());
+    let mut characters = field.chars();
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            unescaped.push(character);
+            continue;
+        }
+        match characters.next() {
+            Some('\\') => unescaped.push('\\'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some('n') => unescaped.push('\n'),
+            Some(other) => return Err(format!("Invalid escape sequence ‘\\{}’.", other)),
+            None => return Err("Incomplete escape sequence at end of column.".to_string()),
+        }
+    }
+    Ok(Some(unescaped))
+}
@@ -0,0 +1,52 @@
+// This file is part of `i18n_cli-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_cli-rizzen-yazston` crate.
+
+use std::error::Error;
+use std::path::PathBuf;
+
+use core::fmt::{Display, Formatter, Result};
+
+/// The `CliError` type consists of the follow:
+///
+/// * `Usage`: Indicates the command line arguments are invalid, containing the reason,
+///
+/// * `UnknownCommand`: Indicates the command is not supported,
+///
+/// * `FileExists`: Indicates the output file already exists, and will not be overwritten,
+///
+/// * `TextFormat`: Indicates an error in the text catalogue at the specified line.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum CliError {
+    Usage(String),
+    UnknownCommand(String),
+    FileExists(PathBuf),
+    TextFormat(usize, String), // line, reason
+}
+
+impl Display for CliError {
+    fn fmt(&self, formatter: &mut Formatter) -> Result {
+        match self {
+            CliError::Usage(reason) => {
+                write!(formatter, "CliError::Usage: {} See ‘i18n help’.", reason)
+            }
+            CliError::UnknownCommand(command) => write!(
+                formatter,
+                "CliError::UnknownCommand: The command ‘{}’ is not supported. See ‘i18n help’.",
+                command
+            ),
+            CliError::FileExists(path) => write!(
+                formatter,
+                "CliError::FileExists: The file ‘{}’ already exists.",
+                path.display()
+            ),
+            CliError::TextFormat(line, reason) => write!(
+                formatter,
+                "CliError::TextFormat: Line {} of the text catalogue: {}",
+                line, reason
+            ),
+        }
+    }
+}
+
+impl Error for CliError {}
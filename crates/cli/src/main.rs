@@ -0,0 +1,356 @@
+// This file is part of `i18n_cli-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_cli-rizzen-yazston` crate.
+
+//! The `i18n` command line tool for maintaining the localisation catalogues of the `i18n_provider_sqlite3` provider.
+//!
+//! Usage: `i18n [--path <directory>] <command> [arguments]`, where the `--path` (or `-p`) option is the directory
+//! containing the Sqlite3 files, defaulting to `./l10n/`. The commands are:
+//!
+//! * `components`: List the components of the repository,
+//!
+//! * `identifiers <component>`: List the identifiers of the component,
+//!
+//! * `details [component]`: Show the details of the repository, or of the component,
+//!
+//! * `coverage [--csv] [language ...]`: Show the translation coverage report as JSON (default) or CSV,
+//!
+//! * `validate [component]`: Check the patterns of all the languages against the default language, exits with a
+//!   failure status when mismatches are found,
+//!
+//! * `render <component> <identifier> <language> [name=value ...]`: Render a string with sample values, where
+//!   values are treated as integers, then floats, otherwise as strings,
+//!
+//! * `extract [--json] <source directory>`: Extract the localisation string references of the Rust source code,
+//!   and compare them with the catalogue, exits with a failure status when references are missing from the
+//!   catalogue or catalogue entries are unused,
+//!
+//! * `export <sqlite3 file> <text file>`: Convert a Sqlite3 file into the text catalogue format,
+//!
+//! * `import <text file> <sqlite3 file>`: Convert a text catalogue into a new Sqlite3 file,
+//!
+//! * `help`: Show the usage.
+
+mod convert;
+mod error;
+
+use error::*;
+
+use i18n_extractor::{Manifest, ManifestReport};
+use i18n_lexer::{DataProvider, IcuDataProvider};
+use i18n_localiser::{CommandRegistry, Localiser, PatternChecker};
+use i18n_provider::{CoverageReport, LanguageData, LocalisationProviderTrait, RepositoryDetails};
+use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+use i18n_utility::{LanguageTag, LanguageTagRegistry, PlaceholderValue};
+use std::collections::HashMap;
+use std::env::args;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as RefCount;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::Arc as RefCount;
+
+const USAGE: &str = "\
+Usage: i18n [--path <directory>] <command> [arguments]
+
+Options:
+  -p, --path <directory>  Directory containing the Sqlite3 files [default: ./l10n/]
+
+Commands:
+  components                                         List the components
+  identifiers <component>                            List the identifiers of a component
+  details [component]                                Show the repository or component details
+  coverage [--csv] [language ...]                    Show the translation coverage report
+  validate [component]                               Check the patterns against the default language
+  render <component> <identifier> <language> [name=value ...]
+                                                     Render a string with sample values
+  extract [--json] <source directory>                Compare the source code references with the catalogue
+  export <sqlite3 file> <text file>                  Convert a Sqlite3 file to the text format
+  import <text file> <sqlite3 file>                  Convert a text catalogue to a new Sqlite3 file
+  help                                               Show this usage";
+
+fn main() -> ExitCode {
+    match run(args().skip(1).collect()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::from(2)
+        }
+    }
+}
+
+// Returns `false` when the command completed, though found problems (such as validation mismatches).
+fn run(arguments: Vec<String>) -> Result<bool, Box<dyn Error>> {
+    let mut path = PathBuf::from("./l10n/");
+    let mut csv = false;
+    let mut json = false;
+    let mut positional = Vec::<String>::new();
+    let mut iterator = arguments.into_iter();
+    while let Some(argument) = iterator.next() {
+        match argument.as_str() {
+            "-p" | "--path" => {
+                let Some(value) = iterator.next() else {
+                    return Err(Box::new(CliError::Usage(
+                        "The option ‘--path’ requires a directory.".to_string(),
+                    )));
+                };
+                path = PathBuf::from(value);
+            }
+            "--csv" => csv = true,
+            "--json" => json = true,
+            "-h" | "--help" => positional.insert(0, "help".to_string()),
+            _ => positional.push(argument),
+        }
+    }
+    let Some(command) = positional.first().cloned() else {
+        return Err(Box::new(CliError::Usage(
+            "No command was provided.".to_string(),
+        )));
+    };
+    let parameters = &positional[1..];
+    match command.as_str() {
+        "components" => {
+            expect_parameters(parameters, 0, 0)?;
+            let (_registry, provider) = provider(&path)?;
+            let mut components = provider.repository_details()?.components.clone();
+            components.sort();
+            for component in components.iter() {
+                println!("{}", component);
+            }
+        }
+        "identifiers" => {
+            expect_parameters(parameters, 1, 1)?;
+            let (_registry, provider) = provider(&path)?;
+            for identifier in provider.identifiers(parameters[0].as_str())?.iter() {
+                println!("{}", identifier);
+            }
+        }
+        "details" => {
+            expect_parameters(parameters, 0, 1)?;
+            let (_registry, provider) = provider(&path)?;
+            match parameters.first() {
+                None => print_repository_details(&provider.repository_details()?),
+                Some(component) => {
+                    let details = provider.component_details(component.as_str())?;
+                    println!("Component: {}", component);
+                    println!("Default language: {}", details.default.as_str());
+                    println!("Total strings: {}", details.total_strings);
+                    print_languages(&details.languages);
+                }
+            }
+        }
+        "coverage" => {
+            let (registry, provider) = provider(&path)?;
+            let mut languages = Vec::<RefCount<LanguageTag>>::new();
+            for language in parameters.iter() {
+                languages.push(registry.tag(language.as_str())?);
+            }
+            let report = match languages.is_empty() {
+                true => CoverageReport::try_new(&provider, None)?,
+                false => CoverageReport::try_new(&provider, Some(&languages))?,
+            };
+            match csv {
+                true => print!("{}", report.to_csv()),
+                false => println!("{}", report.to_json()),
+            }
+        }
+        "validate" => {
+            expect_parameters(parameters, 0, 1)?;
+            let localiser = localiser(&path, None)?;
+            let checker = PatternChecker::new(&localiser);
+            let mut components = match parameters.first() {
+                None => localiser
+                    .localisation_provider()
+                    .repository_details()?
+                    .components
+                    .clone(),
+                Some(component) => vec![component.clone()],
+            };
+            components.sort();
+            let mut count = 0usize;
+            for component in components.iter() {
+                for mismatch in checker.check_component(component.as_str())?.iter() {
+                    println!("{}", mismatch);
+                    count += 1;
+                }
+            }
+            println!("{} mismatch(es) found.", count);
+            return Ok(count == 0);
+        }
+        "render" => {
+            if parameters.len() < 3 {
+                return Err(Box::new(CliError::Usage(
+                    "The command ‘render’ requires a component, an identifier and a language."
+                        .to_string(),
+                )));
+            }
+            let localiser = localiser(&path, Some(parameters[2].as_str()))?;
+            let mut values = HashMap::<String, PlaceholderValue>::new();
+            for parameter in parameters[3..].iter() {
+                let Some((name, value)) = parameter.split_once('=') else {
+                    return Err(Box::new(CliError::Usage(format!(
+                        "The value ‘{}’ is not of the form ‘name=value’.",
+                        parameter
+                    ))));
+                };
+                values.insert(name.to_string(), placeholder_value(value));
+            }
+            let (string, language_tag) = localiser.format(
+                parameters[0].as_str(),
+                parameters[1].as_str(),
+                &values,
+                &localiser.default_language(),
+                None,
+                None,
+            )?;
+            println!("{}", string);
+            if language_tag != localiser.default_language() {
+                eprintln!(
+                    "Note: The string is in the fallback language ‘{}’.",
+                    language_tag.as_str()
+                );
+            }
+        }
+        "extract" => {
+            expect_parameters(parameters, 1, 1)?;
+            let (_registry, provider) = provider(&path)?;
+            let mut manifest = Manifest::new();
+            manifest.add_directory(Path::new(&parameters[0]))?;
+            let report = ManifestReport::try_new(&manifest, &provider)?;
+            if json {
+                println!("{}", report.to_json());
+                return Ok(report.is_clean());
+            }
+            for reference in report.missing.iter() {
+                println!(
+                    "{}: Missing ‘{}’ of the component ‘{}’ in the catalogue.",
+                    reference.location, reference.identifier, reference.component
+                );
+            }
+            for (component, identifier) in report.unused.iter() {
+                println!(
+                    "Unused ‘{}’ of the component ‘{}’ in the catalogue.",
+                    identifier, component
+                );
+            }
+            for reference in manifest.unresolved.iter() {
+                println!(
+                    "{}: Unresolved ‘{}’ reference, the component or identifier is not a string literal.",
+                    reference.location, reference.kind
+                );
+            }
+            println!(
+                "{} reference(s), {} missing, {} unused, {} unresolved.",
+                manifest.references.len(),
+                report.missing.len(),
+                report.unused.len(),
+                manifest.unresolved.len()
+            );
+            return Ok(report.is_clean());
+        }
+        "export" => {
+            expect_parameters(parameters, 2, 2)?;
+            convert::export(Path::new(&parameters[0]), Path::new(&parameters[1]))?;
+        }
+        "import" => {
+            expect_parameters(parameters, 2, 2)?;
+            convert::import(Path::new(&parameters[0]), Path::new(&parameters[1]))?;
+        }
+        "help" => println!("{}", USAGE),
+        _ => return Err(Box::new(CliError::UnknownCommand(command))),
+    }
+    Ok(true)
+}
+
+// Internal functions.
+
+fn expect_parameters(
+    parameters: &[String],
+    minimum: usize,
+    maximum: usize,
+) -> Result<(), CliError> {
+    if parameters.len() < minimum || parameters.len() > maximum {
+        return Err(CliError::Usage(format!(
+            "Expected between {} and {} arguments for the command, found {}.",
+            minimum,
+            maximum,
+            parameters.len()
+        )));
+    }
+    Ok(())
+}
+
+fn provider(
+    path: &Path,
+) -> Result<(RefCount<LanguageTagRegistry>, LocalisationProviderSqlite3), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let provider = LocalisationProviderSqlite3::try_new(path.to_path_buf(), &registry, false)?;
+    Ok((registry, provider))
+}
+
+// When no language is specified, the repository's default language is used, else `en` if there is no default.
+fn localiser(path: &Path, language: Option<&str>) -> Result<Localiser, Box<dyn Error>> {
+    let (registry, provider) = provider(path)?;
+    let language = match language {
+        Some(value) => value.to_string(),
+        None => match provider.repository_details()?.default.as_ref() {
+            Some(value) => value.as_str().to_string(),
+            None => "en".to_string(),
+        },
+    };
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let command_registry = RefCount::new(CommandRegistry::new());
+    Ok(Localiser::try_new(
+        &icu_data_provider,
+        &registry,
+        Box::new(provider),
+        &command_registry,
+        true,
+        false,
+        language.as_str(),
+    )?)
+}
+
+fn placeholder_value(value: &str) -> PlaceholderValue {
+    if let Ok(integer) = value.parse::<i128>() {
+        return PlaceholderValue::Integer(integer);
+    }
+    if let Ok(float) = value.parse::<f64>() {
+        return PlaceholderValue::Float(float);
+    }
+    PlaceholderValue::String(value.to_string())
+}
+
+fn print_repository_details(details: &RefCount<RepositoryDetails>) {
+    match details.default.as_ref() {
+        Some(default) => println!("Default language: {}", default.as_str()),
+        None => println!("Default language: (none)"),
+    }
+    println!("Total strings: {}", details.total_strings);
+    let mut components = details.components.clone();
+    components.sort();
+    println!("Components: {}", components.join(", "));
+    println!("Contributors: {}", details.contributors.join(", "));
+    print_languages(&details.languages);
+}
+
+fn print_languages(languages: &HashMap<RefCount<LanguageTag>, LanguageData>) {
+    let mut tags = languages.keys().collect::<Vec<&RefCount<LanguageTag>>>();
+    tags.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    println!("Languages:");
+    for tag in tags {
+        let data = &languages[tag];
+        println!(
+            "  {}: {} string(s), ratio {:.2}, contributors: {}",
+            tag.as_str(),
+            data.count,
+            data.ratio,
+            data.contributors.join(", ")
+        );
+    }
+}
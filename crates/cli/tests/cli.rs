@@ -0,0 +1,113 @@
+// This file is part of `i18n_cli-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_cli-rizzen-yazston` crate.
+
+//! Testing the `i18n` command line tool.
+
+use std::env::temp_dir;
+use std::error::Error;
+use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+use std::process::{Command, Output};
+
+const PATH: &str = "../provider/sqlite3/l10n/";
+
+fn i18n(arguments: &[&str]) -> Result<Output, Box<dyn Error>> {
+    Ok(Command::new(env!("CARGO_BIN_EXE_i18n"))
+        .arg("--path")
+        .arg(PATH)
+        .args(arguments)
+        .output()?)
+}
+
+#[test]
+fn components() -> Result<(), Box<dyn Error>> {
+    let output = i18n(&["components"])?;
+    assert!(output.status.success(), "Command should succeed.");
+    assert_eq!(
+        String::from_utf8(output.stdout)?,
+        "application\ni18n_provider_sqlite3\n",
+        "Check components."
+    );
+    Ok(())
+}
+
+#[test]
+fn render() -> Result<(), Box<dyn Error>> {
+    let output = i18n(&["render", "application", "example_string", "en-US"])?;
+    assert!(output.status.success(), "Command should succeed.");
+    assert_eq!(
+        String::from_utf8(output.stdout)?,
+        "The color of the knight's armor is silver.\n",
+        "Check rendered string."
+    );
+    Ok(())
+}
+
+#[test]
+fn unknown_command() -> Result<(), Box<dyn Error>> {
+    let output = i18n(&["unknown"])?;
+    assert_eq!(output.status.code(), Some(2), "Should exit with status 2.");
+    Ok(())
+}
+
+#[test]
+fn export_import() -> Result<(), Box<dyn Error>> {
+    let directory = temp_dir().join(format!("i18n_cli_{}", std::process::id()));
+    create_dir_all(&directory)?;
+    let first = directory.join("first.txt");
+    let sqlite3 = directory.join("imported.sqlite3");
+    let second = directory.join("second.txt");
+    let source = format!("{}i18n_provider_sqlite3.sqlite3", PATH);
+    let exported = i18n(&["export", source.as_str(), first.to_str().unwrap()])?;
+    let imported = i18n(&["import", first.to_str().unwrap(), sqlite3.to_str().unwrap()])?;
+    let again = i18n(&[
+        "export",
+        sqlite3.to_str().unwrap(),
+        second.to_str().unwrap(),
+    ])?;
+    let overwrite = i18n(&["import", first.to_str().unwrap(), sqlite3.to_str().unwrap()])?;
+    let result = (read_to_string(&first), read_to_string(&second));
+    remove_dir_all(&directory)?;
+    assert!(exported.status.success(), "Export should succeed.");
+    assert!(imported.status.success(), "Import should succeed.");
+    assert!(again.status.success(), "Second export should succeed.");
+    assert!(!overwrite.status.success(), "Import must not overwrite.");
+    assert_eq!(result.0?, result.1?, "Round trip should be identical.");
+    Ok(())
+}
+
+#[test]
+fn import_invalid() -> Result<(), Box<dyn Error>> {
+    let directory = temp_dir().join(format!("i18n_cli_invalid_{}", std::process::id()));
+    create_dir_all(&directory)?;
+    let text = directory.join("invalid.txt");
+    let sqlite3 = directory.join("invalid.sqlite3");
+    write(
+        &text,
+        "# i18n text catalogue: schema 1.0\n[language]\nen-US\tEnglish\n",
+    )?;
+    let failed = i18n(&["import", text.to_str().unwrap(), sqlite3.to_str().unwrap()])?;
+    let left = (
+        sqlite3.exists(),
+        directory.join("invalid.sqlite3.partial").exists(),
+    );
+    let source = format!("{}i18n_provider_sqlite3.sqlite3", PATH);
+    let exported = directory.join("exported.txt");
+    i18n(&["export", source.as_str(), exported.to_str().unwrap()])?;
+    let retried = i18n(&[
+        "import",
+        exported.to_str().unwrap(),
+        sqlite3.to_str().unwrap(),
+    ])?;
+    remove_dir_all(&directory)?;
+    assert!(!failed.status.success(), "Import should fail.");
+    assert_eq!(left, (false, false), "No file should be left behind.");
+    assert!(
+        String::from_utf8(failed.stderr)?.contains("Line 3"),
+        "Should report the line."
+    );
+    assert!(
+        retried.status.success(),
+        "Import should succeed once fixed."
+    );
+    Ok(())
+}
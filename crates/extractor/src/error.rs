@@ -0,0 +1,53 @@
+// This file is part of `i18n_extractor-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_extractor-rizzen-yazston` crate.
+
+use core::fmt::{Display, Formatter, Result};
+use i18n_provider::ProviderError;
+use std::error::Error;
+use std::path::PathBuf;
+
+/// The `ExtractorError` type consists of the follow:
+///
+/// * `Io`: Indicates the source file or directory could not be read, containing the path and reason,
+///
+/// * `Parse`: Indicates the source file is not valid Rust code, containing the path, line and reason,
+///
+/// * `Provider`: Wraps the [`ProviderError`] of the localisation provider.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ExtractorError {
+    Io(PathBuf, String),           // path, reason
+    Parse(PathBuf, usize, String), // path, line, reason
+    Provider(ProviderError),
+}
+
+impl Display for ExtractorError {
+    fn fmt(&self, formatter: &mut Formatter) -> Result {
+        match self {
+            ExtractorError::Io(path, reason) => write!(
+                formatter,
+                "ExtractorError::Io: Failed to read ‘{}’: {}",
+                path.display(),
+                reason
+            ),
+            ExtractorError::Parse(path, line, reason) => write!(
+                formatter,
+                "ExtractorError::Parse: Failed to parse ‘{}’ at line {}: {}",
+                path.display(),
+                line,
+                reason
+            ),
+            ExtractorError::Provider(ref error) => {
+                write!(formatter, "ExtractorError::Provider: [{}].", error)
+            }
+        }
+    }
+}
+
+impl Error for ExtractorError {}
+
+impl From<ProviderError> for ExtractorError {
+    fn from(error: ProviderError) -> ExtractorError {
+        ExtractorError::Provider(error)
+    }
+}
@@ -0,0 +1,535 @@
+// This file is part of `i18n_extractor-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_extractor-rizzen-yazston` crate.
+
+use crate::ExtractorError;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use i18n_provider::LocalisationProviderTrait;
+use i18n_utility::json_string;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprMethodCall, ExprStruct, Lit, LitStr, Macro, Member, Token};
+
+/// The `ReferenceKind` indicates how the localisation string is referenced in the source code:
+///
+/// * `Format`: A call to the `Localiser`'s `format()` method,
+///
+/// * `FormatToParts`: A call to the `Localiser`'s `format_to_parts()` method,
+///
+/// * `FormatInto`: A call to the `Localiser`'s `format_into()` method,
+///
+/// * `FormatAsync`: A call to the `Localiser`'s `format_async()` method,
+///
+/// * `Message`: A call to the `Localiser`'s `message()` method,
+///
+/// * `FormatWithDefaults`: A call to the `Localiser`'s `format_with_defaults()` method,
+///
+/// * `Literal`: A call to the `Localiser`'s `literal()` method,
+///
+/// * `LiteralWithDefaults`: A call to the `Localiser`'s `literal_with_defaults()` method,
+///
+/// * `LocalisationData`: A construction of the `LocalisationData` struct,
+///
+/// * `L10nMacro`: An invocation of the `l10n!` macro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReferenceKind {
+    Format,
+    FormatToParts,
+    FormatInto,
+    FormatAsync,
+    Message,
+    FormatWithDefaults,
+    Literal,
+    LiteralWithDefaults,
+    LocalisationData,
+    L10nMacro,
+}
+
+impl Display for ReferenceKind {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        match self {
+            ReferenceKind::Format => write!(formatter, "format"),
+            ReferenceKind::FormatToParts => write!(formatter, "format_to_parts"),
+            ReferenceKind::FormatInto => write!(formatter, "format_into"),
+            ReferenceKind::FormatAsync => write!(formatter, "format_async"),
+            ReferenceKind::Message => write!(formatter, "message"),
+            ReferenceKind::FormatWithDefaults => write!(formatter, "format_with_defaults"),
+            ReferenceKind::Literal => write!(formatter, "literal"),
+            ReferenceKind::LiteralWithDefaults => write!(formatter, "literal_with_defaults"),
+            ReferenceKind::LocalisationData => write!(formatter, "LocalisationData"),
+            ReferenceKind::L10nMacro => write!(formatter, "l10n!"),
+        }
+    }
+}
+
+/// The location of a reference in the source code. Both the line and column start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(
+            formatter,
+            "{}:{}:{}",
+            self.file.display(),
+            self.line,
+            self.column
+        )
+    }
+}
+
+/// A reference to a localisation string, where both the component and identifier are string literals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringReference {
+    pub component: String,
+    pub identifier: String,
+    pub kind: ReferenceKind,
+    pub location: SourceLocation,
+}
+
+/// A reference to a localisation string, where the component or identifier is not a string literal, and thus can't
+/// be determined without running the code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedReference {
+    pub kind: ReferenceKind,
+    pub location: SourceLocation,
+}
+
+/// The `Manifest` contains all the localisation string references found in the Rust source code, using the [`syn`]
+/// crate to parse the source files. The following are detected:
+///
+/// * Method calls of `format()`, `format_to_parts()`, `format_into()`, `format_async()`, `message()`,
+///   `format_with_defaults()`, `literal()` and `literal_with_defaults()`, having the argument count of the `Localiser`
+///   methods,
+///
+/// * Constructions of the `LocalisationData` struct,
+///
+/// * Invocations of the `l10n!` macro.
+///
+/// The arguments of other macros, such as `format!()` and `println!()`, are parsed as comma separated expressions, so
+/// references within the macro arguments are also detected.
+///
+/// The component and identifier arguments (or fields) are resolved when they are string literals, including the forms
+/// `"literal".to_string()`, `"literal".to_owned()`, `"literal".into()` and `String::from( "literal" )`. All other
+/// expressions are listed as unresolved references.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_extractor::{Manifest, ReferenceKind};
+/// use std::path::Path;
+/// use std::error::Error;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let mut manifest = Manifest::new();
+///     manifest.add_source(
+///         Path::new( "example.rs" ),
+///         "fn example( localiser: &Localiser ) { localiser.literal_with_defaults( \"application\", \"example\" ); }",
+///     )?;
+///     assert_eq!( manifest.references.len(), 1, "Should be 1 reference." );
+///     assert_eq!( manifest.references[ 0 ].identifier, "example", "Check identifier." );
+///     assert_eq!(
+///         manifest.references[ 0 ].kind,
+///         ReferenceKind::LiteralWithDefaults,
+///         "Check kind."
+///     );
+///     Ok( () )
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Manifest {
+    pub references: Vec<StringReference>,
+    pub unresolved: Vec<UnresolvedReference>,
+}
+
+impl Manifest {
+    /// Creates an empty manifest.
+    pub fn new() -> Self {
+        Manifest::default()
+    }
+
+    /// Adds the references found in the Rust source code. The `path` is only used for the source locations.
+    ///
+    /// Return of [`ExtractorError::Parse`] indicates the source is not valid Rust code.
+    pub fn add_source(&mut self, path: &Path, source: &str) -> Result<(), ExtractorError> {
+        let file = match syn::parse_file(source) {
+            Ok(file) => file,
+            Err(error) => {
+                return Err(ExtractorError::Parse(
+                    path.to_path_buf(),
+                    error.span().start().line,
+                    error.to_string(),
+                ))
+            }
+        };
+        let mut visitor = ReferenceVisitor {
+            path,
+            manifest: self,
+        };
+        visitor.visit_file(&file);
+        Ok(())
+    }
+
+    /// Adds the references found in the Rust source file.
+    ///
+    /// Return of [`ExtractorError`] indicates the file could not be read or parsed.
+    pub fn add_file(&mut self, path: &Path) -> Result<(), ExtractorError> {
+        let source = match read_to_string(path) {
+            Ok(source) => source,
+            Err(error) => return Err(ExtractorError::Io(path.to_path_buf(), error.to_string())),
+        };
+        self.add_source(path, source.as_str())
+    }
+
+    /// Adds the references found in all the `.rs` files of the directory and its subdirectories. The `target`
+    /// directories and hidden directories are skipped. Files are processed in sorted order.
+    ///
+    /// Return of [`ExtractorError`] indicates a directory or file could not be read, or a file could not be parsed.
+    pub fn add_directory(&mut self, path: &Path) -> Result<(), ExtractorError> {
+        let entries = match read_dir(path) {
+            Ok(entries) => entries,
+            Err(error) => return Err(ExtractorError::Io(path.to_path_buf(), error.to_string())),
+        };
+        let mut paths = Vec::<PathBuf>::new();
+        for entry in entries {
+            match entry {
+                Ok(entry) => paths.push(entry.path()),
+                Err(error) => {
+                    return Err(ExtractorError::Io(path.to_path_buf(), error.to_string()))
+                }
+            }
+        }
+        paths.sort();
+        for entry in paths.iter() {
+            let name = entry
+                .file_name()
+                .map(|value| value.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if entry.is_dir() {
+                if name != "target" && !name.starts_with('.') {
+                    self.add_directory(entry)?;
+                }
+            } else if name.ends_with(".rs") {
+                self.add_file(entry)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Exports the manifest as a JSON string.
+    pub fn to_json(&self) -> String {
+        let mut json = "{\"references\":[".to_string();
+        for (index, reference) in self.references.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str(reference_json(reference).as_str());
+        }
+        json.push_str("],\"unresolved\":[");
+        for (index, reference) in self.unresolved.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str(
+                format!(
+                    "{{\"kind\":{},{}}}",
+                    json_string(reference.kind.to_string().as_str()),
+                    location_json(&reference.location)
+                )
+                .as_str(),
+            );
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+/// The `ManifestReport` compares the [`Manifest`] with the catalogue of a localisation provider:
+///
+/// * `missing`: The references where the component or identifier does not exist in the provider's repository,
+///
+/// * `unused`: The component and identifier pairs of the provider's repository that are never referenced.
+///
+/// Note: When the manifest contains unresolved references, some of the `unused` entries may actually be used.
+#[derive(Debug)]
+pub struct ManifestReport {
+    pub missing: Vec<StringReference>,
+    pub unused: Vec<(String, String)>, // component, identifier. Sorted.
+}
+
+impl ManifestReport {
+    /// Creates the report for the manifest, using all the components of the provider's repository.
+    ///
+    /// Return of [`ExtractorError::Provider`] indicates there was an error in accessing the data repository.
+    pub fn try_new(
+        manifest: &Manifest,
+        provider: &dyn LocalisationProviderTrait,
+    ) -> Result<Self, ExtractorError> {
+        let mut catalogue = BTreeMap::<String, BTreeSet<String>>::new();
+        for component in provider.repository_details()?.components.iter() {
+            catalogue.insert(
+                component.clone(),
+                provider
+                    .identifiers(component.as_str())?
+                    .into_iter()
+                    .collect::<BTreeSet<String>>(),
+            );
+        }
+        let mut missing = Vec::<StringReference>::new();
+        let mut used = BTreeSet::<(&str, &str)>::new();
+        for reference in manifest.references.iter() {
+            match catalogue.get(&reference.component) {
+                Some(identifiers) if identifiers.contains(&reference.identifier) => {
+                    used.insert((reference.component.as_str(), reference.identifier.as_str()));
+                }
+                _ => missing.push(reference.clone()),
+            }
+        }
+        let mut unused = Vec::<(String, String)>::new();
+        for (component, identifiers) in catalogue.iter() {
+            for identifier in identifiers.iter() {
+                if !used.contains(&(component.as_str(), identifier.as_str())) {
+                    unused.push((component.clone(), identifier.clone()));
+                }
+            }
+        }
+        Ok(ManifestReport { missing, unused })
+    }
+
+    /// Returns `true` if there are neither missing nor unused entries.
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.unused.is_empty()
+    }
+
+    /// Exports the report as a JSON string.
+    pub fn to_json(&self) -> String {
+        let mut json = "{\"missing\":[".to_string();
+        for (index, reference) in self.missing.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str(reference_json(reference).as_str());
+        }
+        json.push_str("],\"unused\":[");
+        for (index, (component, identifier)) in self.unused.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str(
+                format!(
+                    "{{\"component\":{},\"identifier\":{}}}",
+                    json_string(component),
+                    json_string(identifier)
+                )
+                .as_str(),
+            );
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+// Internal structs and functions.
+
+struct ReferenceVisitor<'a> {
+    path: &'a Path,
+    manifest: &'a mut Manifest,
+}
+
+impl<'a> ReferenceVisitor<'a> {
+    fn add(
+        &mut self,
+        kind: ReferenceKind,
+        component: Option<String>,
+        identifier: Option<String>,
+        span: proc_macro2::Span,
+    ) {
+        let start = span.start();
+        let location = SourceLocation {
+            file: self.path.to_path_buf(),
+            line: start.line,
+            column: start.column + 1,
+        };
+        match (component, identifier) {
+            (Some(component), Some(identifier)) => self.manifest.references.push(StringReference {
+                component,
+                identifier,
+                kind,
+                location,
+            }),
+            _ => self
+                .manifest
+                .unresolved
+                .push(UnresolvedReference { kind, location }),
+        }
+    }
+}
+
+impl<'a, 'ast> Visit<'ast> for ReferenceVisitor<'a> {
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        // The kind, and the argument index of the component.
+        let kind = match (call.method.to_string().as_str(), call.args.len()) {
+            ("format", 6) => Some((ReferenceKind::Format, 0)),
+            ("format_to_parts", 6) => Some((ReferenceKind::FormatToParts, 0)),
+            ("format_into", 7) => Some((ReferenceKind::FormatInto, 1)),
+            ("format_async", 6) => Some((ReferenceKind::FormatAsync, 0)),
+            ("message", 4) => Some((ReferenceKind::Message, 0)),
+            ("format_with_defaults", 3) => Some((ReferenceKind::FormatWithDefaults, 0)),
+            ("literal", 5) => Some((ReferenceKind::Literal, 0)),
+            ("literal_with_defaults", 2) => Some((ReferenceKind::LiteralWithDefaults, 0)),
+            _ => None,
+        };
+        if let Some((kind, index)) = kind {
+            self.add(
+                kind,
+                string_literal(&call.args[index]),
+                string_literal(&call.args[index + 1]),
+                call.method.span(),
+            );
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        let is_l10n = mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "l10n");
+        if is_l10n {
+            if let Ok(arguments) = mac.parse_body::<L10nArguments>() {
+                self.add(
+                    ReferenceKind::L10nMacro,
+                    Some(arguments.component.value()),
+                    Some(arguments.identifier.value()),
+                    mac.path.segments.last().unwrap().ident.span(),
+                );
+                for expr in arguments.exprs.iter() {
+                    self.visit_expr(expr);
+                }
+            }
+        } else if let Ok(exprs) =
+            mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+        {
+            // Macros such as `format!()`, `println!()` and `vec![]`. Other macro bodies are skipped.
+            for expr in exprs.iter() {
+                self.visit_expr(expr);
+            }
+        }
+        visit::visit_macro(self, mac);
+    }
+
+    fn visit_expr_struct(&mut self, expr: &'ast ExprStruct) {
+        if let Some(segment) = expr.path.segments.last() {
+            if segment.ident == "LocalisationData" {
+                let mut component = None;
+                let mut identifier = None;
+                for field in expr.fields.iter() {
+                    if let Member::Named(name) = &field.member {
+                        if name == "component" {
+                            component = string_literal(&field.expr);
+                        } else if name == "identifier" {
+                            identifier = string_literal(&field.expr);
+                        }
+                    }
+                }
+                self.add(
+                    ReferenceKind::LocalisationData,
+                    component,
+                    identifier,
+                    segment.ident.span(),
+                );
+            }
+        }
+        visit::visit_expr_struct(self, expr);
+    }
+}
+
+// The arguments of the `l10n!` macro, being the optional `localiser` or `localiser => language_tag` expressions, the
+// component and identifier string literals, and the `name = value` placeholder expressions.
+struct L10nArguments {
+    component: LitStr,
+    identifier: LitStr,
+    exprs: Vec<Expr>,
+}
+
+impl Parse for L10nArguments {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut exprs = Vec::<Expr>::new();
+        if !input.peek(LitStr) {
+            exprs.push(input.parse::<Expr>()?);
+            if input.peek(Token![=>]) {
+                input.parse::<Token![=>]>()?;
+                exprs.push(input.parse::<Expr>()?);
+            }
+            input.parse::<Token![,]>()?;
+        }
+        let component = input.parse::<LitStr>()?;
+        input.parse::<Token![,]>()?;
+        let identifier = input.parse::<LitStr>()?;
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            exprs.extend(Punctuated::<Expr, Token![,]>::parse_terminated(input)?);
+        }
+        Ok(L10nArguments {
+            component,
+            identifier,
+            exprs,
+        })
+    }
+}
+
+// Resolves the string literal of the expression, including the common conversions to `String`.
+fn string_literal(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(expr) => match &expr.lit {
+            Lit::Str(string) => Some(string.value()),
+            _ => None,
+        },
+        Expr::Reference(expr) => string_literal(&expr.expr),
+        Expr::Paren(expr) => string_literal(&expr.expr),
+        Expr::Group(expr) => string_literal(&expr.expr),
+        Expr::MethodCall(call) if call.args.is_empty() => match call.method.to_string().as_str() {
+            "to_string" | "to_owned" | "into" => string_literal(&call.receiver),
+            _ => None,
+        },
+        Expr::Call(call) if call.args.len() == 1 => match call.func.as_ref() {
+            Expr::Path(path)
+                if path.path.segments.len() == 2
+                    && path.path.segments[0].ident == "String"
+                    && path.path.segments[1].ident == "from" =>
+            {
+                string_literal(&call.args[0])
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn reference_json(reference: &StringReference) -> String {
+    format!(
+        "{{\"component\":{},\"identifier\":{},\"kind\":{},{}}}",
+        json_string(reference.component.as_str()),
+        json_string(reference.identifier.as_str()),
+        json_string(reference.kind.to_string().as_str()),
+        location_json(&reference.location)
+    )
+}
+
+fn location_json(location: &SourceLocation) -> String {
+    format!(
+        "\"file\":{},\"line\":{},\"column\":{}",
+        json_string(location.file.to_string_lossy().as_ref()),
+        location.line,
+        location.column
+    )
+}
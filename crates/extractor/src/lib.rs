@@ -0,0 +1,68 @@
+// This file is part of `i18n_extractor-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_extractor-rizzen-yazston` crate.
+
+//! Welcome to the **`i18n_extractor`** crate of the *Internationalisation* (i18n) project.
+//!
+//! This crate consists of two modules:
+//!
+//! * [`error`]: Contains the error enum for the extractor,
+//!
+//! * [`extractor`]: Extraction of localisation string references from Rust source code.
+//!
+//! # Features
+//!
+//! Available features for `i18n_extractor` crate:
+//!
+//! * `sync`: Allow for rust's concurrency capabilities to be used. Use of [`Arc`] and [`Mutex`] instead [`Rc`] and
+//!   [`RefCell`].
+//!
+//! # Modules
+//!
+//! ## `extractor`: Localisation string references extraction.
+//!
+//! The `Manifest` struct parses Rust source code using the [`syn`] crate, and collects the component and identifier
+//! string literals used in calls to the `Localiser`'s `format()`, `format_to_parts()`, `format_into()`,
+//! `format_async()`, `message()`, `format_with_defaults()`, `literal()` and `literal_with_defaults()` methods, in
+//! `LocalisationData` constructions and in `l10n!` invocations. The arguments of other macros, such as `format!()`,
+//! are also searched. The `ManifestReport` compares the manifest with the catalogue of a localisation provider,
+//! listing the references missing from the catalogue, and the catalogue entries that are never referenced.
+//!
+//! ### Examples
+//!
+//! ```
+//! use i18n_extractor::Manifest;
+//! use std::path::Path;
+//! use std::error::Error;
+//!
+//! fn main() -> Result<(), Box<dyn Error>> {
+//!     let mut manifest = Manifest::new();
+//!     manifest.add_source(
+//!         Path::new( "example.rs" ),
+//!         "fn example() -> LocalisationData {
+//!             LocalisationData {
+//!                 component: \"application\".to_string(),
+//!                 identifier: \"example\".to_string(),
+//!                 values: None,
+//!             }
+//!         }",
+//!     )?;
+//!     assert_eq!( manifest.references.len(), 1, "Should be 1 reference." );
+//!     assert_eq!( manifest.references[ 0 ].component, "application", "Check component." );
+//!     assert_eq!( manifest.references[ 0 ].location.line, 2, "Check line." );
+//!     Ok( () )
+//! }
+//! ```
+
+#[cfg(doc)]
+use std::sync::{Arc, Mutex};
+
+#[cfg(doc)]
+use std::rc::Rc;
+
+#[cfg(doc)]
+use std::cell::RefCell;
+
+pub mod error;
+pub use error::*;
+pub mod extractor;
+pub use extractor::*;
@@ -0,0 +1,124 @@
+// This file is part of `i18n_extractor-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_extractor-rizzen-yazston` crate.
+
+//! Testing the extraction of localisation string references, and comparing with the catalogue.
+
+use i18n_extractor::{Manifest, ManifestReport, ReferenceKind};
+use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+use i18n_utility::LanguageTagRegistry;
+
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as RefCount;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::Arc as RefCount;
+
+use std::error::Error;
+use std::path::Path;
+
+const SOURCE: &str = r#"
+fn example( localiser: &Localiser, tag: &RefCount<LanguageTag>, name: &str ) {
+    let values = HashMap::<String, PlaceholderValue>::new();
+    localiser.format( "application", "example_string", &values, tag, None, None );
+    localiser.format_with_defaults( "application", "not_in_catalogue", &values );
+    localiser.literal( "i18n_provider_sqlite3", "path_conversion", tag, None, None );
+    localiser.literal_with_defaults( "application", name );
+    let _data = LocalisationData {
+        component: String::from( "i18n_provider_sqlite3" ),
+        identifier: "no_sqlite3".into(),
+        values: None,
+    };
+    fixed_decimal_formatter.format( &number );
+}
+"#;
+
+#[test]
+fn manifest() -> Result<(), Box<dyn Error>> {
+    let mut manifest = Manifest::new();
+    manifest.add_source(Path::new("example.rs"), SOURCE)?;
+    assert_eq!(manifest.references.len(), 4, "Should be 4 references.");
+    assert_eq!(
+        manifest.references[3].kind,
+        ReferenceKind::LocalisationData,
+        "Check kind."
+    );
+    assert_eq!(
+        manifest.references[3].identifier, "no_sqlite3",
+        "Check identifier."
+    );
+    assert_eq!(manifest.unresolved.len(), 1, "Should be 1 unresolved.");
+    assert_eq!(manifest.unresolved[0].location.line, 7, "Check line.");
+    Ok(())
+}
+
+#[test]
+fn manifest_report() -> Result<(), Box<dyn Error>> {
+    let mut manifest = Manifest::new();
+    manifest.add_source(Path::new("example.rs"), SOURCE)?;
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let provider = LocalisationProviderSqlite3::try_new(
+        "../provider/sqlite3/l10n/",
+        &language_tag_registry,
+        false,
+    )?;
+    let report = ManifestReport::try_new(&manifest, &provider)?;
+    assert_eq!(report.missing.len(), 1, "Should be 1 missing.");
+    assert_eq!(
+        report.missing[0].identifier, "not_in_catalogue",
+        "Check missing identifier."
+    );
+    assert!(
+        report
+            .unused
+            .contains(&("application".to_string(), "example".to_string())),
+        "Identifier ‘example’ should be unused."
+    );
+    assert!(
+        !report
+            .unused
+            .iter()
+            .any(|(_, identifier)| identifier == "path_conversion"),
+        "Identifier ‘path_conversion’ should be used."
+    );
+    Ok(())
+}
+
+const SOURCE_MACROS: &str = r#"
+async fn example( localiser: &Localiser, tag: &RefCount<LanguageTag>, buffer: &mut String ) {
+    let values = HashMap::<String, PlaceholderValue>::new();
+    println!( "{}", localiser.format_to_parts( "application", "parts", &values, tag, None, None ).is_ok() );
+    let _ = format!( "{}", localiser.message( "application", "message", &values, tag ) );
+    localiser.format_into( buffer, "application", "into", &values, tag, None, None );
+    localiser.format_async( "application", "async", &values, tag, None, None ).await;
+    let _ = l10n!( localiser => tag, "application", "macro", count = localiser.literal_with_defaults( "application", "nested" ) );
+    let _ = vec![ l10n!( localiser, "application", "macro_vector" ) ];
+}
+"#;
+
+#[test]
+fn manifest_macros() -> Result<(), Box<dyn Error>> {
+    let mut manifest = Manifest::new();
+    manifest.add_source(Path::new("example.rs"), SOURCE_MACROS)?;
+    let found = manifest
+        .references
+        .iter()
+        .map(|reference| (reference.kind, reference.identifier.as_str()))
+        .collect::<Vec<(ReferenceKind, &str)>>();
+    assert_eq!(
+        found,
+        vec![
+            (ReferenceKind::FormatToParts, "parts"),
+            (ReferenceKind::Message, "message"),
+            (ReferenceKind::FormatInto, "into"),
+            (ReferenceKind::FormatAsync, "async"),
+            (ReferenceKind::L10nMacro, "macro"),
+            (ReferenceKind::LiteralWithDefaults, "nested"),
+            (ReferenceKind::L10nMacro, "macro_vector"),
+        ],
+        "Check references."
+    );
+    assert_eq!(manifest.references[0].location.line, 4, "Check line.");
+    assert_eq!(manifest.unresolved.len(), 0, "Should be 0 unresolved.");
+    Ok(())
+}
@@ -0,0 +1,312 @@
+// This file is part of `i18n_l10n_macro-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_l10n_macro-rizzen-yazston` crate.
+
+use i18n_lexer::{DataProvider, IcuDataProvider};
+use i18n_localiser::{CommandRegistry, Localiser, PatternChecker};
+use i18n_provider::LocalisationProviderTrait;
+use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+use i18n_utility::{LanguageTagRegistry, RefCount};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::cell::RefCell;
+use std::env::var;
+use std::fs::{metadata, read_dir};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{Error, Expr, Ident, LitStr, Result, Token};
+
+// The names and keywords of the placeholders of a string.
+type Placeholders = Vec<(String, String)>;
+
+/// The parsed arguments of the `l10n!` macro.
+pub(crate) struct L10nInput {
+    localiser: Option<Expr>,
+    language_tag: Option<Expr>,
+    component: LitStr,
+    identifier: LitStr,
+    values: Vec<(Ident, Expr)>,
+}
+
+impl Parse for L10nInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut localiser = None::<Expr>;
+        let mut language_tag = None::<Expr>;
+        if !input.peek(LitStr) {
+            localiser = Some(input.parse::<Expr>()?);
+            if input.peek(Token![=>]) {
+                input.parse::<Token![=>]>()?;
+                language_tag = Some(input.parse::<Expr>()?);
+            }
+            input.parse::<Token![,]>()?;
+        }
+        let component = input.parse::<LitStr>()?;
+        input.parse::<Token![,]>()?;
+        let identifier = input.parse::<LitStr>()?;
+        let mut values = Vec::<(Ident, Expr)>::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let name = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let value = input.parse::<Expr>()?;
+            values.push((name, value));
+        }
+        Ok(L10nInput {
+            localiser,
+            language_tag,
+            component,
+            identifier,
+            values,
+        })
+    }
+}
+
+/// Verifies the arguments against the default language string of the catalogue, and generates the `Localiser` call.
+pub(crate) fn expand(input: L10nInput) -> Result<TokenStream> {
+    let directory = catalogue_directory();
+    let component = input.component.value();
+    let identifier = input.identifier.value();
+    let placeholders = match placeholders(&directory, component.as_str(), identifier.as_str()) {
+        Ok(Some(placeholders)) => placeholders,
+        Ok(None) => {
+            return Err(Error::new_spanned(
+                &input.identifier,
+                format!(
+                "The identifier ‘{}’ was not found for the default language of the component ‘{}’.",
+                identifier, component
+            ),
+            ))
+        }
+        Err(error) => {
+            return Err(Error::new_spanned(
+                &input.component,
+                format!(
+                    "Failed to verify using the catalogue ‘{}’: {}",
+                    directory.display(),
+                    error
+                ),
+            ))
+        }
+    };
+    let mut inserts = Vec::<TokenStream>::new();
+    for (index, (name, value)) in input.values.iter().enumerate() {
+        let key = name.to_string();
+        if input.values[..index]
+            .iter()
+            .any(|(previous, _)| *previous == key)
+        {
+            return Err(Error::new_spanned(
+                name,
+                format!("The placeholder ‘{}’ is supplied more than once.", key),
+            ));
+        }
+        let Some((_, keyword)) = placeholders
+            .iter()
+            .find(|(placeholder, _)| *placeholder == key)
+        else {
+            return Err(Error::new_spanned(
+                name,
+                format!(
+                    "The placeholder ‘{}’ does not exist in the string ‘{}’ of the component ‘{}’. Expected: {}.",
+                    key,
+                    identifier,
+                    component,
+                    names(&placeholders)
+                ),
+            ));
+        };
+        let marker = match keyword.as_str() {
+            "string" | "select" => quote! { ::i18n_utility::StringPlaceholder },
+            "decimal" | "plural" | "ordinal" => quote! { ::i18n_utility::NumberPlaceholder },
+            "date_time" => quote! { ::i18n_utility::DateTimePlaceholder },
+            _ => {
+                return Err(Error::new_spanned(
+                    name,
+                    format!(
+                        "The placeholder ‘{}’ has the unsupported keyword ‘{}’.",
+                        key, keyword
+                    ),
+                ))
+            }
+        };
+
+        // The marker trait bound rejects values of the wrong type, and `From` does the conversion.
+        let conversion = quote_spanned! { value.span() =>
+            {
+                fn placeholder<T: #marker>(value: T) -> ::i18n_utility::PlaceholderValue {
+                    ::core::convert::Into::into(value)
+                }
+                placeholder(#value)
+            }
+        };
+        inserts.push(quote! { values.insert(#key.to_string(), #conversion); });
+    }
+    let missing = placeholders
+        .iter()
+        .filter(|(placeholder, _)| !input.values.iter().any(|(name, _)| name == placeholder))
+        .map(|(placeholder, keyword)| format!("{} ({})", placeholder, keyword))
+        .collect::<Vec<String>>();
+    if !missing.is_empty() {
+        return Err(Error::new(
+            Span::call_site(),
+            format!(
+                "Missing values for the placeholders of the string ‘{}’ of the component ‘{}’: {}.",
+                identifier,
+                component,
+                missing.join(", ")
+            ),
+        ));
+    }
+
+    // Including the catalogue files makes the compiler rebuild the crate when the catalogue changes.
+    let tracked = catalogue_files(&directory)
+        .into_iter()
+        .map(|path| {
+            let path = path.to_string_lossy().to_string();
+            quote! { const _: &[u8] = include_bytes!(#path); }
+        })
+        .collect::<Vec<TokenStream>>();
+    let localiser = match input.localiser.as_ref() {
+        Some(localiser) => quote! { #localiser },
+        None => quote! { localiser },
+    };
+    let component = &input.component;
+    let identifier = &input.identifier;
+    let call = match input.language_tag.as_ref() {
+        None => quote! { (#localiser).format_with_defaults(#component, #identifier, &values) },
+        Some(language_tag) => quote! {
+            (#localiser).format(#component, #identifier, &values, #language_tag, None, None)
+        },
+    };
+    Ok(quote! {
+        {
+            #( #tracked )*
+            #[allow(unused_mut)]
+            let mut values = ::std::collections::HashMap::<
+                ::std::string::String,
+                ::i18n_utility::PlaceholderValue,
+            >::new();
+            #( #inserts )*
+            #call
+        }
+    })
+}
+
+// Internal functions.
+
+// The `I18N_CATALOGUE` environment variable, relative to the crate's directory, else the crate's `l10n` directory.
+fn catalogue_directory() -> PathBuf {
+    let manifest = PathBuf::from(var("CARGO_MANIFEST_DIR").unwrap_or_default());
+    match var("I18N_CATALOGUE") {
+        Ok(path) => manifest.join(path),
+        Err(_) => manifest.join("l10n"),
+    }
+}
+
+fn catalogue_files(directory: &Path) -> Vec<PathBuf> {
+    let mut files = match read_dir(directory) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == "sqlite3")
+            })
+            .collect::<Vec<PathBuf>>(),
+        Err(_) => Vec::<PathBuf>::new(),
+    };
+    files.sort();
+    files
+}
+
+// The `Localiser` of a catalogue directory, used for parsing the strings. The modification times of the catalogue
+// files detect changes, when the macro is used by a long running process, such as an IDE.
+struct Catalogue {
+    directory: PathBuf,
+    stamps: Vec<Option<SystemTime>>,
+    localiser: Localiser,
+}
+
+thread_local! {
+    // The macro is expanded many times during a crate's compilation, thus the catalogue is only opened once.
+    static CATALOGUES: RefCell<Vec<Catalogue>> = const { RefCell::new(Vec::<Catalogue>::new()) };
+}
+
+// Returns `None` if there is no string for the identifier in the component's default language.
+fn placeholders(
+    directory: &Path,
+    component: &str,
+    identifier: &str,
+) -> core::result::Result<Option<Placeholders>, Box<dyn std::error::Error>> {
+    let stamps = catalogue_files(directory)
+        .iter()
+        .map(|path| metadata(path).and_then(|metadata| metadata.modified()).ok())
+        .collect::<Vec<Option<SystemTime>>>();
+    CATALOGUES.with(|catalogues| {
+        let mut catalogues = catalogues.borrow_mut();
+        catalogues
+            .retain(|catalogue| catalogue.directory != directory || catalogue.stamps == stamps);
+        if !catalogues
+            .iter()
+            .any(|catalogue| catalogue.directory == directory)
+        {
+            catalogues.push(Catalogue {
+                directory: directory.to_path_buf(),
+                stamps,
+                localiser: catalogue_localiser(directory)?,
+            });
+        }
+        let localiser = &catalogues
+            .iter()
+            .find(|catalogue| catalogue.directory == directory)
+            .unwrap()
+            .localiser;
+        let provider = localiser.localisation_provider();
+        let default = provider.component_details(component)?.default.clone();
+        let Some((string, _)) = provider.string_exact_match(component, identifier, &default)?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(
+            PatternChecker::new(localiser).placeholders(string.as_str())?,
+        ))
+    })
+}
+
+fn catalogue_localiser(
+    directory: &Path,
+) -> core::result::Result<Localiser, Box<dyn std::error::Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let provider = LocalisationProviderSqlite3::try_new(directory.to_path_buf(), &registry, false)?;
+
+    // The default language of the `Localiser` is not used for obtaining the placeholders.
+    let default = match provider.repository_details()?.default.as_ref() {
+        Some(default) => default.as_str().to_string(),
+        None => "en".to_string(),
+    };
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let command_registry = RefCount::new(CommandRegistry::new());
+    Ok(Localiser::try_new(
+        &icu_data_provider,
+        &registry,
+        Box::new(provider),
+        &command_registry,
+        false,
+        false,
+        default.as_str(),
+    )?)
+}
+
+fn names(placeholders: &[(String, String)]) -> String {
+    match placeholders.is_empty() {
+        true => "no placeholders".to_string(),
+        false => placeholders
+            .iter()
+            .map(|(name, keyword)| format!("{} ({})", name, keyword))
+            .collect::<Vec<String>>()
+            .join(", "),
+    }
+}
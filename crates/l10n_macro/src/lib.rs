@@ -0,0 +1,122 @@
+// This file is part of `i18n_l10n_macro-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_l10n_macro-rizzen-yazston` crate.
+
+//! Welcome to the **`i18n_l10n_macro`** crate of the *Internationalisation* (i18n) project.
+//!
+//! This crate consists of one procedural macro:
+//!
+//! * [`l10n!`]: Compile time checked localisation, expanding to a `Localiser` format call.
+//!
+//! The generated code refers to the `i18n_utility` crate, thus the crate using the macro must have the
+//! `i18n_utility-rizzen-yazston` crate as a dependency.
+//!
+//! # Features
+//!
+//! Available features for `i18n_l10n_macro` crate:
+//!
+//! * `sync`: Enables the `sync` feature of the crates used by the macro for reading the catalogue. The generated code
+//!   is not affected.
+//!
+//! # Macros
+//!
+//! ## `l10n!`: Compile time checked localisation.
+//!
+//! At compile time the macro opens the Sqlite3 catalogue, and verifies that the identifier exists for the default
+//! language of the component, and that the supplied placeholder names are exactly the placeholders of the default
+//! language string. The values are converted into `PlaceholderValue` using the `From` implementations, where the marker
+//! trait of the placeholder's keyword is required, thus a value of the wrong type is rejected by the compiler:
+//!
+//! * no keyword or `select`: [`StringPlaceholder`] (`&str`, `String`, `char`, `TaggedString`),
+//!
+//! * `decimal`, `plural` or `ordinal`: [`NumberPlaceholder`] (integers, floats, `FixedDecimal`),
+//!
+//! * `date_time`: [`DateTimePlaceholder`] (`DateTime<Iso>`, `ZonedDateTime`, `Date<Iso>`, `Time`).
+//!
+//! The catalogue directory is obtained from the `I18N_CATALOGUE` environment variable at build time (for example, set
+//! in the `[env]` section of `.cargo/config.toml`), which is relative to the directory of the crate being compiled.
+//! When not set, the `l10n` directory of the crate is used. The catalogue files are tracked, so the crate is
+//! recompiled when the catalogue changes. The catalogue is opened once for all the macro invocations of the crate.
+//!
+//! The macro has three forms:
+//!
+//! * `l10n!( "component", "identifier", name = value, ... )`: Expands to `localiser.format_with_defaults( ... )`,
+//!   using the `localiser` variable in scope, which may be a `Localiser` or a reference to a `Localiser`,
+//!
+//! * `l10n!( localiser, "component", "identifier", name = value, ... )`: Expands to
+//!   `localiser.format_with_defaults( ... )`,
+//!
+//! * `l10n!( localiser => language_tag, "component", "identifier", name = value, ... )`: Expands to
+//!   `localiser.format( ..., language_tag, None, None )`.
+//!
+//! ### Examples
+//!
+//! ```
+//! use i18n_l10n_macro::l10n;
+//! use i18n_lexer::{DataProvider, IcuDataProvider};
+//! use i18n_localiser::{CommandRegistry, Localiser};
+//! use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+//! use i18n_utility::{LanguageTagRegistry, RefCount};
+//! use std::error::Error;
+//!
+//! fn main() -> Result<(), Box<dyn Error>> {
+//!     let icu_data_provider = RefCount::new( IcuDataProvider::try_new( DataProvider::Internal )? );
+//!     let language_tag_registry = RefCount::new( LanguageTagRegistry::new() );
+//!     let lstring_provider = LocalisationProviderSqlite3::try_new(
+//!         "./l10n/",
+//!         &language_tag_registry,
+//!         false
+//!     )?;
+//!     let command_registry = RefCount::new( CommandRegistry::new() );
+//!     let localiser = Localiser::try_new(
+//!         &icu_data_provider,
+//!         &language_tag_registry,
+//!         Box::new( lstring_provider ),
+//!         &command_registry,
+//!         true,
+//!         true,
+//!         "en-ZA",
+//!     )?;
+//!     let ( string, _ ) = l10n!( "i18n_l10n_macro", "items", count = 3 )?;
+//!     assert_eq!( string.as_str(), "There are 3 items.", "Check plural." );
+//!     Ok( () )
+//! }
+//! ```
+//!
+//! A misspelt placeholder name fails to compile:
+//!
+//! ```compile_fail
+//! # use i18n_l10n_macro::l10n;
+//! # fn example( localiser: &i18n_localiser::Localiser ) {
+//! let _ = l10n!( localiser, "i18n_l10n_macro", "greeting", nmae = "World" );
+//! # }
+//! ```
+//!
+//! A value of the wrong type fails to compile:
+//!
+//! ```compile_fail
+//! # use i18n_l10n_macro::l10n;
+//! # fn example( localiser: &i18n_localiser::Localiser ) {
+//! let _ = l10n!( localiser, "i18n_l10n_macro", "items", count = "three" );
+//! # }
+//! ```
+//!
+//! [`StringPlaceholder`]: https://docs.rs/i18n_utility-rizzen-yazston/latest/i18n_utility/trait.StringPlaceholder.html
+//! [`NumberPlaceholder`]: https://docs.rs/i18n_utility-rizzen-yazston/latest/i18n_utility/trait.NumberPlaceholder.html
+//! [`DateTimePlaceholder`]: https://docs.rs/i18n_utility-rizzen-yazston/latest/i18n_utility/trait.DateTimePlaceholder.html
+
+mod l10n;
+
+use proc_macro::TokenStream;
+use syn::parse_macro_input;
+
+/// Compile time checked localisation, expanding to a `Localiser` format call.
+///
+/// See the crate documentation for the forms and the catalogue location.
+#[proc_macro]
+pub fn l10n(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as l10n::L10nInput);
+    match l10n::expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
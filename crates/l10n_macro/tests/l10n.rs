@@ -0,0 +1,74 @@
+// This file is part of `i18n_l10n_macro-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_l10n_macro-rizzen-yazston` crate.
+
+//! Testing the `l10n!` macro against the crate's test catalogue.
+
+use i18n_l10n_macro::l10n;
+use i18n_lexer::{DataProvider, IcuDataProvider};
+use i18n_localiser::{CommandRegistry, Localiser};
+use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+use i18n_utility::{LanguageTagRegistry, RefCount};
+use std::error::Error;
+
+fn localiser() -> Result<Localiser, Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    Ok(Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?)
+}
+
+#[test]
+fn with_defaults() -> Result<(), Box<dyn Error>> {
+    let localiser = localiser()?;
+    let name = "World".to_string();
+    let (string, _) = l10n!(localiser, "i18n_l10n_macro", "greeting", name = &name)?;
+    assert_eq!(string.as_str(), "Hello World!", "Check string placeholder.");
+    let (string, _) = l10n!(&localiser, "i18n_l10n_macro", "items", count = 1u8)?;
+    assert_eq!(
+        string.as_str(),
+        "There is 1 item.",
+        "Check plural placeholder."
+    );
+    let (string, _) = l10n!(localiser, "i18n_l10n_macro", "literal")?;
+    assert_eq!(string.as_str(), "No placeholders.", "Check literal.");
+    Ok(())
+}
+
+#[test]
+fn localiser_in_scope() -> Result<(), Box<dyn Error>> {
+    let localiser = localiser()?;
+    let (string, _) = l10n!("i18n_l10n_macro", "items", count = 2)?;
+    assert_eq!(
+        string.as_str(),
+        "There are 2 items.",
+        "Check plural placeholder."
+    );
+    let (string, _) = l10n!("i18n_l10n_macro", "greeting", name = 'W')?;
+    assert_eq!(string.as_str(), "Hello W!", "Check string placeholder.");
+    Ok(())
+}
+
+#[test]
+fn with_language_tag() -> Result<(), Box<dyn Error>> {
+    let localiser = localiser()?;
+    let language_tag = localiser.language_tag_registry().tag("it")?;
+    let (string, tag) = l10n!(
+        localiser => &language_tag,
+        "i18n_l10n_macro",
+        "greeting",
+        name = "Mondo",
+    )?;
+    assert_eq!(string.as_str(), "Ciao Mondo!", "Check Italian string.");
+    assert_eq!(tag.as_str(), "it", "Check language tag.");
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// This file is part of `i18n_l10n_macro-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_l10n_macro-rizzen-yazston` crate.
+
+//! Testing the `l10n!` macro with the `sync` feature of `i18n_localiser`, without the `sync` feature of the macro.
+//!
+//! Run with `cargo test -p i18n_l10n_macro-rizzen-yazston --features i18n_localiser-rizzen-yazston/sync`.
+
+use i18n_l10n_macro::l10n;
+use i18n_lexer::{DataProvider, IcuDataProvider};
+use i18n_localiser::{CommandRegistry, Localiser};
+use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+use i18n_utility::{LanguageTagRegistry, RefCount};
+use std::error::Error;
+use std::thread;
+
+#[test]
+fn shared_localiser() -> Result<(), Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = RefCount::new(Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?);
+    let shared = RefCount::clone(&localiser);
+    let string = thread::spawn(move || {
+        l10n!(shared.as_ref(), "i18n_l10n_macro", "items", count = 3)
+            .map(|(string, _)| string)
+            .map_err(|error| error.to_string())
+    })
+    .join()
+    .expect("Thread panicked.")?;
+    assert_eq!(
+        string.as_str(),
+        "There are 3 items.",
+        "Check plural placeholder."
+    );
+    Ok(())
+}
@@ -0,0 +1,103 @@
+// This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
+
+//! Benchmark of a `Localiser` shared between threads (using feature `sync`), obtaining the same cached strings.
+//!
+//! A cache lookup only takes the read lock of the cache, and the cached formatter is not locked while formatting,
+//! thus the throughput of `cached_format` should increase with the number of threads. The cached literal string of
+//! `cached_literal` is returned without formatting, thus measures the cost of the cache lookup itself.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use i18n_lexer::{DataProvider, IcuDataProvider};
+use i18n_localiser::{CommandRegistry, Localiser};
+use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+use i18n_utility::{LanguageTagRegistry, Values};
+use std::hint::black_box;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn concurrent(criterion: &mut Criterion) {
+    let icu_data_provider = Arc::new(IcuDataProvider::try_new(DataProvider::Internal).unwrap());
+    let language_tag_registry = Arc::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false).unwrap();
+    let command_registry = Arc::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )
+    .unwrap();
+    let values = Values::new()
+        .with("component", "i18n_localiser")
+        .with("identifier", "string_not_found")
+        .with("language_tag", "en-ZA")
+        .with("fallback", "true");
+
+    // Cache the formatter before measuring.
+    localiser
+        .format_with_defaults("i18n_localiser", "string_not_found", &values)
+        .unwrap();
+    let format = || {
+        black_box(
+            localiser
+                .format_with_defaults("i18n_localiser", "string_not_found", &values)
+                .unwrap(),
+        );
+    };
+    localiser
+        .literal_with_defaults("i18n_localiser", "no_grammar")
+        .unwrap();
+    let literal = || {
+        black_box(
+            localiser
+                .literal_with_defaults("i18n_localiser", "no_grammar")
+                .unwrap(),
+        );
+    };
+    threads(criterion, "cached_format", &format);
+    threads(criterion, "cached_literal", &literal);
+}
+
+// Measure the function being called concurrently by an increasing number of threads.
+fn threads<F: Fn() + Sync>(criterion: &mut Criterion, name: &str, function: &F) {
+    let mut group = criterion.benchmark_group(name);
+    for threads in [1usize, 2, 4, 8] {
+        group.throughput(Throughput::Elements(threads as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(threads),
+            &threads,
+            |bencher, &threads| {
+                bencher.iter_custom(|iterations| {
+                    thread::scope(|scope| {
+                        let handles = (0..threads)
+                            .map(|_| {
+                                scope.spawn(|| {
+                                    let start = Instant::now();
+                                    for _ in 0..iterations {
+                                        function();
+                                    }
+                                    start.elapsed()
+                                })
+                            })
+                            .collect::<Vec<_>>();
+                        handles
+                            .into_iter()
+                            .map(|handle| handle.join().unwrap())
+                            .max()
+                            .unwrap_or(Duration::ZERO)
+                    })
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, concurrent);
+criterion_main!(benches);
@@ -0,0 +1,106 @@
+// This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
+
+//! Benchmark of the formatting of cached strings, comparing `format()`, which returns a new `RefCount<String>`,
+//! against `format_into()` writing into a reused buffer, and the lazy `Message` written with `write!()`.
+//!
+//! Both a pattern string with placeholders (`string_not_found`) and a literal string (`cache_entry`) are measured, as
+//! a cached literal string is shared by `format()` without being copied.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use i18n_lexer::{DataProvider, IcuDataProvider};
+use i18n_localiser::{CommandRegistry, Localiser};
+use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+use i18n_utility::{LanguageTagRegistry, Values};
+use std::fmt::Write;
+use std::hint::black_box;
+
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as RefCount;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::Arc as RefCount;
+
+fn format(criterion: &mut Criterion) {
+    let icu_data_provider =
+        RefCount::new(IcuDataProvider::try_new(DataProvider::Internal).unwrap());
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false).unwrap();
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )
+    .unwrap();
+    let tag = language_tag_registry.tag("en-ZA").unwrap();
+    let values = Values::new()
+        .with("component", "i18n_localiser")
+        .with("identifier", "string_not_found")
+        .with("language_tag", "en-ZA")
+        .with("fallback", "true");
+    let mut group = criterion.benchmark_group("format");
+    for identifier in ["string_not_found", "cache_entry"] {
+        // Cache the formatter before measuring.
+        localiser
+            .format("i18n_localiser", identifier, &values, &tag, None, None)
+            .unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("format", identifier),
+            identifier,
+            |bencher, identifier| {
+                bencher.iter(|| {
+                    black_box(
+                        localiser
+                            .format("i18n_localiser", identifier, &values, &tag, None, None)
+                            .unwrap(),
+                    );
+                })
+            },
+        );
+        let mut buffer = String::with_capacity(256);
+        group.bench_with_input(
+            BenchmarkId::new("format_into", identifier),
+            identifier,
+            |bencher, identifier| {
+                bencher.iter(|| {
+                    buffer.clear();
+                    localiser
+                        .format_into(
+                            &mut buffer,
+                            "i18n_localiser",
+                            identifier,
+                            &values,
+                            &tag,
+                            None,
+                            None,
+                        )
+                        .unwrap();
+                    black_box(&buffer);
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("message", identifier),
+            identifier,
+            |bencher, identifier| {
+                bencher.iter(|| {
+                    buffer.clear();
+                    let message = localiser.message("i18n_localiser", identifier, &values, &tag);
+                    write!(buffer, "{}", message).unwrap();
+                    black_box(&buffer);
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, format);
+criterion_main!(benches);
@@ -0,0 +1,268 @@
+// This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
+
+use i18n_utility::LanguageTag;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as RefCount;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::Arc as RefCount;
+
+/// The statistics of the cache of a `Localiser` instance.
+///
+/// `hits`: Is the number of lookups that found the string in the cache,
+///
+/// `misses`: Is the number of lookups that did not find the string in the cache,
+///
+/// `evictions`: Is the number of least recently used entries removed to stay within the capacity. Entries removed
+/// by `clear_cache()` and `evict()` are not counted,
+///
+/// `entries`: Is the current number of cached strings,
+///
+/// `capacity`: Is the maximum number of cached strings, where `None` indicates the cache is unbounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStatistics {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub entries: usize,
+    pub capacity: Option<usize>,
+}
+
+/// The key of a cached string, which borrows its parts, thus a lookup does not allocate. The `fallback` flag is part
+/// of the key, as the string obtained using fallback may be of another language.
+#[derive(Clone, Copy)]
+pub(crate) struct CacheKey<'a> {
+    language_tag: &'a RefCount<LanguageTag>,
+    component: &'a str,
+    identifier: &'a str,
+    fallback: bool,
+}
+
+impl<'a> CacheKey<'a> {
+    pub(crate) fn new(
+        language_tag: &'a RefCount<LanguageTag>,
+        component: &'a str,
+        identifier: &'a str,
+        fallback: bool,
+    ) -> Self {
+        CacheKey {
+            language_tag,
+            component,
+            identifier,
+            fallback,
+        }
+    }
+}
+
+// The owned key of an entry in the recency order, which is only created when inserting an entry.
+struct OrderKey {
+    language_tag: RefCount<LanguageTag>,
+    component: String,
+    identifier: String,
+    fallback: bool,
+}
+
+// The entry of a language and fallback flag for a component's string. The `tick` is updated by lookups using shared
+// access, while `ordered` is the tick under which the entry is found in the recency order.
+struct Entry<V> {
+    language_tag: RefCount<LanguageTag>,
+    fallback: bool,
+    value: V,
+    tick: AtomicU64,
+    ordered: u64,
+}
+
+impl<V> Entry<V> {
+    fn matches(&self, language_tag: &RefCount<LanguageTag>, fallback: bool) -> bool {
+        self.fallback == fallback
+            && (RefCount::ptr_eq(&self.language_tag, language_tag)
+                || self.language_tag == *language_tag)
+    }
+}
+
+// The entries of the identifiers of the components, where the few entries of the languages of an identifier are
+// searched linearly.
+type Entries<V> = HashMap<String, HashMap<String, Vec<Entry<V>>>>;
+
+/// A least recently used (LRU) cache of an optional capacity.
+///
+/// Every access of an entry assigns it the next tick, thus the entry of the lowest tick is the least recently used
+/// entry, and is the first to be evicted when the capacity is exceeded. The lookups only need shared access, as the
+/// ticks and statistics are atomic, thus the recency order is only brought up to date when evicting: an entry
+/// accessed since it was ordered is moved to its current tick instead of being evicted.
+pub(crate) struct Cache<V> {
+    capacity: Option<usize>,
+    entries: Entries<V>,
+    order: BTreeMap<u64, OrderKey>,
+    length: usize,
+    tick: AtomicU64,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: u64,
+}
+
+impl<V> Cache<V> {
+    pub(crate) fn new(capacity: Option<usize>) -> Self {
+        Cache {
+            capacity,
+            entries: Entries::<V>::new(),
+            order: BTreeMap::<u64, OrderKey>::new(),
+            length: 0,
+            tick: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: 0,
+        }
+    }
+
+    /// Obtain the entry of the key, making it the most recently used entry.
+    pub(crate) fn get(&self, key: &CacheKey) -> Option<&V> {
+        let tick = self.tick.fetch_add(1, Ordering::Relaxed) + 1;
+        let entry = self
+            .entries
+            .get(key.component)
+            .and_then(|identifiers| identifiers.get(key.identifier))
+            .and_then(|entries| {
+                entries
+                    .iter()
+                    .find(|entry| entry.matches(key.language_tag, key.fallback))
+            });
+        match entry {
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+            Some(entry) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                entry.tick.store(tick, Ordering::Relaxed);
+                Some(&entry.value)
+            }
+        }
+    }
+
+    /// Insert or replace the entry of the key as the most recently used entry, then evict the least recently used
+    /// entries that exceed the capacity.
+    pub(crate) fn insert(&mut self, key: CacheKey, value: V) {
+        let tick = *self.tick.get_mut() + 1;
+        *self.tick.get_mut() = tick;
+        let entries = self
+            .entries
+            .entry(key.component.to_string())
+            .or_default()
+            .entry(key.identifier.to_string())
+            .or_default();
+        let entry = Entry {
+            language_tag: RefCount::clone(key.language_tag),
+            fallback: key.fallback,
+            value,
+            tick: AtomicU64::new(tick),
+            ordered: tick,
+        };
+        match entries
+            .iter_mut()
+            .find(|entry| entry.matches(key.language_tag, key.fallback))
+        {
+            Some(existing) => {
+                self.order.remove(&existing.ordered);
+                *existing = entry;
+            }
+            None => {
+                entries.push(entry);
+                self.length += 1;
+            }
+        }
+        self.order.insert(
+            tick,
+            OrderKey {
+                language_tag: RefCount::clone(key.language_tag),
+                component: key.component.to_string(),
+                identifier: key.identifier.to_string(),
+                fallback: key.fallback,
+            },
+        );
+        self.shrink();
+    }
+
+    /// Remove all the entries of the component, returning the number of entries removed.
+    pub(crate) fn evict(&mut self, component: &str) -> usize {
+        let Some(identifiers) = self.entries.remove(component) else {
+            return 0;
+        };
+        let evicted = identifiers.values().map(Vec::len).sum();
+        self.order.retain(|_, key| key.component != component);
+        self.length -= evicted;
+        evicted
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+        self.length = 0;
+    }
+
+    pub(crate) fn set_capacity(&mut self, capacity: Option<usize>) {
+        self.capacity = capacity;
+        self.shrink();
+    }
+
+    pub(crate) fn statistics(&self) -> CacheStatistics {
+        CacheStatistics {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions,
+            entries: self.length,
+            capacity: self.capacity,
+        }
+    }
+
+    pub(crate) fn reset_statistics(&mut self) {
+        *self.hits.get_mut() = 0;
+        *self.misses.get_mut() = 0;
+        self.evictions = 0;
+    }
+
+    fn shrink(&mut self) {
+        let Some(capacity) = self.capacity else {
+            return;
+        };
+        while self.length > capacity {
+            let Some((ordered, key)) = self.order.pop_first() else {
+                break;
+            };
+            let Some(identifiers) = self.entries.get_mut(&key.component) else {
+                continue;
+            };
+            let Some(entries) = identifiers.get_mut(&key.identifier) else {
+                continue;
+            };
+            let Some(index) = entries
+                .iter()
+                .position(|entry| entry.matches(&key.language_tag, key.fallback))
+            else {
+                continue;
+            };
+
+            // An entry accessed since it was ordered is not the least recently used entry.
+            let tick = *entries[index].tick.get_mut();
+            if tick > ordered {
+                entries[index].ordered = tick;
+                self.order.insert(tick, key);
+                continue;
+            }
+            entries.swap_remove(index);
+            if entries.is_empty() {
+                identifiers.remove(&key.identifier);
+                if identifiers.is_empty() {
+                    self.entries.remove(&key.component);
+                }
+            }
+            self.length -= 1;
+            self.evictions += 1;
+        }
+    }
+}
@@ -0,0 +1,605 @@
+// This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
+
+use crate::{
+    plural_category, Formatter, FormatterError, Localiser, LocaliserError, NodeType, Tree,
+};
+use core::fmt::{Display, Formatter as FmtFormatter, Result as FmtResult};
+use i18n_utility::LanguageTag;
+use icu_provider::prelude::DataLocale;
+
+#[cfg(feature = "logging")]
+use log::debug;
+
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as RefCount;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::Arc as RefCount;
+
+/// The `PatternChecker` compares the pattern strings of all the languages of a component against the pattern string
+/// of the component's default language. The strings are parsed with the same lexer and tree as used by the
+/// `Localiser`, and the following are compared:
+///
+/// * placeholder names: missing or unknown placeholders,
+///
+/// * placeholder types: the keyword (`decimal`, `date_time`, `plural`, `ordinal`, `select`) of a placeholder, where
+///   `string` is used for placeholders having no keyword,
+///
+/// * `select` branches: the selectors must be the same as the default language's selectors,
+///
+/// * `plural` and `ordinal` branches: the selectors must be plural categories of the string's language, as the
+///   categories differ between languages. Only `other` and the categories also used by the default language's string
+///   are required, as the formatter falls back to `other`,
+///
+/// * commands: the commands used in the default language must also be used in the string.
+///
+/// Every mismatch is reported as a [`PatternMismatch`] together with the spans of the pattern in both strings.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_lexer::{IcuDataProvider, DataProvider};
+/// use i18n_utility::LanguageTagRegistry;
+/// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+/// use i18n_localiser::{Localiser, CommandRegistry, PatternChecker, MismatchType};
+/// use std::rc::Rc;
+/// use std::error::Error;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let icu_data_provider = Rc::new( IcuDataProvider::try_new( DataProvider::Internal )? );
+///     let language_tag_registry = Rc::new( LanguageTagRegistry::new() );
+///     let lstring_provider = LocalisationProviderSqlite3::try_new(
+///         "./l10n/", &language_tag_registry, false
+///     )?;
+///     let command_registry = Rc::new( CommandRegistry::new() );
+///     let localiser = Localiser::try_new(
+///         &icu_data_provider, &language_tag_registry, Box::new( lstring_provider ), &command_registry, true, true, "en-ZA",
+///     )?;
+///     let checker = PatternChecker::new( &localiser );
+///     let mismatches = checker.check_strings(
+///         "application",
+///         "example",
+///         "Found {count decimal} files in {path}.",
+///         &language_tag_registry.tag( "en-ZA" )?,
+///         "Trovati {count} file.",
+///         &language_tag_registry.tag( "it" )?,
+///     )?;
+///     assert_eq!( mismatches.len(), 2, "Should be 2 mismatches." );
+///     assert_eq!(
+///         mismatches[ 0 ].mismatch,
+///         MismatchType::PlaceholderType(
+///             "count".to_string(), "decimal".to_string(), "string".to_string()
+///         ),
+///         "Should be wrong type for count."
+///     );
+///     assert_eq!(
+///         mismatches[ 1 ].mismatch,
+///         MismatchType::MissingPlaceholder( "path".to_string() ),
+///         "Should be missing path."
+///     );
+///     Ok( () )
+/// }
+/// ```
+pub struct PatternChecker<'a> {
+    localiser: &'a Localiser,
+}
+
+impl<'a> PatternChecker<'a> {
+    /// Creates a `PatternChecker`, which uses the localisation provider, grammar and ICU data provider of the
+    /// supplied [`Localiser`].
+    pub fn new(localiser: &'a Localiser) -> Self {
+        PatternChecker { localiser }
+    }
+
+    /// Checks the strings of all the identifiers of the component.
+    ///
+    /// Identifiers without a string for the component's default language are skipped, as there is nothing to
+    /// compare against.
+    ///
+    /// Return of [`LocaliserError`] indicates there was an error in accessing the data repository, or obtaining the
+    /// plural rules of a language.
+    pub fn check_component(&self, component: &str) -> Result<Vec<PatternMismatch>, LocaliserError> {
+        #[cfg(feature = "logging")]
+        debug!("Checking patterns of component '{}'.", component);
+
+        let mut mismatches = Vec::<PatternMismatch>::new();
+        let provider = self.localiser.localisation_provider();
+        for identifier in provider.identifiers(component)?.iter() {
+            mismatches.append(&mut self.check_identifier(component, identifier)?);
+        }
+        Ok(mismatches)
+    }
+
+    /// Checks the strings of all the languages of the identifier of the component.
+    ///
+    /// Return of [`LocaliserError`] indicates there was an error in accessing the data repository, or obtaining the
+    /// plural rules of a language.
+    pub fn check_identifier(
+        &self,
+        component: &str,
+        identifier: &str,
+    ) -> Result<Vec<PatternMismatch>, LocaliserError> {
+        #[cfg(feature = "logging")]
+        debug!(
+            "Checking patterns of identifier '{}' of component '{}'.",
+            identifier, component
+        );
+
+        let mut mismatches = Vec::<PatternMismatch>::new();
+        let provider = self.localiser.localisation_provider();
+        let details = provider.identifier_details(component, identifier)?;
+        let Some((default_string, default_tag)) =
+            provider.string_exact_match(component, identifier, &details.default)?
+        else {
+            return Ok(mismatches);
+        };
+        let mut languages = details.languages;
+        languages.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        for language_tag in languages.iter() {
+            if *language_tag == default_tag {
+                continue;
+            }
+            let Some((string, tag)) =
+                provider.string_exact_match(component, identifier, language_tag)?
+            else {
+                continue;
+            };
+            mismatches.append(&mut self.check_strings(
+                component,
+                identifier,
+                default_string.as_str(),
+                &default_tag,
+                string.as_str(),
+                &tag,
+            )?);
+        }
+        Ok(mismatches)
+    }
+
+    /// Checks the `string` of the language `language_tag` against the string `default_string` of the default
+    /// language `default_tag`.
+    ///
+    /// Return of [`LocaliserError`] indicates there was an error obtaining the plural rules of the language.
+    pub fn check_strings(
+        &self,
+        component: &str,
+        identifier: &str,
+        default_string: &str,
+        default_tag: &RefCount<LanguageTag>,
+        string: &str,
+        language_tag: &RefCount<LanguageTag>,
+    ) -> Result<Vec<PatternMismatch>, LocaliserError> {
+        let mut mismatches = Vec::<PatternMismatch>::new();
+        let mut report = |mismatch: MismatchType,
+                          language_tag: &RefCount<LanguageTag>,
+                          default_span: Option<PatternSpan>,
+                          span: Option<PatternSpan>| {
+            mismatches.push(PatternMismatch {
+                component: component.to_string(),
+                identifier: identifier.to_string(),
+                language_tag: RefCount::clone(language_tag),
+                mismatch,
+                default_span,
+                span,
+            });
+        };
+        let default = match self.summary(default_string) {
+            Ok(summary) => summary,
+            Err(error) => {
+                report(MismatchType::Parse(error), default_tag, None, None);
+                return Ok(mismatches);
+            }
+        };
+        let translation = match self.summary(string) {
+            Ok(summary) => summary,
+            Err(error) => {
+                report(MismatchType::Parse(error), language_tag, None, None);
+                return Ok(mismatches);
+            }
+        };
+
+        // Placeholders of the default language.
+        for expected in default.placeholders.iter() {
+            let Some(found) = translation
+                .placeholders
+                .iter()
+                .find(|placeholder| placeholder.name == expected.name)
+            else {
+                report(
+                    MismatchType::MissingPlaceholder(expected.name.clone()),
+                    language_tag,
+                    Some(expected.span.clone()),
+                    None,
+                );
+                continue;
+            };
+            if found.keyword != expected.keyword {
+                report(
+                    MismatchType::PlaceholderType(
+                        expected.name.clone(),
+                        expected.keyword.clone(),
+                        found.keyword.clone(),
+                    ),
+                    language_tag,
+                    Some(expected.span.clone()),
+                    Some(found.span.clone()),
+                );
+                continue;
+            }
+            let valid = match found.keyword.as_str() {
+                "plural" => self.plural_categories(language_tag, false)?,
+                "ordinal" => self.plural_categories(language_tag, true)?,
+                "select" => expected.selectors.clone(),
+                _ => continue,
+            };
+
+            // As the formatter falls back to `other`, only `other` and the categories used by the default language's
+            // string are required of the plural categories.
+            let required = match found.keyword.as_str() {
+                "select" => valid.clone(),
+                _ => valid
+                    .iter()
+                    .filter(|category| {
+                        category.as_str() == "other" || expected.selectors.contains(category)
+                    })
+                    .cloned()
+                    .collect::<Vec<String>>(),
+            };
+            let missing = required
+                .iter()
+                .filter(|selector| !found.selectors.contains(selector))
+                .cloned()
+                .collect::<Vec<String>>();
+            if !missing.is_empty() {
+                report(
+                    MismatchType::MissingBranches(found.name.clone(), missing),
+                    language_tag,
+                    Some(expected.span.clone()),
+                    Some(found.span.clone()),
+                );
+            }
+            let unknown = found
+                .selectors
+                .iter()
+                .filter(|selector| !valid.contains(selector))
+                .cloned()
+                .collect::<Vec<String>>();
+            if !unknown.is_empty() {
+                report(
+                    MismatchType::UnknownBranches(found.name.clone(), unknown),
+                    language_tag,
+                    Some(expected.span.clone()),
+                    Some(found.span.clone()),
+                );
+            }
+        }
+        for found in translation.placeholders.iter() {
+            if !default
+                .placeholders
+                .iter()
+                .any(|placeholder| placeholder.name == found.name)
+            {
+                report(
+                    MismatchType::UnknownPlaceholder(found.name.clone()),
+                    language_tag,
+                    None,
+                    Some(found.span.clone()),
+                );
+            }
+        }
+
+        // Commands, which may be used multiple times within a string.
+        let mut commands = translation.commands.clone();
+        for (command, span) in default.commands.iter() {
+            match commands.iter().position(|(name, _)| name == command) {
+                Some(position) => {
+                    commands.remove(position);
+                }
+                None => report(
+                    MismatchType::MissingCommand(command.clone()),
+                    language_tag,
+                    Some(span.clone()),
+                    None,
+                ),
+            }
+        }
+        for (command, span) in commands {
+            report(
+                MismatchType::UnknownCommand(command),
+                language_tag,
+                None,
+                Some(span),
+            );
+        }
+        Ok(mismatches)
+    }
+
+    /// Obtain the placeholders of the string as pairs of placeholder name and keyword, where `string` is used for
+    /// placeholders having no keyword. Each placeholder is listed once, in the order of first appearance.
+    ///
+    /// Return of [`LocaliserError`] indicates the string could not be parsed.
+    pub fn placeholders(&self, string: &str) -> Result<Vec<(String, String)>, LocaliserError> {
+        let tree = Tree::try_new(
+            string,
+            self.localiser.grammar(),
+            self.localiser.icu_data_provider(),
+        )
+        .map_err(FormatterError::from)?;
+        let mut summary = Summary {
+            placeholders: Vec::<Placeholder>::new(),
+            commands: Vec::<(String, PatternSpan)>::new(),
+        };
+        summary_node(&tree, &0, &mut summary);
+        let mut placeholders = Vec::<(String, String)>::new();
+        for placeholder in summary.placeholders {
+            if !placeholders
+                .iter()
+                .any(|(name, _)| *name == placeholder.name)
+            {
+                placeholders.push((placeholder.name, placeholder.keyword));
+            }
+        }
+        Ok(placeholders)
+    }
+
+    // Internal methods
+
+    fn summary(&self, string: &str) -> Result<Summary, String> {
+        let tree = match Tree::try_new(
+            string,
+            self.localiser.grammar(),
+            self.localiser.icu_data_provider(),
+        ) {
+            Ok(tree) => tree,
+            Err(error) => return Err(error.to_string()),
+        };
+        let mut summary = Summary {
+            placeholders: Vec::<Placeholder>::new(),
+            commands: Vec::<(String, PatternSpan)>::new(),
+        };
+        summary_node(&tree, &0, &mut summary);
+        Ok(summary)
+    }
+
+    fn plural_categories(
+        &self,
+        language_tag: &RefCount<LanguageTag>,
+        ordinal: bool,
+    ) -> Result<Vec<String>, LocaliserError> {
+        let icu_language = self
+            .localiser
+            .language_tag_registry()
+            .icu_language(language_tag);
+        let data_locale = DataLocale::from(RefCount::as_ref(&icu_language));
+        let plurals = match ordinal {
+            true => Formatter::plural_rules_ordinal(self.localiser, &data_locale)?,
+            false => Formatter::plural_rules_cardinal(self.localiser, &data_locale)?,
+        };
+        Ok(plurals
+            .categories()
+            .map(|category| plural_category(category).to_string())
+            .collect::<Vec<String>>())
+    }
+}
+
+/// A mismatch found by the [`PatternChecker`] for a language of the identifier of a component.
+///
+/// The `default_span` refers to the pattern in the default language string, and `span` refers to the pattern in
+/// the string of the language. A span is `None` when the pattern does not exist in that string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternMismatch {
+    pub component: String,
+    pub identifier: String,
+    pub language_tag: RefCount<LanguageTag>,
+    pub mismatch: MismatchType,
+    pub default_span: Option<PatternSpan>,
+    pub span: Option<PatternSpan>,
+}
+
+impl Display for PatternMismatch {
+    fn fmt(&self, formatter: &mut FmtFormatter) -> FmtResult {
+        write!(
+            formatter,
+            "Component ‘{}’, identifier ‘{}’, language ‘{}’: {}",
+            self.component,
+            self.identifier,
+            self.language_tag.as_str(),
+            self.mismatch
+        )?;
+        if let Some(span) = self.span.as_ref() {
+            write!(formatter, " At grapheme {}.", span.start_grapheme)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `MismatchType` consists of the following:
+///
+/// * `Parse`: The string could not be parsed, containing the parsing error message,
+///
+/// * `MissingPlaceholder`: The placeholder of the default language is not used,
+///
+/// * `UnknownPlaceholder`: The placeholder does not exist in the default language,
+///
+/// * `PlaceholderType`: The placeholder has a different type (placeholder, expected, found),
+///
+/// * `MissingBranches`: The `plural`, `ordinal` or `select` placeholder is missing the listed selectors,
+///
+/// * `UnknownBranches`: The `plural`, `ordinal` or `select` placeholder has selectors that are never used,
+///
+/// * `MissingCommand`: The command of the default language is not used,
+///
+/// * `UnknownCommand`: The command does not exist in the default language.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum MismatchType {
+    Parse(String),
+    MissingPlaceholder(String),
+    UnknownPlaceholder(String),
+    PlaceholderType(String, String, String),
+    MissingBranches(String, Vec<String>),
+    UnknownBranches(String, Vec<String>),
+    MissingCommand(String),
+    UnknownCommand(String),
+}
+
+impl Display for MismatchType {
+    fn fmt(&self, formatter: &mut FmtFormatter) -> FmtResult {
+        match self {
+            MismatchType::Parse(error) => {
+                write!(formatter, "The string could not be parsed: [{}].", error)
+            }
+            MismatchType::MissingPlaceholder(placeholder) => write!(
+                formatter,
+                "The placeholder ‘{}’ of the default language is missing.",
+                placeholder
+            ),
+            MismatchType::UnknownPlaceholder(placeholder) => write!(
+                formatter,
+                "The placeholder ‘{}’ does not exist in the default language.",
+                placeholder
+            ),
+            MismatchType::PlaceholderType(placeholder, expected, found) => write!(
+                formatter,
+                "The placeholder ‘{}’ is of the type ‘{}’, though the default language uses ‘{}’.",
+                placeholder, found, expected
+            ),
+            MismatchType::MissingBranches(placeholder, selectors) => write!(
+                formatter,
+                "The placeholder ‘{}’ is missing the selectors: {}.",
+                placeholder,
+                selectors.join(", ")
+            ),
+            MismatchType::UnknownBranches(placeholder, selectors) => write!(
+                formatter,
+                "The placeholder ‘{}’ has selectors that are never used: {}.",
+                placeholder,
+                selectors.join(", ")
+            ),
+            MismatchType::MissingCommand(command) => write!(
+                formatter,
+                "The command ‘{}’ of the default language is missing.",
+                command
+            ),
+            MismatchType::UnknownCommand(command) => write!(
+                formatter,
+                "The command ‘{}’ does not exist in the default language.",
+                command
+            ),
+        }
+    }
+}
+
+/// The location of a pattern within a string, excluding the enclosing braces.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternSpan {
+    pub start_byte: usize,       // Start position in the string in terms of bytes.
+    pub end_byte: usize,         // End position in the string in terms of bytes.
+    pub start_grapheme: usize,   // Start position in the string in terms of graphemes.
+    pub length_graphemes: usize, // Length of the pattern in terms of graphemes.
+}
+
+// Internal structs and functions
+
+struct Summary {
+    placeholders: Vec<Placeholder>,
+    commands: Vec<(String, PatternSpan)>,
+}
+
+struct Placeholder {
+    name: String,
+    keyword: String,
+    selectors: Vec<String>,
+    span: PatternSpan,
+}
+
+fn summary_node(tree: &Tree, index: &usize, summary: &mut Summary) {
+    match tree.node_type(index) {
+        NodeType::Root | NodeType::NamedGroup | NodeType::NamedString | NodeType::String => {
+            for child in tree.children(index).iter() {
+                summary_node(tree, child, summary);
+            }
+        }
+        NodeType::Pattern => {
+            let children = tree.children(index);
+
+            // Markup tags are not placeholders, as they have no values.
+            if let Some(keyword) = children.get(1) {
+                if matches!(identifier(tree, keyword).as_str(), "open" | "close") {
+                    return;
+                }
+            }
+            let mut selectors = Vec::<String>::new();
+            for selector in children.iter().skip(2) {
+                if let Some(first) = tree.first(selector) {
+                    selectors.push(identifier(tree, first));
+                }
+            }
+            summary.placeholders.push(Placeholder {
+                name: identifier(tree, &children[0]),
+                keyword: match children.get(1) {
+                    None => "string".to_string(),
+                    Some(keyword) => identifier(tree, keyword),
+                },
+                selectors,
+                span: span(tree, index),
+            });
+        }
+        NodeType::Command => {
+            if let Some(command) = tree.first(index) {
+                summary
+                    .commands
+                    .push((identifier(tree, command), span(tree, index)));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn identifier(tree: &Tree, index: &usize) -> String {
+    match tree.tokens(index) {
+        None => String::new(),
+        Some(tokens) => tokens
+            .iter()
+            .map(|token| tree.token(token).string.as_str())
+            .collect::<String>(),
+    }
+}
+
+// The span covers all the tokens of the node and its descendants.
+fn span(tree: &Tree, index: &usize) -> PatternSpan {
+    let mut tokens = Vec::<usize>::new();
+    span_tokens(tree, index, &mut tokens);
+    let first = tokens.iter().min().map(|token| tree.token(token));
+    let last = tokens.iter().max().map(|token| tree.token(token));
+    match (first, last) {
+        (Some(first), Some(last)) => PatternSpan {
+            start_byte: first.start_byte,
+            end_byte: last.end_byte,
+            start_grapheme: first.start_grapheme,
+            length_graphemes: last.start_grapheme + last.length_graphemes - first.start_grapheme,
+        },
+        _ => PatternSpan {
+            start_byte: 0,
+            end_byte: 0,
+            start_grapheme: 0,
+            length_graphemes: 0,
+        },
+    }
+}
+
+fn span_tokens(tree: &Tree, index: &usize, tokens: &mut Vec<usize>) {
+    if let Some(node_tokens) = tree.tokens(index) {
+        tokens.extend(node_tokens.iter());
+    }
+    if matches!(
+        tree.node_type(index),
+        NodeType::Pattern | NodeType::Command | NodeType::Selector
+    ) {
+        for child in tree.children(index).iter() {
+            span_tokens(tree, child, tokens);
+        }
+    }
+}
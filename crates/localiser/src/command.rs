@@ -3,12 +3,18 @@
 
 use crate::CommandError;
 use i18n_utility::PlaceholderValue;
-use std::cell::RefCell;
 use std::collections::HashMap;
 use std::iter::FromIterator;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+#[cfg(not(feature = "sync"))]
+use std::cell::RefCell as MutCell;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::RwLock as MutCell;
+
 /// Registry for pattern commands callback functions.
 ///
 /// # Examples
@@ -32,7 +38,7 @@ use std::str::FromStr;
 /// ```
 #[allow(clippy::type_complexity)]
 pub struct CommandRegistry {
-    registry: RefCell<HashMap<String, fn(Vec<PlaceholderValue>) -> Result<String, CommandError>>>,
+    registry: MutCell<HashMap<String, fn(Vec<PlaceholderValue>) -> Result<String, CommandError>>>,
 }
 
 impl CommandRegistry {
@@ -59,7 +65,7 @@ impl CommandRegistry {
     /// ```
     pub fn new() -> Self {
         CommandRegistry {
-            registry: RefCell::new(HashMap::<
+            registry: MutCell::new(HashMap::<
                 String,
                 fn(Vec<PlaceholderValue>) -> Result<String, CommandError>,
             >::new()),
@@ -97,14 +103,16 @@ impl CommandRegistry {
         command: &str, //T,
         function: fn(Vec<PlaceholderValue>) -> Result<String, CommandError>,
     ) -> Result<(), CommandError> {
-        {
-            if self.registry.borrow().contains_key(command) {
-                return Err(CommandError::AlreadyExists(command.to_string()));
-            }
+        #[cfg(not(feature = "sync"))]
+        let mut binding = self.registry.borrow_mut();
+
+        #[cfg(feature = "sync")]
+        let mut binding = self.registry.write().unwrap();
+
+        if binding.contains_key(command) {
+            return Err(CommandError::AlreadyExists(command.to_string()));
         }
-        self.registry
-            .borrow_mut()
-            .insert(command.to_string(), function);
+        binding.insert(command.to_string(), function);
         Ok(())
     }
 
@@ -135,7 +143,12 @@ impl CommandRegistry {
         &self,
         command: &str, //T,
     ) -> Result<fn(Vec<PlaceholderValue>) -> Result<String, CommandError>, CommandError> {
+        #[cfg(not(feature = "sync"))]
         let binding = self.registry.borrow();
+
+        #[cfg(feature = "sync")]
+        let binding = self.registry.read().unwrap();
+
         let Some(result) = binding.get(command) else {
             return Err(CommandError::NotFound(command.to_string()));
         };
@@ -159,7 +172,13 @@ impl CommandRegistry {
     /// }
     /// ```
     pub fn list(&self) -> Vec<String> {
-        Vec::from_iter(self.registry.borrow().keys().map(|x| x.to_string()))
+        #[cfg(not(feature = "sync"))]
+        let binding = self.registry.borrow();
+
+        #[cfg(feature = "sync")]
+        let binding = self.registry.read().unwrap();
+
+        Vec::from_iter(binding.keys().map(|x| x.to_string()))
     }
 }
 
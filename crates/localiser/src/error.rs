@@ -13,6 +13,7 @@ use icu_datetime::DateTimeError;
 use icu_decimal::Error as DecimalError;
 use icu_locid::ParserError as IcuParserError;
 use icu_plurals::Error as PluralError;
+use icu_timezone::TimeZoneError;
 use std::num::ParseIntError;
 use std::{
     collections::HashMap,
@@ -26,7 +27,7 @@ use std::rc::Rc as RefCount;
 #[cfg(target_has_atomic = "ptr")]
 use std::sync::Arc as RefCount;
 
-use core::fmt::{Display, Formatter, Result};
+use core::fmt::{Display, Error as FmtError, Formatter, Result};
 
 /// The `TreeError` type consists of the follow:
 ///
@@ -709,12 +710,16 @@ impl From<ProviderError> for LocaliserError {
 ///
 /// * `DateTime`: Wraps the ICU4X date time error [`DateTimeError`],
 ///
+/// * `TimeZone`: Wraps the ICU4X time zone error [`TimeZoneError`],
+///
 /// * `PluralRules`: Wraps the ICU4X plural error [`PluralError`],
 ///
 /// * `FixedDecimal`: Wraps the ICU4X fixed error [`FixedDecimalError`],
 ///
 /// * `Command`: Wraps the `i18n_pattern`'s command error [`CommandError`],
 ///
+/// * `Write`: Wraps the error [`FmtError`] of the writer of `format_into()`,
+///
 /// * `InvalidRoot`: Indicates the token tree did not have a `NodeType::Root` node for the root,
 ///
 /// * `RetrieveChildren`: Indicates not children was retrieved,
@@ -761,6 +766,12 @@ impl From<ProviderError> for LocaliserError {
 ///
 /// * `NamedStringIdentifier`: Indicates the named string identifiers must be unique,
 ///
+/// * `UnknownTimeZone`: Indicates the IANA time zone of a zoned date-time without an offset is not in the bundled
+///   time zone database,
+///
+/// * `UnbalancedMarkup`: Indicates a markup tag of the string is not closed, or is closed without being the last
+///   opened markup tag,
+///
 /// * `NoIcuProvider`: Indicates no ICU4X data provider was provided,
 ///
 /// * `NeverReached`: Indicates this branch should never be reached. A serious bug has occurred.
@@ -774,9 +785,11 @@ pub enum FormatterError {
     ParseInt(ParseIntError),
     Decimal(DecimalError),
     DateTime(DateTimeError),
+    TimeZone(TimeZoneError),
     PluralRules(PluralError),
     FixedDecimal(FixedDecimalError),
     Command(CommandError),
+    Write(FmtError),
     NoGrammar,
     InvalidRoot,
     RetrieveChildren(NodeType),
@@ -801,6 +814,8 @@ pub enum FormatterError {
     PlaceholderValue(String, String),
     InvalidValue(String),
     NamedStringIdentifier(String),
+    UnknownTimeZone(String),
+    UnbalancedMarkup(String),
     NoIcuProvider,
     NeverReached,
 }
@@ -937,6 +952,24 @@ impl LocalisationTrait for FormatterError {
                     values: Some(values),
                 }
             }
+            FormatterError::TimeZone(ref error) => {
+                // Currently no localisation is available for this error type: TimeZoneError.
+                let mut values = HashMap::<String, PlaceholderValue>::new();
+                values.insert("type".to_string(), type_string);
+                values.insert(
+                    "variant".to_string(),
+                    PlaceholderValue::String("TimeZone".to_string()),
+                );
+                values.insert(
+                    "error".to_string(),
+                    PlaceholderValue::String(error.to_string()),
+                );
+                LocalisationData {
+                    component: "i18n_localiser".to_string(),
+                    identifier: "error_format_enum_embedded".to_string(),
+                    values: Some(values),
+                }
+            }
             FormatterError::PluralRules(ref error) => {
                 // Currently no localisation is available for this error type: PluralsError.
                 let mut values = HashMap::<String, PlaceholderValue>::new();
@@ -990,6 +1023,24 @@ impl LocalisationTrait for FormatterError {
                     values: Some(values),
                 }
             }
+            FormatterError::Write(ref error) => {
+                // Currently no localisation is available for this error type: core::fmt::Error.
+                let mut values = HashMap::<String, PlaceholderValue>::new();
+                values.insert("type".to_string(), type_string);
+                values.insert(
+                    "variant".to_string(),
+                    PlaceholderValue::String("Write".to_string()),
+                );
+                values.insert(
+                    "error".to_string(),
+                    PlaceholderValue::String(error.to_string()),
+                );
+                LocalisationData {
+                    component: "i18n_localiser".to_string(),
+                    identifier: "error_format_enum_embedded".to_string(),
+                    values: Some(values),
+                }
+            }
             FormatterError::InvalidRoot => {
                 let message = LocalisationData {
                     component: "i18n_localiser".to_string(),
@@ -1680,6 +1731,42 @@ impl LocalisationTrait for FormatterError {
                     values: Some(values),
                 }
             }
+            FormatterError::UnknownTimeZone(time_zone) => {
+                // Currently no localisation is available for this error variant.
+                let mut values = HashMap::<String, PlaceholderValue>::new();
+                values.insert("type".to_string(), type_string);
+                values.insert(
+                    "variant".to_string(),
+                    PlaceholderValue::String("UnknownTimeZone".to_string()),
+                );
+                values.insert(
+                    "error".to_string(),
+                    PlaceholderValue::String(time_zone.to_string()),
+                );
+                LocalisationData {
+                    component: "i18n_localiser".to_string(),
+                    identifier: "error_format_enum_embedded".to_string(),
+                    values: Some(values),
+                }
+            }
+            FormatterError::UnbalancedMarkup(name) => {
+                // Currently no localisation is available for this error variant.
+                let mut values = HashMap::<String, PlaceholderValue>::new();
+                values.insert("type".to_string(), type_string);
+                values.insert(
+                    "variant".to_string(),
+                    PlaceholderValue::String("UnbalancedMarkup".to_string()),
+                );
+                values.insert(
+                    "error".to_string(),
+                    PlaceholderValue::String(name.to_string()),
+                );
+                LocalisationData {
+                    component: "i18n_localiser".to_string(),
+                    identifier: "error_format_enum_embedded".to_string(),
+                    values: Some(values),
+                }
+            }
             FormatterError::NoIcuProvider => {
                 let message = LocalisationData {
                     component: "i18n_localiser".to_string(),
@@ -1752,6 +1839,9 @@ impl Display for FormatterError {
             FormatterError::DateTime( ref error ) => write!(
                 formatter, "FormatterError::DateTime: [{}].", error
             ),
+            FormatterError::TimeZone( ref error ) => write!(
+                formatter, "FormatterError::TimeZone: [{}].", error
+            ),
             FormatterError::PluralRules( ref error ) => write!(
                 formatter, "FormatterError::PluralRules: [{}].", error
             ),
@@ -1761,6 +1851,9 @@ impl Display for FormatterError {
             FormatterError::Command( ref error ) => write!(
                 formatter, "FormatterError::Command: [{}].", error
             ),
+            FormatterError::Write( ref error ) => write!(
+                formatter, "FormatterError::Write: [{}].", error
+            ),
             FormatterError::InvalidRoot => write!( formatter, "FormatterError::InvalidRoot: The tree root must be a ‘Root’ node." ),
             FormatterError::NoGrammar => write!( formatter, "FormatterError::NoGrammar: No grammar syntax characters was found in the provided localisation string." ),
             FormatterError::RetrieveChildren( node_type ) =>
@@ -1844,6 +1937,17 @@ impl Display for FormatterError {
                     "FormatterError::NamedStringIdentifier: The named string identifier ‘{}’ already exists. The identifiers must be unique and not ‘_’.",
                     identifier
                 ),
+            FormatterError::UnknownTimeZone( time_zone ) => write!(
+                formatter,
+                "FormatterError::UnknownTimeZone: The time zone ‘{}’ is not in the bundled time zone database.",
+                time_zone
+            ),
+            FormatterError::UnbalancedMarkup( name ) => write!(
+                formatter,
+                "FormatterError::UnbalancedMarkup: The markup tag ‘{}’ is not balanced. Each opening tag must be closed \
+                in the same string, after any opening tags that follow it are closed.",
+                name
+            ),
             FormatterError::NoIcuProvider => write!(
                 formatter,
                 "FormatterError::NoIcuProvider: Build error: At least one ICU4X data provider must be specified for the crate ‘i18n_icu’ using the \
@@ -1893,6 +1997,12 @@ impl From<DateTimeError> for FormatterError {
     }
 }
 
+impl From<TimeZoneError> for FormatterError {
+    fn from(error: TimeZoneError) -> FormatterError {
+        FormatterError::TimeZone(error)
+    }
+}
+
 impl From<PluralError> for FormatterError {
     fn from(error: PluralError) -> FormatterError {
         FormatterError::PluralRules(error)
@@ -1911,6 +2021,12 @@ impl From<CommandError> for FormatterError {
     }
 }
 
+impl From<FmtError> for FormatterError {
+    fn from(error: FmtError) -> FormatterError {
+        FormatterError::Write(error)
+    }
+}
+
 impl From<TreeError> for FormatterError {
     fn from(error: TreeError) -> FormatterError {
         FormatterError::Tree(error)
@@ -5,27 +5,49 @@
 
 #![allow(unexpected_cfgs)]
 
-use crate::{CommandRegistry, FormatterError, Localiser, NodeType, Tree};
+use crate::{
+    CommandRegistry, FormatSink, FormattedPart, FormatterError, Localiser, NodeType, PseudoLocale,
+    PseudoLocalisation, Tree, WriteSink,
+};
+use core::fmt::Write;
 use fixed_decimal::{DoublePrecision, FixedDecimal, SignDisplay};
 #[allow(unused_imports)]
 use i18n_lexer::{DataProvider, IcuDataProvider, Token, TokenType};
-use i18n_utility::{LanguageTag, PlaceholderValue};
+use i18n_utility::{Direction, LanguageTag, PlaceholderValue, ZonedDateTime};
 use icu_calendar::{
     types::{IsoHour, IsoMinute, IsoSecond, NanoSecond, Time},
-    Date, DateTime, Iso,
+    AnyCalendarKind, Date, DateTime, Iso,
 };
 use icu_datetime::{
+    options::components::{self, Day, Month, Numeric, Text, TimeZoneName, Year},
     options::length::{Bag, Date as DateLength, Time as TimeLength},
-    DateFormatter, DateTimeFormatter, TimeFormatter,
+    options::preferences::{self, HourCycle},
+    time_zone::TimeZoneFormatter,
+    DateFormatter, DateTimeFormatter, TimeFormatter, ZonedDateTimeFormatter,
 };
 use icu_decimal::{options, FixedDecimalFormatter};
+use icu_locid::extensions::unicode::{key, value, Value};
+
+#[cfg(feature = "icu_extended")]
+use icu_locid::extensions::unicode::Key;
 use icu_plurals::{PluralCategory, PluralRules};
 use icu_provider::prelude::DataLocale;
+use icu_timezone::{CustomTimeZone, GmtOffset, ZoneVariant};
+
+#[cfg(feature = "icu_compiled_data")]
+use icu_datetime::time_zone::TimeZoneFormatterOptions;
 
-#[cfg(not(feature = "extend"))]
+#[cfg(feature = "icu_compiled_data")]
+use icu_timezone::{MetazoneCalculator, TimeZoneIdMapper};
+use jiff::{
+    civil,
+    tz::{Offset, TimeZone},
+};
+
+#[cfg(not(feature = "icu_extended"))]
 use icu_locid::LanguageIdentifier as IcuLanguage;
 
-#[cfg(feature = "extend")]
+#[cfg(feature = "icu_extended")]
 use icu_locid::Locale as IcuLanguage;
 
 #[cfg(feature = "logging")]
@@ -46,17 +68,24 @@ pub(crate) struct Formatter {
     language_tag: RefCount<LanguageTag>,
     icu_language: RefCount<IcuLanguage>,
     patterns: HashMap<String, Vec<PatternPart>>,
-    numbers: Vec<String>,
+    numbers: usize, // The number of number signs, whose strings are per `format()` call.
     selectors: Vec<HashMap<String, String>>,
+    zero_digit: Option<char>,
 }
 
 impl Formatter {
     /// Creates a Formatter for a language string using parsing results.
     /// During the creation of the formatter for the supplied string, the semantic analyse is done.
+    ///
+    /// The `language_tag` is the language of the string, while the `requested` language tag is the language that was
+    /// requested, whose Unicode extension keywords (`-u-ca`, `-u-hc` and `-u-nu`) are applied as user preferences
+    /// (using feature `icu_extended`). The `-u-fw` keyword is not supported, as the ICU4X formatters have no week
+    /// based fields.
     pub fn try_new(
         localiser: &Localiser,
         string: &str,
         language_tag: &RefCount<LanguageTag>,
+        requested: &RefCount<LanguageTag>,
     ) -> Result<Formatter, FormatterError> {
         #[cfg(feature = "logging")]
         debug!(
@@ -75,22 +104,22 @@ impl Formatter {
         if !tree.has_grammar() {
             return Err(FormatterError::NoGrammar);
         }
-        let icu_language = localiser.language_tag_registry().icu_language(language_tag);
+        let icu_language = preferences(
+            localiser.language_tag_registry().icu_language(language_tag),
+            &localiser.language_tag_registry().icu_language(requested),
+        );
+
+        // The ICU data only contains the numbering systems commonly used for the language, thus the digits of other
+        // numbering systems of the `-u-nu` extension are substituted after formatting.
+        let zero_digit = DataLocale::from(RefCount::as_ref(&icu_language))
+            .get_unicode_ext(&key!("nu"))
+            .and_then(|value| zero_digit(value.to_string().as_str()));
         let mut patterns = HashMap::<String, Vec<PatternPart>>::new();
         patterns.insert("_".to_string(), Vec::<PatternPart>::new()); // Insert empty main pattern.
-        let mut numbers = Vec::<String>::new();
+        let mut numbers = 0usize;
         let mut selectors = Vec::<HashMap<String, String>>::new();
         let option_selectors = OptionSelectors {
             valid_plurals: vec!["zero", "one", "two", "few", "many", "other"],
-            calendars: vec![
-                "gregorian",
-                "buddhist",
-                "japanese",
-                "ethiopian",
-                "indian",
-                "coptic",
-                "iso",
-            ],
         };
         if tree.node_type(&0) != &NodeType::Root {
             return Err(FormatterError::InvalidRoot);
@@ -126,9 +155,8 @@ impl Formatter {
                     if node_type == &NodeType::Text {
                         part_text(&mut pattern, &tree, child)?;
                     } else if node_type == &NodeType::NumberSign {
-                        let len = numbers.len();
-                        numbers.push(String::new());
-                        pattern.push(PatternPart::NumberSign(len));
+                        pattern.push(PatternPart::NumberSign(numbers));
+                        numbers += 1;
                     } else if node_type == &NodeType::Pattern {
                         part_pattern(
                             &mut pattern,
@@ -137,7 +165,6 @@ impl Formatter {
                             child,
                             &mut selectors,
                             &option_selectors,
-                            &icu_language,
                         )?;
                     } else if node_type == &NodeType::Command {
                         part_command(&mut pattern, &tree, child, localiser.command_registry())?;
@@ -146,6 +173,7 @@ impl Formatter {
                         return Err(FormatterError::InvalidNode(NodeType::String));
                     }
                 }
+                markup_balance(&pattern)?;
                 patterns.insert(identifier_token.string.as_str().to_string(), pattern);
             }
         };
@@ -167,7 +195,6 @@ impl Formatter {
                     child,
                     &mut selectors,
                     &option_selectors,
-                    &icu_language,
                 )?;
             } else if tree.node_type(child) == &NodeType::Command {
                 part_command(&mut pattern, &tree, child, localiser.command_registry())?;
@@ -176,6 +203,7 @@ impl Formatter {
                 return Err(FormatterError::InvalidNode(NodeType::String));
             }
         }
+        markup_balance(&pattern)?;
         patterns.insert("_".to_string(), pattern);
         Ok(Formatter {
             language_tag: RefCount::clone(language_tag),
@@ -183,21 +211,81 @@ impl Formatter {
             patterns,
             numbers,
             selectors,
+            zero_digit,
         })
     }
 
     /// Format the language string with supplied values as [`HashMap`]`<`[`String`]`, `[`PlaceholderValue`]`>`.
     pub fn format(
-        &mut self,
+        &self,
         localiser: &Localiser,
         values: &HashMap<String, PlaceholderValue>,
     ) -> Result<(String, &RefCount<LanguageTag>), FormatterError> {
-        let pattern_string = self.format_pattern(localiser, values, "_")?;
-        Ok((pattern_string, &self.language_tag))
+        let mut string = String::new();
+        self.format_to_sink(localiser, values, &mut WriteSink(&mut string))?;
+        Ok((string, &self.language_tag))
+    }
+
+    /// Format the language string with supplied values directly into the `writer`, without allocating the
+    /// formatted string. An error of the `writer` is returned as `FormatterError::Write`.
+    pub fn format_into<W: Write + ?Sized>(
+        &self,
+        localiser: &Localiser,
+        values: &HashMap<String, PlaceholderValue>,
+        writer: &mut W,
+    ) -> Result<&RefCount<LanguageTag>, FormatterError> {
+        self.format_to_sink(localiser, values, &mut WriteSink(writer))?;
+        Ok(&self.language_tag)
+    }
+
+    /// Format the language string with supplied values into the [`FormattedPart`]s.
+    pub fn format_to_parts(
+        &self,
+        localiser: &Localiser,
+        values: &HashMap<String, PlaceholderValue>,
+    ) -> Result<(Vec<FormattedPart>, &RefCount<LanguageTag>), FormatterError> {
+        let mut parts = Vec::<FormattedPart>::new();
+        self.format_to_sink(localiser, values, &mut parts)?;
+        Ok((parts, &self.language_tag))
+    }
+
+    /// Transform the text of the patterns for the pseudo-locale, leaving the placeholders and commands intact, and
+    /// wrap the main pattern with the brackets. The `language_tag` of the pseudo-locale replaces the language of the
+    /// string, while the ICU data of the string's language is still used for formatting.
+    pub fn pseudo_localise(
+        &mut self,
+        pseudo: &PseudoLocalisation,
+        locale: PseudoLocale,
+        language_tag: &RefCount<LanguageTag>,
+    ) {
+        for pattern in self.patterns.values_mut() {
+            for part in pattern.iter_mut() {
+                if let PatternPart::Text(text) = part {
+                    *text = pseudo.transform(locale, text);
+                }
+            }
+        }
+        if let Some(pattern) = self.patterns.get_mut("_") {
+            pattern.insert(0, PatternPart::Text(pseudo.brackets.0.to_string()));
+            pattern.push(PatternPart::Text(pseudo.brackets.1.to_string()));
+        }
+        self.language_tag = RefCount::clone(language_tag);
     }
 
     // Internal methods
 
+    fn format_to_sink<S: FormatSink>(
+        &self,
+        localiser: &Localiser,
+        values: &HashMap<String, PlaceholderValue>,
+        sink: &mut S,
+    ) -> Result<(), FormatterError> {
+        // The numbers of the number signs are set while formatting, thus are kept per call, allowing the formatter to
+        // be shared.
+        let mut numbers = vec![FormattedNumber::default(); self.numbers];
+        self.format_pattern(localiser, values, &mut numbers, "_", sink)
+    }
+
     fn part_ref(&self, string: &str, index: &usize) -> Option<&PatternPart> {
         if let Some(pattern) = self.patterns.get(string) {
             if let Some(part) = pattern.get(*index) {
@@ -207,13 +295,14 @@ impl Formatter {
         None
     }
 
-    fn format_pattern(
-        &mut self,
+    fn format_pattern<S: FormatSink>(
+        &self,
         localiser: &Localiser,
         values: &HashMap<String, PlaceholderValue>,
+        numbers: &mut Vec<FormattedNumber>,
         named: &str,
-    ) -> Result<String, FormatterError> {
-        let mut string = String::new();
+        sink: &mut S,
+    ) -> Result<(), FormatterError> {
         let mut _len = 0usize;
         {
             let Some(pattern) = self.patterns.get(named) else {
@@ -221,6 +310,9 @@ impl Formatter {
             };
             _len = pattern.len();
         }
+
+        // The flag is read once, instead of locking the localiser for every placeholder.
+        let bidi_isolation = localiser.bidi_isolation();
         let mut i = 0usize;
         while i < _len {
             let Some(part) = self.part_ref(named, &i) else {
@@ -231,7 +323,7 @@ impl Formatter {
                     #[cfg(feature = "logging")]
                     trace!("Formatting PatternPart::Text");
 
-                    string.push_str(text.as_str());
+                    sink.text(text)?;
                 }
                 PatternPart::PatternString(placeholder) => {
                     #[cfg(feature = "logging")]
@@ -243,11 +335,25 @@ impl Formatter {
                             placeholder.to_string(),
                         ));
                     };
-                    match value {
-                        PlaceholderValue::String(value) => string.push_str(value),
-                        PlaceholderValue::TaggedString(value) => string.push_str(value.as_str()),
-                        PlaceholderValue::Localised(value, _) => string.push_str(value.as_str()),
+                    let (value, tag) = match value {
+                        PlaceholderValue::String(value) => (value.as_str(), None),
+                        PlaceholderValue::TaggedString(value) => {
+                            (value.as_str(), Some(value.tag()))
+                        }
+                        PlaceholderValue::Localised(value, tag) => (value.as_str(), Some(tag)),
                         _ => return Err(FormatterError::InvalidValue("PatternString".to_string())),
+                    };
+                    if bidi_isolation {
+                        let mut string = String::new();
+                        isolate(
+                            &mut string,
+                            value,
+                            tag.and_then(|tag| right_to_left(localiser, tag)),
+                            right_to_left(localiser, &self.language_tag),
+                        );
+                        sink.placeholder(placeholder, &string)?;
+                    } else {
+                        sink.placeholder(placeholder, value)?;
                     }
                 }
                 PatternPart::PatternDecimal {
@@ -270,63 +376,48 @@ impl Formatter {
                         options.grouping_strategy = group.unwrap();
                     }
                     let fdf = self.fixed_decimal_formatter(localiser, &data_locale, options)?;
-                    match value {
-                        PlaceholderValue::FixedDecimal(number) => {
-                            let fixed_decimal = &mut number.clone();
-                            if sign.is_some() {
-                                fixed_decimal.apply_sign_display(sign.unwrap());
-                            }
-                            let number_string = fdf.format(fixed_decimal).to_string();
-                            string.push_str(number_string.as_str());
-                        }
-                        PlaceholderValue::Unsigned(number) => {
-                            let mut fixed_decimal = FixedDecimal::from(*number);
-                            if sign.is_some() {
-                                fixed_decimal.apply_sign_display(sign.unwrap());
-                            }
-                            let number_string = fdf.format(&fixed_decimal).to_string();
-                            string.push_str(number_string.as_str());
-                        }
-                        PlaceholderValue::Integer(number) => {
-                            let mut fixed_decimal = FixedDecimal::from(*number);
-                            if sign.is_some() {
-                                fixed_decimal.apply_sign_display(sign.unwrap());
-                            }
-                            let number_string = fdf.format(&fixed_decimal).to_string();
-                            string.push_str(number_string.as_str());
-                        }
+                    let mut fixed_decimal = match value {
+                        PlaceholderValue::FixedDecimal(number) => number.clone(),
+                        PlaceholderValue::Unsigned(number) => FixedDecimal::from(*number),
+                        PlaceholderValue::Integer(number) => FixedDecimal::from(*number),
                         PlaceholderValue::Float(number) => {
                             // Precision is always Floating, for other precisions options use PlaceholderFixedDecimal
-                            let mut fixed_decimal =
-                                FixedDecimal::try_from_f64(*number, DoublePrecision::Floating)?;
-                            if sign.is_some() {
-                                fixed_decimal.apply_sign_display(sign.unwrap());
-                            }
-                            let number_string = fdf.format(&fixed_decimal).to_string();
-                            string.push_str(number_string.as_str());
+                            FixedDecimal::try_from_f64(*number, DoublePrecision::Floating)?
                         }
                         _ => {
                             return Err(FormatterError::InvalidValue("PatternDecimal".to_string()))
                         }
+                    };
+                    if let Some(sign) = sign {
+                        fixed_decimal.apply_sign_display(*sign);
                     }
+                    let number_string = self.digits(fdf.format(&fixed_decimal).to_string());
+                    sink.number(
+                        placeholder,
+                        number_string.as_str(),
+                        fraction_digits(&fixed_decimal),
+                    )?;
                 }
                 PatternPart::PatternDateTime {
                     placeholder,
                     length_date,
                     length_time,
                     calendar,
+                    zone,
+                    fields,
+                    hour,
                 } => {
                     #[cfg(feature = "logging")]
                     trace!("Formatting PatternPart::PatternDateTime");
 
                     // TODO: add more options as they become non-experimental.
-                    // TODO: implement hour for Time/DateTime when no longer experimental
                     let Some(value) = values.get(placeholder) else {
                         return Err(FormatterError::PlaceholderValue(
                             "PatternDateTime".to_string(),
                             placeholder.to_string(),
                         ));
                     };
+                    let mut string = String::new();
                     let length_date = match length_date {
                         None => DateLength::Medium,
                         Some(result) => *result,
@@ -336,59 +427,145 @@ impl Formatter {
                         Some(result) => *result,
                     };
                     let options = Bag::from_date_time_style(length_date, length_time);
-                    let data_locale = match calendar {
-                        None => DataLocale::from(RefCount::as_ref(&self.icu_language)),
-                        Some(locale) => DataLocale::from(locale),
-                    };
-                    match value {
-                        PlaceholderValue::DateTime(date_time) => {
-                            let dtf = self.date_time_formatter(localiser, &data_locale, options)?;
-                            let date_string = dtf.format_to_string(&date_time.to_any())?;
-                            string.push_str(date_string.as_str());
-                        }
-                        PlaceholderValue::Date(date) => {
-                            let df = self.date_formatter(localiser, &data_locale, length_date)?;
-                            let date_string = df.format_to_string(&date.to_any())?;
-                            string.push_str(date_string.as_str());
+                    let mut data_locale = DataLocale::from(RefCount::as_ref(&self.icu_language));
+
+                    // The `calendar` option replaces the `-u-ca` extension of the locale, thus the formatters convert
+                    // the ISO dates to the calendar.
+                    if let Some(kind) = calendar {
+                        data_locale.set_unicode_ext(key!("ca"), kind.as_bcp47_value());
+                    }
+
+                    // The `hour` option replaces the `-u-hc` extension of the locale.
+                    if let Some(hour_cycle) = hour {
+                        data_locale.set_unicode_ext(key!("hc"), hour_cycle_value(*hour_cycle));
+                    }
+
+                    // The `zone` option is only applicable to zoned date-times.
+                    if zone.is_some() && !matches!(value, PlaceholderValue::ZonedDateTime(_)) {
+                        return Err(FormatterError::InvalidValue("PatternDateTime".to_string()));
+                    }
+                    // The `fields` skeleton formats all values as date-times, where a date is at midnight and a
+                    // time is on an arbitrary date, thus only the fields of the skeleton are displayed.
+                    if let Some(fields) = fields {
+                        let mut fields = *fields;
+                        if let Some(hour_cycle) = hour {
+                            fields.preferences =
+                                Some(preferences::Bag::from_hour_cycle(*hour_cycle));
                         }
-                        PlaceholderValue::Time(time) => {
-                            let tf = self.time_formatter(localiser, &data_locale, length_time)?;
-                            let date_string = tf.format_to_string(time);
+                        if let PlaceholderValue::ZonedDateTime(zoned) = value {
+                            string.push_str(
+                                self.zoned_date_time_string(
+                                    localiser,
+                                    &data_locale,
+                                    zoned,
+                                    fields,
+                                    zone,
+                                )?
+                                .as_str(),
+                            );
+                        } else {
+                            let date_time = match value {
+                                PlaceholderValue::DateTime(date_time) => *date_time,
+                                PlaceholderValue::Date(date) => {
+                                    DateTime::new(*date, Time::midnight())
+                                }
+                                PlaceholderValue::Time(time) => {
+                                    DateTime::new(Date::try_new_iso_date(2000, 1, 1)?, *time)
+                                }
+                                PlaceholderValue::String(value) => decompose_iso_date_time(value)?,
+                                _ => {
+                                    return Err(FormatterError::InvalidValue(
+                                        "PatternDateTime".to_string(),
+                                    ))
+                                }
+                            };
+                            let dtf = self.components_formatter(localiser, &data_locale, fields)?;
+                            let date_string = self.digits(format_date_time(&dtf, &date_time)?);
                             string.push_str(date_string.as_str());
                         }
-                        PlaceholderValue::String(value) => {
-                            let date_time_strings: Vec<&str> = value.split('T').collect();
-                            if date_time_strings.len() == 2 {
-                                if date_time_strings[0].is_empty() {
-                                    // time only
-                                    let time: Time = decompose_iso_time(date_time_strings[1])?;
-                                    let tf =
-                                        self.time_formatter(localiser, &data_locale, length_time)?;
-                                    let date_string = tf.format_to_string(&time);
-                                    string.push_str(date_string.as_str());
-                                } else {
-                                    // date and time
-                                    let date: Date<Iso> = decompose_iso_date(date_time_strings[0])?;
-                                    let time: Time = decompose_iso_time(date_time_strings[1])?;
-                                    let date_time = DateTime::<Iso>::new(date, time);
-                                    let dtf =
-                                        self.date_time_formatter(localiser, &data_locale, options)?;
-                                    let date_string = dtf.format_to_string(&date_time.to_any())?;
-                                    string.push_str(date_string.as_str());
+                    } else {
+                        match value {
+                            // The time zone is part of the pattern, thus the lengths are expressed as components.
+                            PlaceholderValue::ZonedDateTime(zoned) => {
+                                let mut fields = length_components(length_date, length_time);
+                                if let Some(hour_cycle) = hour {
+                                    fields.preferences =
+                                        Some(preferences::Bag::from_hour_cycle(*hour_cycle));
                                 }
-                            } else {
-                                // date only
-                                let date: Date<Iso> = decompose_iso_date(date_time_strings[0])?;
+                                string.push_str(
+                                    self.zoned_date_time_string(
+                                        localiser,
+                                        &data_locale,
+                                        zoned,
+                                        fields,
+                                        zone,
+                                    )?
+                                    .as_str(),
+                                );
+                            }
+                            PlaceholderValue::DateTime(date_time) => {
+                                let dtf =
+                                    self.date_time_formatter(localiser, &data_locale, options)?;
+                                let date_string = self.digits(format_date_time(&dtf, date_time)?);
+                                string.push_str(date_string.as_str());
+                            }
+                            PlaceholderValue::Date(date) => {
                                 let df =
                                     self.date_formatter(localiser, &data_locale, length_date)?;
-                                let date_string = df.format_to_string(&date.to_any())?;
+                                let date_string = self.digits(format_date(&df, date)?);
                                 string.push_str(date_string.as_str());
                             }
-                        }
-                        _ => {
-                            return Err(FormatterError::InvalidValue("PatternDateTime".to_string()))
+                            PlaceholderValue::Time(time) => {
+                                let tf =
+                                    self.time_formatter(localiser, &data_locale, length_time)?;
+                                let date_string = self.digits(tf.format_to_string(time));
+                                string.push_str(date_string.as_str());
+                            }
+                            PlaceholderValue::String(value) => {
+                                let date_time_strings: Vec<&str> = value.split('T').collect();
+                                if date_time_strings.len() == 2 {
+                                    if date_time_strings[0].is_empty() {
+                                        // time only
+                                        let time: Time = decompose_iso_time(date_time_strings[1])?;
+                                        let tf = self.time_formatter(
+                                            localiser,
+                                            &data_locale,
+                                            length_time,
+                                        )?;
+                                        let date_string = self.digits(tf.format_to_string(&time));
+                                        string.push_str(date_string.as_str());
+                                    } else {
+                                        // date and time
+                                        let date: Date<Iso> =
+                                            decompose_iso_date(date_time_strings[0])?;
+                                        let time: Time = decompose_iso_time(date_time_strings[1])?;
+                                        let date_time = DateTime::<Iso>::new(date, time);
+                                        let dtf = self.date_time_formatter(
+                                            localiser,
+                                            &data_locale,
+                                            options,
+                                        )?;
+                                        let date_string =
+                                            self.digits(format_date_time(&dtf, &date_time)?);
+                                        string.push_str(date_string.as_str());
+                                    }
+                                } else {
+                                    // date only
+                                    let date: Date<Iso> = decompose_iso_date(date_time_strings[0])?;
+                                    let df =
+                                        self.date_formatter(localiser, &data_locale, length_date)?;
+                                    let date_string = self.digits(format_date(&df, &date)?);
+                                    string.push_str(date_string.as_str());
+                                }
+                            }
+                            _ => {
+                                return Err(FormatterError::InvalidValue(
+                                    "PatternDateTime".to_string(),
+                                ))
+                            }
                         }
                     }
+                    sink.placeholder(placeholder, string.as_str())?;
                 }
                 PatternPart::PatternComplex {
                     placeholder,
@@ -408,12 +585,15 @@ impl Formatter {
                     let data_locale = DataLocale::from(RefCount::as_ref(&self.icu_language));
                     match complex {
                         ComplexType::Plural => {
-                            let plurals = self.plural_rules_cardinal(localiser, &data_locale)?;
+                            let plurals =
+                                Formatter::plural_rules_cardinal(localiser, &data_locale)?;
                             match value {
                                 PlaceholderValue::FixedDecimal(number) => self.find_number_sign(
                                     localiser,
                                     values,
-                                    &mut string,
+                                    numbers,
+                                    sink,
+                                    placeholder,
                                     number,
                                     plurals,
                                     &data_locale,
@@ -424,7 +604,9 @@ impl Formatter {
                                     self.find_number_sign(
                                         localiser,
                                         values,
-                                        &mut string,
+                                        numbers,
+                                        sink,
+                                        placeholder,
                                         &fixed_decimal,
                                         plurals,
                                         &data_locale,
@@ -436,7 +618,9 @@ impl Formatter {
                                     self.find_number_sign(
                                         localiser,
                                         values,
-                                        &mut string,
+                                        numbers,
+                                        sink,
+                                        placeholder,
                                         &fixed_decimal,
                                         plurals,
                                         &data_locale,
@@ -451,7 +635,9 @@ impl Formatter {
                                     self.find_number_sign(
                                         localiser,
                                         values,
-                                        &mut string,
+                                        numbers,
+                                        sink,
+                                        placeholder,
                                         &fixed_decimal,
                                         plurals,
                                         &data_locale,
@@ -467,14 +653,16 @@ impl Formatter {
                         }
                         ComplexType::Ordinal => {
                             // Only positive integers and zero are allowed.
-                            let plurals = self.plural_rules_ordinal(localiser, &data_locale)?;
+                            let plurals = Formatter::plural_rules_ordinal(localiser, &data_locale)?;
                             match value {
                                 PlaceholderValue::Unsigned(number) => {
                                     let fixed_decimal = FixedDecimal::from(*number);
                                     self.find_number_sign(
                                         localiser,
                                         values,
-                                        &mut string,
+                                        numbers,
+                                        sink,
+                                        placeholder,
                                         &fixed_decimal,
                                         plurals,
                                         &data_locale,
@@ -494,7 +682,8 @@ impl Formatter {
                                     self.select(
                                         localiser,
                                         values,
-                                        &mut string,
+                                        numbers,
+                                        sink,
                                         value,
                                         *selectors_index,
                                     )?;
@@ -504,7 +693,8 @@ impl Formatter {
                                     self.select(
                                         localiser,
                                         values,
-                                        &mut string,
+                                        numbers,
+                                        sink,
                                         value.as_string(),
                                         *selectors_index,
                                     )?;
@@ -514,7 +704,8 @@ impl Formatter {
                                     self.select(
                                         localiser,
                                         values,
-                                        &mut string,
+                                        numbers,
+                                        sink,
                                         localised,
                                         *selectors_index,
                                     )?;
@@ -532,10 +723,10 @@ impl Formatter {
                     #[cfg(feature = "logging")]
                     trace!("Formatting PatternPart::NumberSign");
 
-                    let Some(number_string) = self.numbers.get(*index) else {
+                    let Some(number) = numbers.get(*index) else {
                         return Err(FormatterError::NumberSignString(*index));
                     };
-                    string.push_str(number_string.as_str());
+                    sink.number(&number.placeholder, &number.string, number.fraction)?;
                 }
                 PatternPart::Command { strings } => {
                     #[cfg(feature = "logging")]
@@ -569,20 +760,34 @@ impl Formatter {
                         }
                     }
                     let function = localiser.command_registry().command(command)?;
-                    string.push_str(&function(parameters)?);
+                    sink.command(command, &function(parameters)?)?;
+                }
+                PatternPart::MarkupOpen { name, options } => {
+                    #[cfg(feature = "logging")]
+                    trace!("Formatting PatternPart::MarkupOpen");
+
+                    sink.markup_open(name, options)?;
+                }
+                PatternPart::MarkupClose { name } => {
+                    #[cfg(feature = "logging")]
+                    trace!("Formatting PatternPart::MarkupClose");
+
+                    sink.markup_close(name)?;
                 }
             }
             i += 1;
         }
-        Ok(string)
+        Ok(())
     }
 
     #[allow(clippy::too_many_arguments)]
-    fn find_number_sign(
-        &mut self,
+    fn find_number_sign<S: FormatSink>(
+        &self,
         localiser: &Localiser,
         values: &HashMap<String, PlaceholderValue>,
-        string: &mut String,
+        numbers: &mut Vec<FormattedNumber>,
+        sink: &mut S,
+        placeholder: &str,
         fixed_decimal: &FixedDecimal,
         plurals: PluralRules,
         data_locale: &DataLocale,
@@ -592,7 +797,11 @@ impl Formatter {
 
         // Format number using graphemes of the locale.
         let fdf = self.fixed_decimal_formatter(localiser, data_locale, Default::default())?;
-        let number_string = fdf.format(fixed_decimal).to_string();
+        let number = FormattedNumber {
+            placeholder: placeholder.to_string(),
+            string: self.digits(fdf.format(fixed_decimal).to_string()),
+            fraction: fraction_digits(fixed_decimal),
+        };
         let category = plural_category(plurals.category_for(fixed_decimal)).to_string();
 
         // Get the named string, and locate number signs to update the string.
@@ -626,21 +835,20 @@ impl Formatter {
                     _part = Some(part);
                 }
                 if let PatternPart::NumberSign(index) = _part.unwrap() {
-                    self.update_number_part(*index, &number_string)?
+                    Formatter::update_number_part(numbers, *index, &number)?
                 }
                 i += 1;
             }
         }
-        let part_string = self.format_pattern(localiser, values, &_named)?;
-        string.push_str(part_string.as_str());
-        Ok(())
+        self.format_pattern(localiser, values, numbers, &_named, sink)
     }
 
-    fn select(
-        &mut self,
+    fn select<S: FormatSink>(
+        &self,
         localiser: &Localiser,
         values: &HashMap<String, PlaceholderValue>,
-        string: &mut String,
+        numbers: &mut Vec<FormattedNumber>,
+        sink: &mut S,
         string_value: &String,
         selectors_index: usize,
     ) -> Result<(), FormatterError> {
@@ -654,23 +862,37 @@ impl Formatter {
                 selectors_index,
             ));
         };
-        let part_string = self.format_pattern(localiser, values, &named.to_string())?;
-        string.push_str(part_string.as_str());
-        Ok(())
+        self.format_pattern(localiser, values, numbers, &named.to_string(), sink)
     }
 
     fn update_number_part(
-        &mut self,
+        numbers: &mut [FormattedNumber],
         index: usize,
-        number_string: &String,
+        number: &FormattedNumber,
     ) -> Result<(), FormatterError> {
-        let Some(number_string_mut) = self.numbers.get_mut(index) else {
+        let Some(number_mut) = numbers.get_mut(index) else {
             return Err(FormatterError::NumberSignString(index));
         };
-        *number_string_mut = number_string.to_string();
+        *number_mut = number.clone();
         Ok(())
     }
 
+    // Substitutes the ASCII digits with the digits of the `-u-nu` numbering system, when not supported by the ICU data.
+    fn digits(&self, string: String) -> String {
+        let Some(zero) = self.zero_digit else {
+            return string;
+        };
+        string
+            .chars()
+            .map(|character| match character.to_digit(10) {
+                Some(digit) if character.is_ascii_digit() => {
+                    char::from_u32(zero as u32 + digit).unwrap_or(character)
+                }
+                _ => character,
+            })
+            .collect()
+    }
+
     fn fixed_decimal_formatter(
         &self,
         localiser: &Localiser,
@@ -805,10 +1027,230 @@ impl Formatter {
         }
     }
 
-    fn plural_rules_cardinal(
+    // Formats the zoned date-time, where the locale's pattern places the time zone and the `specific` format is the
+    // default. ICU4X has no pattern field for the generic location format, thus for `location` the localised GMT
+    // format is placed by the pattern, and then replaced with the location format.
+    fn zoned_date_time_string(
+        &self,
+        localiser: &Localiser,
+        data_locale: &DataLocale,
+        zoned: &ZonedDateTime,
+        mut fields: components::Bag,
+        zone: &Option<ZoneFormat>,
+    ) -> Result<String, FormatterError> {
+        let zone = zone.unwrap_or(ZoneFormat::Specific);
+        fields.time_zone_name = Some(match zone {
+            ZoneFormat::Specific => TimeZoneName::LongSpecific,
+            ZoneFormat::Generic => TimeZoneName::LongGeneric,
+            ZoneFormat::Offset | ZoneFormat::Location => TimeZoneName::GmtOffset,
+        });
+        let time_zone = self.custom_time_zone(localiser, zoned)?;
+        let zdtf = self.zoned_date_time_formatter(localiser, data_locale, fields)?;
+        let mut string = zdtf.format_to_string(&zoned.date_time.to_any(), &time_zone)?;
+        if let ZoneFormat::Location = zone {
+            let offset = self
+                .time_zone_formatter(localiser, data_locale, ZoneFormat::Offset)?
+                .format_to_string(&time_zone);
+            let location = self
+                .time_zone_formatter(localiser, data_locale, ZoneFormat::Location)?
+                .format_to_string(&time_zone);
+            string = string.replacen(offset.as_str(), location.as_str(), 1);
+        }
+        Ok(self.digits(string))
+    }
+
+    fn zoned_date_time_formatter(
+        &self,
+        localiser: &Localiser,
+        _data_locale: &DataLocale,
+        _fields: components::Bag,
+    ) -> Result<ZonedDateTimeFormatter, FormatterError> {
+        match localiser.icu_data_provider().data_provider() {
+            #[cfg(feature = "icu_compiled_data")]
+            DataProvider::Internal => Ok(ZonedDateTimeFormatter::try_new_experimental(
+                _data_locale,
+                _fields.into(),
+                TimeZoneFormatterOptions::default(),
+            )?),
+
+            #[cfg(feature = "blob")]
+            DataProvider::Blob(provider) => {
+                Ok(ZonedDateTimeFormatter::try_new_experimental_unstable(
+                    &provider.as_deserializing(),
+                    _data_locale,
+                    _fields.into(),
+                    TimeZoneFormatterOptions::default(),
+                )?)
+            }
+
+            #[cfg(feature = "fs")]
+            DataProvider::Fs(provider) => {
+                Ok(ZonedDateTimeFormatter::try_new_experimental_unstable(
+                    &provider.as_deserializing(),
+                    _data_locale,
+                    _fields.into(),
+                    TimeZoneFormatterOptions::default(),
+                )?)
+            }
+
+            #[allow(unreachable_patterns)]
+            _ => Err(FormatterError::NoIcuProvider),
+        }
+    }
+
+    fn components_formatter(
         &self,
         localiser: &Localiser,
         _data_locale: &DataLocale,
+        _fields: components::Bag,
+    ) -> Result<DateTimeFormatter, FormatterError> {
+        match localiser.icu_data_provider().data_provider() {
+            #[cfg(feature = "icu_compiled_data")]
+            DataProvider::Internal => Ok(DateTimeFormatter::try_new_experimental(
+                _data_locale,
+                _fields.into(),
+            )?),
+
+            #[cfg(feature = "blob")]
+            DataProvider::Blob(provider) => Ok(
+                DateTimeFormatter::try_new_experimental_with_buffer_provider(
+                    provider,
+                    _data_locale,
+                    _fields.into(),
+                )?,
+            ),
+
+            #[cfg(feature = "fs")]
+            DataProvider::Fs(provider) => Ok(
+                DateTimeFormatter::try_new_experimental_with_buffer_provider(
+                    provider,
+                    _data_locale,
+                    _fields.into(),
+                )?,
+            ),
+
+            #[allow(unreachable_patterns)]
+            _ => Err(FormatterError::NoIcuProvider),
+        }
+    }
+
+    // Builds the ICU4X time zone of the zoned date-time, where the IANA identifier is mapped to the BCP 47
+    // identifier to obtain the metazone in effect at the date-time. An unknown IANA identifier is ignored when the
+    // offset is supplied, thus the formatter falls back to the localised GMT format.
+    fn custom_time_zone(
+        &self,
+        localiser: &Localiser,
+        zoned: &ZonedDateTime,
+    ) -> Result<CustomTimeZone, FormatterError> {
+        let (offset, daylight) = zone_offset(zoned)?;
+        let mut time_zone =
+            CustomTimeZone::new_with_offset(GmtOffset::try_from_offset_seconds(offset)?);
+        time_zone.zone_variant = daylight.map(|daylight| match daylight {
+            true => ZoneVariant::daylight(),
+            false => ZoneVariant::standard(),
+        });
+        let Some(_iana) = zoned.time_zone.as_ref() else {
+            return Ok(time_zone);
+        };
+        match localiser.icu_data_provider().data_provider() {
+            #[cfg(feature = "icu_compiled_data")]
+            DataProvider::Internal => {
+                time_zone.time_zone_id = TimeZoneIdMapper::new().as_borrowed().iana_to_bcp47(_iana);
+                time_zone.maybe_calculate_metazone(&MetazoneCalculator::new(), &zoned.date_time);
+                Ok(time_zone)
+            }
+
+            #[cfg(feature = "blob")]
+            DataProvider::Blob(provider) => {
+                time_zone.time_zone_id = TimeZoneIdMapper::try_new_with_buffer_provider(provider)?
+                    .as_borrowed()
+                    .iana_to_bcp47(_iana);
+                time_zone.maybe_calculate_metazone(
+                    &MetazoneCalculator::try_new_with_buffer_provider(provider)?,
+                    &zoned.date_time,
+                );
+                Ok(time_zone)
+            }
+
+            #[cfg(feature = "fs")]
+            DataProvider::Fs(provider) => {
+                time_zone.time_zone_id = TimeZoneIdMapper::try_new_with_buffer_provider(provider)?
+                    .as_borrowed()
+                    .iana_to_bcp47(_iana);
+                time_zone.maybe_calculate_metazone(
+                    &MetazoneCalculator::try_new_with_buffer_provider(provider)?,
+                    &zoned.date_time,
+                );
+                Ok(time_zone)
+            }
+
+            #[allow(unreachable_patterns)]
+            _ => Err(FormatterError::NoIcuProvider),
+        }
+    }
+
+    fn time_zone_formatter(
+        &self,
+        localiser: &Localiser,
+        _data_locale: &DataLocale,
+        _zone: ZoneFormat,
+    ) -> Result<TimeZoneFormatter, FormatterError> {
+        match localiser.icu_data_provider().data_provider() {
+            #[cfg(feature = "icu_compiled_data")]
+            DataProvider::Internal => {
+                let mut formatter =
+                    TimeZoneFormatter::try_new(_data_locale, TimeZoneFormatterOptions::default())?;
+                match _zone {
+                    ZoneFormat::Specific => formatter.include_specific_non_location_long()?,
+                    ZoneFormat::Generic => formatter.include_generic_non_location_long()?,
+                    ZoneFormat::Offset => formatter.include_localized_gmt_format()?,
+                    ZoneFormat::Location => formatter.include_generic_location_format()?,
+                };
+                Ok(formatter)
+            }
+
+            #[cfg(feature = "blob")]
+            DataProvider::Blob(provider) => {
+                let mut formatter = TimeZoneFormatter::try_new_with_buffer_provider(
+                    provider,
+                    _data_locale,
+                    TimeZoneFormatterOptions::default(),
+                )?;
+                let provider = provider.as_deserializing();
+                match _zone {
+                    ZoneFormat::Specific => formatter.load_specific_non_location_long(&provider)?,
+                    ZoneFormat::Generic => formatter.load_generic_non_location_long(&provider)?,
+                    ZoneFormat::Offset => formatter.load_localized_gmt_format()?,
+                    ZoneFormat::Location => formatter.load_generic_location_format(&provider)?,
+                };
+                Ok(formatter)
+            }
+
+            #[cfg(feature = "fs")]
+            DataProvider::Fs(provider) => {
+                let mut formatter = TimeZoneFormatter::try_new_with_buffer_provider(
+                    provider,
+                    _data_locale,
+                    TimeZoneFormatterOptions::default(),
+                )?;
+                let provider = provider.as_deserializing();
+                match _zone {
+                    ZoneFormat::Specific => formatter.load_specific_non_location_long(&provider)?,
+                    ZoneFormat::Generic => formatter.load_generic_non_location_long(&provider)?,
+                    ZoneFormat::Offset => formatter.load_localized_gmt_format()?,
+                    ZoneFormat::Location => formatter.load_generic_location_format(&provider)?,
+                };
+                Ok(formatter)
+            }
+
+            #[allow(unreachable_patterns)]
+            _ => Err(FormatterError::NoIcuProvider),
+        }
+    }
+
+    pub(crate) fn plural_rules_cardinal(
+        localiser: &Localiser,
+        _data_locale: &DataLocale,
     ) -> Result<PluralRules, FormatterError> {
         match localiser.icu_data_provider().data_provider() {
             #[cfg(feature = "icu_compiled_data")]
@@ -831,8 +1273,7 @@ impl Formatter {
         }
     }
 
-    fn plural_rules_ordinal(
-        &self,
+    pub(crate) fn plural_rules_ordinal(
         localiser: &Localiser,
         _data_locale: &DataLocale,
     ) -> Result<PluralRules, FormatterError> {
@@ -1016,8 +1457,30 @@ pub fn decompose_iso_time(string: &str) -> Result<Time, FormatterError> {
     Ok(Time::new(hour, minute, second, nano))
 }
 
+// Decomposes an ISO date, time or date-time string into a date-time, for the `fields` skeleton. The date of a time is
+// the arbitrary date 2000-01-01, while the time of a date is midnight.
+fn decompose_iso_date_time(string: &str) -> Result<DateTime<Iso>, FormatterError> {
+    let date_time_strings: Vec<&str> = string.split('T').collect();
+    let date = if date_time_strings[0].is_empty() {
+        Date::try_new_iso_date(2000, 1, 1)?
+    } else {
+        decompose_iso_date(date_time_strings[0])?
+    };
+    let time = if date_time_strings.len() == 2 {
+        decompose_iso_time(date_time_strings[1])?
+    } else {
+        Time::midnight()
+    };
+    Ok(DateTime::new(date, time))
+}
+
 // Internal structures, enums, etc.
 
+const LEFT_TO_RIGHT_ISOLATE: char = '\u{2066}';
+const RIGHT_TO_LEFT_ISOLATE: char = '\u{2067}';
+const FIRST_STRONG_ISOLATE: char = '\u{2068}';
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
 fn part_text(
     pattern: &mut Vec<PatternPart>,
     tree: &Tree,
@@ -1041,7 +1504,6 @@ fn part_pattern(
     index: &usize,
     selectors: &mut Vec<HashMap<String, String>>,
     option_selectors: &OptionSelectors,
-    icu_language: &RefCount<IcuLanguage>,
 ) -> Result<(), FormatterError> {
     #[cfg(feature = "logging")]
     trace!("Processing pattern node.");
@@ -1104,69 +1566,48 @@ fn part_pattern(
         let strings = pattern_selectors(tree, index)?;
         let mut length_date: Option<DateLength> = None;
         let mut length_time: Option<TimeLength> = None;
-        let mut calendar: Option<IcuLanguage> = None;
+        let mut calendar: Option<AnyCalendarKind> = None;
+        let mut zone: Option<ZoneFormat> = None;
+        let mut fields: Option<components::Bag> = None;
+        let mut hour: Option<HourCycle> = None;
         for (key, value) in strings.iter() {
             if key.as_str() == "date" {
                 length_date = Some(date_length(value.as_str())?);
             } else if key.as_str() == "time" {
                 length_time = Some(time_length(value.as_str())?);
             } else if key.as_str() == "calendar" {
-                if !option_selectors.calendars.contains(&value.as_str()) {
-                    return Err(FormatterError::InvalidOption(
-                        value.as_str().to_string(),
-                        "ordinal".to_string(),
-                        placeholder_token.string.as_str().to_string(),
-                    ));
-                }
-                let mut new_calendar = "-u-ca-".to_string();
-                new_calendar.push_str(value.as_str());
-                let mut locale_string = icu_language.to_string();
-                match locale_string.find("-u-ca-") {
-                    None => {
-                        locale_string.push_str(new_calendar.as_str());
-                    }
-                    Some(tag_position) => {
-                        let mut hyphens = locale_string.match_indices('-');
-                        while let Some(pair) = hyphens.next() {
-                            if pair.0 == tag_position {
-                                hyphens.next(); // There is an experimental advance_by( 2 ) to replace two next()
-                                hyphens.next();
-                                match hyphens.next() {
-                                    None => {
-                                        // The calendar tag is last tag
-                                        let old_calendar =
-                                            locale_string.get(tag_position..).unwrap();
-                                        locale_string = locale_string
-                                            .replace(old_calendar, new_calendar.as_str());
-                                    }
-                                    Some(end) => {
-                                        new_calendar.push('-');
-                                        let old_calendar =
-                                            locale_string.get(tag_position..=end.0).unwrap();
-                                        locale_string = locale_string
-                                            .replace(old_calendar, new_calendar.as_str());
-                                    }
-                                }
-                                break;
-                            }
-                        }
-                    }
-                }
-                let calendar_locale = locale_string.parse()?;
-                calendar = Some(calendar_locale);
+                calendar = Some(calendar_kind(value.as_str())?);
+            } else if key.as_str() == "zone" {
+                zone = Some(zone_format(value.as_str())?);
+            } else if key.as_str() == "fields" {
+                fields = Some(components_bag(value.as_str())?);
+            } else if key.as_str() == "hour" {
+                hour = Some(hour_cycle(value.as_str())?);
             } else {
                 return Err(FormatterError::InvalidOption(
-                    value.as_str().to_string(),
-                    "date-time".to_string(),
+                    key.as_str().to_string(),
+                    "date_time".to_string(),
                     placeholder_token.string.as_str().to_string(),
                 ));
             }
         }
+
+        // The `fields` skeleton replaces the `date` and `time` lengths.
+        if fields.is_some() && (length_date.is_some() || length_time.is_some()) {
+            return Err(FormatterError::InvalidOption(
+                "fields".to_string(),
+                "date_time".to_string(),
+                placeholder_token.string.as_str().to_string(),
+            ));
+        }
         pattern.push(PatternPart::PatternDateTime {
             placeholder: placeholder_token.string.to_string(),
             length_date,
             length_time,
             calendar,
+            zone,
+            fields,
+            hour,
         });
     } else if keyword_token.string.as_str() == "ordinal" {
         let strings = pattern_selectors(tree, index)?;
@@ -1256,6 +1697,23 @@ fn part_pattern(
             complex: ComplexType::Select,
             selectors: len,
         });
+    } else if keyword_token.string.as_str() == "open" {
+        // The options are passed through to the markup.
+        pattern.push(PatternPart::MarkupOpen {
+            name: placeholder_token.string.to_string(),
+            options: pattern_selectors(tree, index)?,
+        });
+    } else if keyword_token.string.as_str() == "close" {
+        if let Some((key, _)) = pattern_selectors(tree, index)?.iter().next() {
+            return Err(FormatterError::InvalidOption(
+                key.as_str().to_string(),
+                "close".to_string(),
+                placeholder_token.string.as_str().to_string(),
+            ));
+        }
+        pattern.push(PatternPart::MarkupClose {
+            name: placeholder_token.string.to_string(),
+        });
     } else {
         return Err(FormatterError::InvalidKeyword(
             keyword_token.string.as_str().to_string(),
@@ -1265,6 +1723,25 @@ fn part_pattern(
     Ok(())
 }
 
+// Check the markup tags of the string are balanced, each closing tag closing the last unclosed opening tag of the
+// same name.
+fn markup_balance(pattern: &[PatternPart]) -> Result<(), FormatterError> {
+    let mut open = Vec::<&String>::new();
+    for part in pattern.iter() {
+        match part {
+            PatternPart::MarkupOpen { name, .. } => open.push(name),
+            PatternPart::MarkupClose { name } if open.pop() != Some(name) => {
+                return Err(FormatterError::UnbalancedMarkup(name.to_string()));
+            }
+            _ => {}
+        }
+    }
+    if let Some(name) = open.pop() {
+        return Err(FormatterError::UnbalancedMarkup(name.to_string()));
+    }
+    Ok(())
+}
+
 // Commands always returns static text
 fn part_command(
     pattern: &mut Vec<PatternPart>,
@@ -1347,7 +1824,7 @@ fn pattern_selectors(
             return Err(FormatterError::NodeNotFound(NodeType::Identifier));
         }
         let first_token = tree.token(&tree.tokens(first).unwrap()[0]);
-        let last = tree.first(selector).unwrap();
+        let last = tree.last(selector).unwrap();
         if tree.node_type(last) != &NodeType::Identifier {
             return Err(FormatterError::NodeNotFound(NodeType::Identifier));
         }
@@ -1360,7 +1837,49 @@ fn pattern_selectors(
     Ok(pairs)
 }
 
-fn plural_category(category: PluralCategory) -> &'static str {
+// The number of fraction digits displayed by the formatted number.
+fn fraction_digits(fixed_decimal: &FixedDecimal) -> usize {
+    (-*fixed_decimal.magnitude_range().start()).max(0) as usize
+}
+
+// The horizontal direction of the script of the language, where `true` is right-to-left, and `None` indicates the
+// direction is unknown.
+fn right_to_left(localiser: &Localiser, language_tag: &RefCount<LanguageTag>) -> Option<bool> {
+    let script_data = localiser.script_data_for_language_tag(language_tag)?;
+    let directions = script_data.directions.first()?.directions();
+    [directions.0, directions.1]
+        .iter()
+        .find_map(|direction| match direction {
+            Direction::LeftToRight => Some(false),
+            Direction::RightToLeft => Some(true),
+            _ => None,
+        })
+}
+
+// Wrap the value with the Unicode isolate of the value's direction, when it differs from the direction of the
+// pattern. A value of unknown direction is wrapped with the first strong isolate (FSI), thus the direction is
+// determined by the value's first strong character.
+fn isolate(
+    string: &mut String,
+    value: &str,
+    value_direction: Option<bool>,
+    direction: Option<bool>,
+) {
+    let isolate = match value_direction {
+        None => FIRST_STRONG_ISOLATE,
+        Some(right_to_left) if Some(right_to_left) == direction => {
+            string.push_str(value);
+            return;
+        }
+        Some(true) => RIGHT_TO_LEFT_ISOLATE,
+        Some(false) => LEFT_TO_RIGHT_ISOLATE,
+    };
+    string.push(isolate);
+    string.push_str(value);
+    string.push(POP_DIRECTIONAL_ISOLATE);
+}
+
+pub(crate) fn plural_category(category: PluralCategory) -> &'static str {
     match category {
         PluralCategory::Zero => "zero",
         PluralCategory::One => "one",
@@ -1371,6 +1890,191 @@ fn plural_category(category: PluralCategory) -> &'static str {
     }
 }
 
+// Formats the ISO date, which the date formatter converts to the calendar of its locale.
+fn format_date(formatter: &DateFormatter, date: &Date<Iso>) -> Result<String, FormatterError> {
+    Ok(formatter.format_to_string(&date.to_any())?)
+}
+
+// Formats the ISO date-time, which the date-time formatter converts to the calendar of its locale.
+fn format_date_time(
+    formatter: &DateTimeFormatter,
+    date_time: &DateTime<Iso>,
+) -> Result<String, FormatterError> {
+    Ok(formatter.format_to_string(&date_time.to_any())?)
+}
+
+// Completes the UTC offset and the daylight saving time indicator of the zoned date-time using the bundled time zone
+// database, where the supplied values take precedence.
+fn zone_offset(zoned: &ZonedDateTime) -> Result<(i32, Option<bool>), FormatterError> {
+    let time_zone = match zoned.time_zone.as_ref() {
+        Some(iana) if zoned.offset.is_none() || zoned.daylight.is_none() => {
+            TimeZone::get(iana).ok()
+        }
+        _ => None,
+    };
+    let Some(time_zone) = time_zone else {
+        return match zoned.offset {
+            Some(offset) => Ok((offset, zoned.daylight)),
+            None => Err(FormatterError::UnknownTimeZone(
+                zoned.time_zone.clone().unwrap_or_default(),
+            )),
+        };
+    };
+    let date = &zoned.date_time.date;
+    let time = &zoned.date_time.time;
+    let Ok(year) = i16::try_from(date.year().number) else {
+        return Err(FormatterError::InvalidValue("PatternDateTime".to_string()));
+    };
+    let timestamp = civil::DateTime::new(
+        year,
+        date.month().ordinal as i8,
+        date.day_of_month().0 as i8,
+        time.hour.number() as i8,
+        time.minute.number() as i8,
+        time.second.number() as i8,
+        0,
+    )
+    .and_then(|date_time| match zoned.offset {
+        Some(offset) => Offset::from_seconds(offset)?.to_timestamp(date_time),
+        None => time_zone.to_ambiguous_timestamp(date_time).compatible(),
+    })
+    .map_err(|_| FormatterError::InvalidValue("PatternDateTime".to_string()))?;
+    let info = time_zone.to_offset_info(timestamp);
+    Ok((
+        zoned.offset.unwrap_or(info.offset().seconds()),
+        Some(zoned.daylight.unwrap_or(info.dst().is_dst())),
+    ))
+}
+
+// The components of the date and time lengths, as the zoned date-time formatter needs the time zone in the pattern.
+fn length_components(date: DateLength, time: TimeLength) -> components::Bag {
+    let mut fields = components::Bag::empty();
+    fields.year = Some(Year::Numeric);
+    match date {
+        DateLength::Full => {
+            fields.weekday = Some(Text::Long);
+            fields.month = Some(Month::Long);
+            fields.day = Some(Day::NumericDayOfMonth);
+        }
+        DateLength::Long => {
+            fields.month = Some(Month::Long);
+            fields.day = Some(Day::NumericDayOfMonth);
+        }
+        DateLength::Short => {
+            fields.month = Some(Month::TwoDigit);
+            fields.day = Some(Day::TwoDigitDayOfMonth);
+        }
+        _ => {
+            fields.month = Some(Month::Short);
+            fields.day = Some(Day::NumericDayOfMonth);
+        }
+    }
+    fields.hour = Some(Numeric::Numeric);
+    fields.minute = Some(Numeric::TwoDigit);
+    if !matches!(time, TimeLength::Short) {
+        fields.second = Some(Numeric::TwoDigit);
+    }
+    fields
+}
+
+// The Unicode extension keywords of the requested language tag are the user's preferences, thus are applied to the
+// language of the pattern string, which may differ from the requested language.
+#[cfg(feature = "icu_extended")]
+fn preferences(
+    icu_language: RefCount<IcuLanguage>,
+    requested: &IcuLanguage,
+) -> RefCount<IcuLanguage> {
+    const PREFERENCES: [Key; 3] = [key!("ca"), key!("hc"), key!("nu")];
+    let mut locale = IcuLanguage::clone(&icu_language);
+    for key in PREFERENCES {
+        if let Some(value) = requested.extensions.unicode.keywords.get(&key) {
+            locale.extensions.unicode.keywords.set(key, value.clone());
+        }
+    }
+    if locale == *icu_language {
+        return icu_language;
+    }
+    RefCount::new(locale)
+}
+
+#[cfg(not(feature = "icu_extended"))]
+fn preferences(
+    icu_language: RefCount<IcuLanguage>,
+    _requested: &IcuLanguage,
+) -> RefCount<IcuLanguage> {
+    icu_language
+}
+
+// The zero digit of the numeric numbering systems of CLDR, excluding `latn`. Numbering systems that are algorithmic
+// or whose digits are not contiguous code points (such as `hanidec`) are not supported.
+fn zero_digit(numbering_system: &str) -> Option<char> {
+    let zero = match numbering_system {
+        "adlm" => 0x1E950,
+        "ahom" => 0x11730,
+        "arab" => 0x0660,
+        "arabext" => 0x06F0,
+        "bali" => 0x1B50,
+        "beng" => 0x09E6,
+        "bhks" => 0x11C50,
+        "brah" => 0x11066,
+        "cakm" => 0x11136,
+        "cham" => 0xAA50,
+        "deva" => 0x0966,
+        "diak" => 0x11950,
+        "fullwide" => 0xFF10,
+        "gong" => 0x11DA0,
+        "gonm" => 0x11D50,
+        "gujr" => 0x0AE6,
+        "guru" => 0x0A66,
+        "hmng" => 0x16B50,
+        "hmnp" => 0x1E140,
+        "java" => 0xA9D0,
+        "kali" => 0xA900,
+        "kawi" => 0x11F50,
+        "khmr" => 0x17E0,
+        "knda" => 0x0CE6,
+        "lana" => 0x1A80,
+        "lanatham" => 0x1A90,
+        "laoo" => 0x0ED0,
+        "lepc" => 0x1C40,
+        "limb" => 0x1946,
+        "mlym" => 0x0D66,
+        "modi" => 0x11650,
+        "mong" => 0x1810,
+        "mroo" => 0x16A60,
+        "mtei" => 0xABF0,
+        "mymr" => 0x1040,
+        "mymrshan" => 0x1090,
+        "mymrtlng" => 0xA9F0,
+        "nagm" => 0x1E4F0,
+        "newa" => 0x11450,
+        "nkoo" => 0x07C0,
+        "olck" => 0x1C50,
+        "orya" => 0x0B66,
+        "osma" => 0x104A0,
+        "rohg" => 0x10D30,
+        "saur" => 0xA8D0,
+        "shrd" => 0x111D0,
+        "sind" => 0x112F0,
+        "sinh" => 0x0DE6,
+        "sora" => 0x110F0,
+        "sund" => 0x1BB0,
+        "takr" => 0x116C0,
+        "talu" => 0x19D0,
+        "tamldec" => 0x0BE6,
+        "telu" => 0x0C66,
+        "thai" => 0x0E50,
+        "tibt" => 0x0F20,
+        "tirh" => 0x114D0,
+        "tnsa" => 0x16AC0,
+        "vaii" => 0xA620,
+        "wara" => 0x118E0,
+        "wcho" => 0x1E2F0,
+        _ => return None,
+    };
+    char::from_u32(zero)
+}
+
 fn sign_display(sign: &str) -> Result<SignDisplay, FormatterError> {
     match sign {
         "auto" => Ok(SignDisplay::Auto),
@@ -1400,6 +2104,156 @@ fn decimal_grouping_display(group: &str) -> Result<options::GroupingStrategy, Fo
     }
 }
 
+// The calendars are the BCP 47 calendar identifiers supported by ICU4X, excluding those containing a hyphen. The
+// original names `gregorian`, `ethiopian` and `iso` are still accepted.
+fn calendar_kind(calendar: &str) -> Result<AnyCalendarKind, FormatterError> {
+    let identifier = match calendar {
+        "gregorian" => "gregory",
+        "ethiopian" => "ethiopic",
+        _ => calendar,
+    };
+    match AnyCalendarKind::get_for_bcp47_string(identifier) {
+        Some(kind) => Ok(kind),
+        None => Err(FormatterError::InvalidOptionValue(
+            calendar.to_string(),
+            "calendar".to_string(),
+            "date_time".to_string(),
+        )),
+    }
+}
+
+// Converts the skeleton of the `fields` option into a components bag, such as `MMMd` for "Mar 5" and `EEEEjmm` for
+// "Tuesday 17:07". The supported field symbols are:
+//   G to GGG, GGGG and GGGGG for the short, long and narrow era,
+//   y and yy for the numeric and two digit year,
+//   M or L, MM or LL, MMM or LLL, MMMM or LLLL, and MMMMM or LLLLL for the numeric, two digit, short, long and narrow
+//     month,
+//   d and dd for the numeric and two digit day of the month,
+//   E to EEE, EEEE and EEEEE for the short, long and narrow weekday,
+//   j, h, H, K and k (optionally repeated for two digits) for the hour of the locale's hour cycle, the 12 hour clock
+//     (1 to 12), the 24 hour clock (0 to 23), the 12 hour clock (0 to 11) and the 24 hour clock (1 to 24),
+//   m and mm for the numeric and two digit minute,
+//   s and ss for the numeric and two digit second.
+// Time zones are displayed using the `zone` option.
+fn components_bag(skeleton: &str) -> Result<components::Bag, FormatterError> {
+    let error = || {
+        FormatterError::InvalidOptionValue(
+            skeleton.to_string(),
+            "fields".to_string(),
+            "date_time".to_string(),
+        )
+    };
+    let mut bag = components::Bag::empty();
+    let mut chars = skeleton.chars().peekable();
+    if chars.peek().is_none() {
+        return Err(error());
+    }
+    while let Some(symbol) = chars.next() {
+        let mut count = 1usize;
+        while chars.next_if_eq(&symbol).is_some() {
+            count += 1;
+        }
+        let numeric = match count {
+            1 => Some(Numeric::Numeric),
+            2 => Some(Numeric::TwoDigit),
+            _ => None,
+        };
+        let text = match count {
+            1..=3 => Some(Text::Short),
+            4 => Some(Text::Long),
+            5 => Some(Text::Narrow),
+            _ => None,
+        };
+        let duplicate = match symbol {
+            'G' => bag.era.replace(text.ok_or_else(error)?).is_some(),
+            'y' => {
+                let year = match count {
+                    1 => Year::Numeric,
+                    2 => Year::TwoDigit,
+                    _ => return Err(error()),
+                };
+                bag.year.replace(year).is_some()
+            }
+            'M' | 'L' => {
+                let month = match count {
+                    1 => Month::Numeric,
+                    2 => Month::TwoDigit,
+                    3 => Month::Short,
+                    4 => Month::Long,
+                    5 => Month::Narrow,
+                    _ => return Err(error()),
+                };
+                bag.month.replace(month).is_some()
+            }
+            'd' => {
+                let day = match count {
+                    1 => Day::NumericDayOfMonth,
+                    2 => Day::TwoDigitDayOfMonth,
+                    _ => return Err(error()),
+                };
+                bag.day.replace(day).is_some()
+            }
+            'E' => bag.weekday.replace(text.ok_or_else(error)?).is_some(),
+            'j' | 'h' | 'H' | 'K' | 'k' => {
+                let hour_cycle = match symbol {
+                    'h' => Some(HourCycle::H12),
+                    'H' => Some(HourCycle::H23),
+                    'K' => Some(HourCycle::H11),
+                    'k' => Some(HourCycle::H24),
+                    _ => None,
+                };
+                bag.preferences = hour_cycle.map(preferences::Bag::from_hour_cycle);
+                bag.hour.replace(numeric.ok_or_else(error)?).is_some()
+            }
+            'm' => bag.minute.replace(numeric.ok_or_else(error)?).is_some(),
+            's' => bag.second.replace(numeric.ok_or_else(error)?).is_some(),
+            _ => return Err(error()),
+        };
+        if duplicate {
+            return Err(error());
+        }
+    }
+    Ok(bag)
+}
+
+// The hour cycles of the `hour` option: `24` (1 to 24), `23` (0 to 23), `12` (1 to 12) and `11` (0 to 11).
+fn hour_cycle(hour: &str) -> Result<HourCycle, FormatterError> {
+    match hour {
+        "24" => Ok(HourCycle::H24),
+        "23" => Ok(HourCycle::H23),
+        "12" => Ok(HourCycle::H12),
+        "11" => Ok(HourCycle::H11),
+        _ => Err(FormatterError::InvalidOptionValue(
+            hour.to_string(),
+            "hour".to_string(),
+            "date_time".to_string(),
+        )),
+    }
+}
+
+fn hour_cycle_value(hour_cycle: HourCycle) -> Value {
+    match hour_cycle {
+        HourCycle::H24 => value!("h24"),
+        HourCycle::H23 => value!("h23"),
+        HourCycle::H12 => value!("h12"),
+        HourCycle::H11 => value!("h11"),
+    }
+}
+
+fn zone_format(zone: &str) -> Result<ZoneFormat, FormatterError> {
+    match zone {
+        "specific" => Ok(ZoneFormat::Specific),
+        "generic" => Ok(ZoneFormat::Generic),
+        "offset" => Ok(ZoneFormat::Offset),
+        "location" => Ok(ZoneFormat::Location),
+        _ => Err(FormatterError::InvalidOptionValue(
+            zone.to_string(),
+            "zone".to_string(),
+            "date_time".to_string(),
+        )),
+    }
+}
+
 fn date_length(len: &str) -> Result<DateLength, FormatterError> {
     match len {
         "full" => Ok(DateLength::Full),
@@ -1430,7 +2284,16 @@ fn time_length(len: &str) -> Result<TimeLength, FormatterError> {
 
 struct OptionSelectors<'a> {
     valid_plurals: Vec<&'a str>,
-    calendars: Vec<&'a str>,
+}
+
+// The time zone formats: specific non-location (such as "Central European Summer Time"), generic non-location (such
+// as "Central European Time"), localised GMT offset (such as "GMT+02:00") and generic location (such as "Berlin Time").
+#[derive(Clone, Copy)]
+enum ZoneFormat {
+    Specific,
+    Generic,
+    Offset,
+    Location,
 }
 
 enum ComplexType {
@@ -1439,6 +2302,14 @@ enum ComplexType {
     Select,
 }
 
+// The formatted number of the number signs of a selected branch.
+#[derive(Clone, Default)]
+struct FormattedNumber {
+    placeholder: String,
+    string: String,
+    fraction: usize,
+}
+
 enum PatternPart {
     Text(String),
     NumberSign(usize),
@@ -1452,7 +2323,10 @@ enum PatternPart {
         placeholder: String,
         length_date: Option<DateLength>,
         length_time: Option<TimeLength>,
-        calendar: Option<IcuLanguage>,
+        calendar: Option<AnyCalendarKind>,
+        zone: Option<ZoneFormat>,
+        fields: Option<components::Bag>,
+        hour: Option<HourCycle>,
     },
     PatternComplex {
         placeholder: String,
@@ -1462,4 +2336,11 @@ enum PatternPart {
     Command {
         strings: Vec<PlaceholderValue>,
     },
+    MarkupOpen {
+        name: String,
+        options: HashMap<String, String>,
+    },
+    MarkupClose {
+        name: String,
+    },
 }
@@ -3,7 +3,11 @@
 
 //! Welcome to the **`i18n_localiser`** crate of the *Internationalisation* (i18n) project.
 //!
-//! This crate consists of five modules:
+//! This crate consists of eleven modules:
+//!
+//! * [`cache`]: Contains the statistics of the localiser's cache,
+//!
+//! * [`checker`]: Contains the cross-language pattern consistency checker,
 //!
 //! * [`command`]: Contains the command registry,
 //!
@@ -13,12 +17,25 @@
 //!
 //! * [`localiser`]: Contains the localiser,
 //!
+//! * [`message`]: Contains the lazy localised message,
+//!
+//! * `negotiation` \[Private\]: Language negotiation of the user's language preferences,
+//!
+//! * [`parts`]: Contains the segments of a string formatted into parts,
+//!
+//! * [`pseudo`]: Contains the pseudo-localisation settings and pseudo-locales,
+//!
 //! * `tree` \[Private\]: Simple tagged string type.
 //!
 //! # Features
 //!
 //! Available features for `i18n_localiser` crate:
 //!
+//! * `async`: Enables `sync`, and adds `Localiser::format_async()` for retrieving uncached strings from an
+//!   `AsyncLocalisationProviderTrait` provider, such as a database server, or from a synchronous provider wrapped in a
+//!   `BlockingProvider`. A `Localiser` having only an asynchronous provider is created with
+//!   `Localiser::try_new_async()`.
+//!
 //! * `icu_blob`: Allow for instances of `BlobDataProvider` to be used various ICU4X components that supports
 //!   [`BufferProvider`]. An alternative provider when the internal data of ICU4X components are insufficient for a
 //!   particular use case.
@@ -34,10 +51,23 @@
 //! * `logging`: To provide some logging information.
 //!
 //! * `sync`: Allow for rust's concurrency capabilities to be used. Use of `Arc` and `Mutex` instead `Rc` and
-//!   `RefCell`.
+//!   `RefCell`. The `Localiser` can be shared between threads, and cached strings are formatted concurrently, as a
+//!   cache lookup only takes the read lock of the cache, and the cached formatter is not locked while formatting. The
+//!   localisation provider must be `Send` and `Sync`.
 //!
 //! # Modules
 //!
+//! ## `cache`: Localiser cache
+//!
+//! The least recently used (LRU) cache of the `Localiser`, for the parsed strings. The capacity of the cache is
+//! optional, and the hit, miss and eviction counters are available as `CacheStatistics`.
+//!
+//! ## `checker`: Pattern consistency checker
+//!
+//! The `PatternChecker` parses the strings of every language of a component, and compares the placeholder names,
+//! placeholder types, `plural`/`ordinal`/`select` branches and command usage against the string of the component's
+//! default language. Each mismatch is reported with the location of the pattern within the strings.
+//!
 //! ## `command`: User defined commands registry
 //!
 //! This module contains the command registry for user defined functions.
@@ -57,6 +87,9 @@
 //! The localiser is capable of caching retrieved strings that are prepared for placeholder replacement, thus can be
 //! reused without the need to parse the string for placeholders.
 //!
+//! Optionally the placeholder values are wrapped with Unicode directional isolates, based on the script direction of
+//! the value's language and of the string's language, thus mixed direction text is displayed correctly.
+//!
 //! The localiser makes use of all the other component crates that make up the `i18n` project. Ideally one only
 //! needs to use the meta crate `i18n`, as it includes all the crates including this `i18n_localiser` crate.
 //!
@@ -64,10 +97,9 @@
 //!
 //! ```
 //! use i18n_lexer::{IcuDataProvider, DataProvider};
-//! use i18n_utility::{PlaceholderValue, LanguageTagRegistry};
+//! use i18n_utility::{LanguageTagRegistry, Values};
 //! use i18n_provider_sqlite3::LocalisationProviderSqlite3;
 //! use i18n_localiser::{Localiser, CommandRegistry};
-//! use std::collections::HashMap;
 //! use std::rc::Rc;
 //! use std::error::Error;
 //!
@@ -82,23 +114,11 @@
 //!         &icu_data_provider, &language_tag_registry, Box::new( lstring_provider ), &command_registry, true, true, "en-ZA",
 //!     )?;
 //!     let tag = language_tag_registry.tag("en-ZA").expect("Failed to canonicalise language tag.");
-//!     let mut values = HashMap::<String, PlaceholderValue>::new();
-//!     values.insert(
-//!         "component".to_string(),
-//!         PlaceholderValue::String( "i18n_localiser".to_string() )
-//!     );
-//!     values.insert(
-//!         "identifier".to_string(),
-//!         PlaceholderValue::String( "string_not_found".to_string() )
-//!     );
-//!     values.insert(
-//!         "language_tag".to_string(),
-//!         PlaceholderValue::String( "en-ZA".to_string() )
-//!     );
-//!     values.insert(
-//!         "fallback".to_string(),
-//!         PlaceholderValue::String( "true".to_string() )
-//!     );
+//!     let values = Values::new()
+//!         .with( "component", "i18n_localiser" )
+//!         .with( "identifier", "string_not_found" )
+//!         .with( "language_tag", "en-ZA" )
+//!         .with( "fallback", "true" );
 //!     let lstring = message_system.format(
 //!         "i18n_localiser",
 //!         "string_not_found",
@@ -117,6 +137,31 @@
 //! }
 //! ```
 //!
+//! ## `message`: Lazy message
+//!
+//! The `Message` struct created by `Localiser::message()`, a localised message that is only formatted when displayed.
+//! The formatted string is streamed into the output using `Localiser::format_into()`, thus messages can be passed to
+//! logging macros and `write!()` without allocating the formatted string.
+//!
+//! ## `negotiation`: Language negotiation (Private)
+//!
+//! Selects the best supported language for a list of weighted language preferences, by comparing the language tags
+//! after adding the likely subtags. Used by the `Localiser` methods `negotiate()` and `negotiate_components()`.
+//!
+//! ## `parts`: Formatted parts
+//!
+//! The `FormattedPart` and `NumberPart` enums, the segments of a string formatted by `Localiser::format_to_parts()`:
+//! literal text, placeholder values, numbers split into sign, integer, group, decimal and fraction parts, the output of
+//! delayed commands, and the opening and closing markup tags of the pattern string. User interfaces can use the parts
+//! to style placeholder values, or to link part of the string.
+//!
+//! ## `pseudo`: Pseudo-localisation
+//!
+//! The `PseudoLocalisation` settings and the `PseudoLocale` enum of the pseudo-locales `en-XA` (accented) and `ar-XB`
+//! (mirrored right-to-left), which the `Localiser` produces from the strings of the component's default language for
+//! detecting hard-coded strings and truncated layouts. Only the text of the parsed strings is transformed, the text is
+//! padded by the expansion percentage, and the strings are wrapped with brackets.
+//!
 //! ## `tree`: Tree (Private)
 //!
 //! A custom tree that is used internally of the formatter. The tree is created by parsing the provided string, then
@@ -138,6 +183,8 @@ use std::cell::RefCell;
 
 pub mod error;
 pub use error::*;
+pub mod cache;
+pub use cache::*;
 pub(crate) mod formatter;
 pub(crate) use formatter::*;
 pub mod localiser;
@@ -146,5 +193,15 @@ pub(crate) mod tree;
 pub(crate) use tree::*;
 pub mod command;
 pub use command::*;
+pub mod checker;
+pub use checker::*;
 mod script;
 use script::*;
+mod negotiation;
+use negotiation::*;
+pub mod pseudo;
+pub use pseudo::*;
+pub mod parts;
+pub use parts::*;
+pub mod message;
+pub use message::*;
@@ -1,7 +1,11 @@
 // This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
 // called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
 
-use crate::{CommandRegistry, DataProvider, Formatter, FormatterError, LocaliserError};
+use crate::{
+    negotiate, Cache, CacheKey, CacheStatistics, CommandRegistry, DataProvider, FormattedPart,
+    Formatter, FormatterError, LocaliserError, Message, PseudoLocale, PseudoLocalisation,
+};
+use core::fmt::Write;
 use i18n_lexer::IcuDataProvider;
 use i18n_provider::LocalisationProviderTrait;
 use i18n_utility::{
@@ -11,6 +15,12 @@ use i18n_utility::{
 use icu_locid_transform::{LocaleExpander, TransformResult};
 use std::collections::HashMap;
 
+#[cfg(feature = "async")]
+use i18n_provider::{
+    AsyncLocalisationProviderTrait, ComponentDetails, IdentifierDetails, ProviderError,
+    RepositoryDetails,
+};
+
 #[cfg(not(feature = "icu_extended"))]
 use icu_locid::LanguageIdentifier;
 
@@ -36,19 +46,36 @@ use std::sync::Arc;
 #[cfg(doc)]
 use std::rc::Rc;
 
+// The fallback chains keyed by the language tag string.
+type FallbackChains = HashMap<String, Vec<RefCount<LanguageTag>>>;
+
+// With feature `sync`, the localisation provider is shared between the threads using the `Localiser`.
+#[cfg(not(feature = "sync"))]
+type LocalisationProvider = Box<dyn LocalisationProviderTrait>;
+
+#[cfg(feature = "sync")]
+type LocalisationProvider = Box<dyn LocalisationProviderTrait + Send + Sync>;
+
 pub struct Localiser {
     icu_data_provider: RefCount<IcuDataProvider>,
     grammar: String,
     language_registry: RefCount<LanguageTagRegistry>,
-    localisation_provider: Box<dyn LocalisationProviderTrait>,
+    localisation_provider: LocalisationProvider,
     command_registry: RefCount<CommandRegistry>,
     script_data: DataProvider,
     expander: LocaleExpander,
     fallback: MutCell<bool>,
     caching: MutCell<bool>,
-    cache: MutCell<HashMap<RefCount<LanguageTag>, HashMap<String, CacheData>>>,
+    cache: MutCell<Cache<CacheData>>,
     language_tag: MutCell<RefCount<LanguageTag>>,
     language_script_data: MutCell<Option<ScriptData>>,
+    fallback_chains: MutCell<FallbackChains>,
+    component_fallback_chains: MutCell<HashMap<String, FallbackChains>>,
+    pseudo_localisation: MutCell<Option<PseudoLocalisation>>,
+    bidi_isolation: MutCell<bool>,
+
+    #[cfg(feature = "async")]
+    async_localisation_provider: Option<Box<dyn AsyncLocalisationProviderTrait>>,
 }
 
 impl Localiser {
@@ -62,7 +89,9 @@ impl Localiser {
     /// Two boolean flags `fallback` and `caching` are also set to be the defaults of the
     /// `Localiser` instance. These flags govern whether parsed strings are cached for reuse,
     /// and if no string is found for the specified language whether the `format()` method
-    /// should fallback to the default language tag of the string identifier.
+    /// should fallback to the default language tag of the string identifier. Fallback walks
+    /// the fallback chain of the language (see `set_fallback_chain()`) before using the
+    /// default language tag. The cache is initially unbounded (see `set_cache_capacity()`).
     ///
     /// The `language_tag` parameter is for the default language for this `Localiser` instance,
     /// allows for simpler formatting function `format_with_defaults()`.
@@ -130,7 +159,7 @@ impl Localiser {
     pub fn try_new(
         icu_data_provider: &RefCount<IcuDataProvider>,
         language_tag_registry: &RefCount<LanguageTagRegistry>,
-        localisation_provider: Box<dyn LocalisationProviderTrait>,
+        localisation_provider: LocalisationProvider,
         command_registry: &RefCount<CommandRegistry>,
         fallback: bool,
         caching: bool,
@@ -150,11 +179,16 @@ impl Localiser {
             expander,
             fallback: MutCell::new(fallback),
             caching: MutCell::new(caching),
-            cache: MutCell::new(
-                HashMap::<RefCount<LanguageTag>, HashMap<String, CacheData>>::new(),
-            ),
+            cache: MutCell::new(Cache::<CacheData>::new(None)),
             language_tag: MutCell::new(tag),
             language_script_data: MutCell::new(language_script_data),
+            fallback_chains: MutCell::new(FallbackChains::new()),
+            component_fallback_chains: MutCell::new(HashMap::<String, FallbackChains>::new()),
+            pseudo_localisation: MutCell::new(None),
+            bidi_isolation: MutCell::new(false),
+
+            #[cfg(feature = "async")]
+            async_localisation_provider: None,
         })
     }
 
@@ -163,6 +197,8 @@ impl Localiser {
     /// when there is no string pattern for the specified language. Optionally specify whether the parsed string should
     /// be cache for reuse.
     ///
+    /// The `values` may also be a `&Values` of the `i18n_utility` crate, which dereferences to the `HashMap`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -254,17 +290,358 @@ impl Localiser {
         )
     }
 
+    /// The structured counterpart of `format()`, where the formatted string is returned as a sequence of
+    /// [`FormattedPart`]s, such as the literal text, the placeholder values, the parts of the numbers, the command
+    /// strings, and the opening and closing markup tags (`{name open}` and `{name close}`) of the pattern string.
+    /// User interfaces can thus style the placeholder values, and apply the markup, such as bold text or links.
+    ///
+    /// The concatenation of the parts is the string of `format()`, and the cache is shared with `format()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_lexer::{IcuDataProvider, DataProvider};
+    /// use i18n_utility::{LanguageTagRegistry, Values};
+    /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+    /// use i18n_localiser::{CommandRegistry, FormattedPart, Localiser};
+    /// use std::rc::Rc;
+    /// use std::error::Error;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let icu_data_provider = Rc::new( IcuDataProvider::try_new( DataProvider::Internal )? );
+    ///     let language_tag_registry = Rc::new( LanguageTagRegistry::new() );
+    ///     let localisation_provider = LocalisationProviderSqlite3::try_new(
+    ///         "./l10n/", &language_tag_registry, false
+    ///     )?;
+    ///     let command_registry = Rc::new( CommandRegistry::new() );
+    ///     let localiser = Localiser::try_new(
+    ///         &icu_data_provider, &language_tag_registry, Box::new( localisation_provider ),
+    ///         &command_registry, true, true, "en-ZA",
+    ///     )?;
+    ///     let values = Values::new().with( "node_type", "Root" );
+    ///     let parts = localiser.format_to_parts(
+    ///         "i18n_localiser",
+    ///         "invalid_node",
+    ///         &values,
+    ///         &language_tag_registry.tag( "en-ZA" )?,
+    ///         None,
+    ///         None,
+    ///     )?;
+    ///     assert_eq!(
+    ///         parts.0,
+    ///         vec![
+    ///             FormattedPart::Text( "Invalid child node found in the ‘".to_string() ),
+    ///             FormattedPart::Placeholder {
+    ///                 placeholder: "node_type".to_string(),
+    ///                 value: "Root".to_string(),
+    ///             },
+    ///             FormattedPart::Text( "’ node.".to_string() ),
+    ///         ],
+    ///         "Check parts."
+    ///     );
+    ///     Ok( () )
+    /// }
+    /// ```
+    pub fn format_to_parts(
+        &self,
+        component: &str,
+        identifier: &str,
+        values: &HashMap<String, PlaceholderValue>,
+        language_tag: &RefCount<LanguageTag>,
+        fallback: Option<bool>, // true = fallback to default language, None = use the Localiser default.
+        caching: Option<bool>, // true = cache the resultant Formatter for repeating use with different values.
+    ) -> Result<(Vec<FormattedPart>, RefCount<LanguageTag>), LocaliserError> {
+        #[cfg(feature = "logging")]
+        debug!("Localiser is using format_to_parts().");
+
+        #[cfg(not(feature = "sync"))]
+        let bool_fallback = fallback.unwrap_or(*self.fallback.borrow());
+
+        #[cfg(not(feature = "sync"))]
+        let bool_caching = caching.unwrap_or(*self.caching.borrow());
+
+        #[cfg(feature = "sync")]
+        let bool_fallback = fallback.unwrap_or(*self.fallback.read().unwrap());
+
+        #[cfg(feature = "sync")]
+        let bool_caching = caching.unwrap_or(*self.caching.read().unwrap());
+
+        match self.cache_data(
+            component,
+            identifier,
+            language_tag,
+            bool_fallback,
+            bool_caching,
+        )? {
+            CacheData::Localised(string, tag) => {
+                let mut parts = Vec::<FormattedPart>::new();
+                if !string.is_empty() {
+                    parts.push(FormattedPart::Text(string.to_string()));
+                }
+                Ok((parts, tag))
+            }
+            CacheData::Formatter(formatter) => {
+                let (parts, tag) = formatter.format_to_parts(self, values)?;
+                Ok((parts, RefCount::clone(tag)))
+            }
+        }
+    }
+
+    /// The streaming counterpart of `format()`, where the formatted string is written directly into the `writer`
+    /// (any [`Write`], such as a reused [`String`] buffer or a [`core::fmt::Formatter`]), instead of being returned as
+    /// a new `RefCount<String>`. The text of the pattern string and the placeholder values are written without
+    /// allocating intermediate strings, while the formatted numbers and dates are still allocated by ICU4X. Returns
+    /// the language tag of the pattern string.
+    ///
+    /// Should an error occur, part of the string may already have been written. An error of the `writer` is returned
+    /// as `FormatterError::Write`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_lexer::{IcuDataProvider, DataProvider};
+    /// use i18n_utility::{LanguageTagRegistry, Values};
+    /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+    /// use i18n_localiser::{CommandRegistry, Localiser};
+    /// use std::rc::Rc;
+    /// use std::error::Error;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let icu_data_provider = Rc::new( IcuDataProvider::try_new( DataProvider::Internal )? );
+    ///     let language_tag_registry = Rc::new( LanguageTagRegistry::new() );
+    ///     let localisation_provider = LocalisationProviderSqlite3::try_new(
+    ///         "./l10n/", &language_tag_registry, false
+    ///     )?;
+    ///     let command_registry = Rc::new( CommandRegistry::new() );
+    ///     let localiser = Localiser::try_new(
+    ///         &icu_data_provider, &language_tag_registry, Box::new( localisation_provider ),
+    ///         &command_registry, true, true, "en-ZA",
+    ///     )?;
+    ///     let tag = language_tag_registry.tag( "en-ZA" )?;
+    ///     let mut buffer = String::from( "[error] " );
+    ///     localiser.format_into(
+    ///         &mut buffer,
+    ///         "i18n_localiser",
+    ///         "invalid_node",
+    ///         &Values::new().with( "node_type", "Root" ),
+    ///         &tag,
+    ///         None,
+    ///         None,
+    ///     )?;
+    ///     assert_eq!(
+    ///         buffer.as_str(),
+    ///         "[error] Invalid child node found in the ‘Root’ node.",
+    ///         "Check appended string."
+    ///     );
+    ///     Ok( () )
+    /// }
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn format_into<W: Write + ?Sized>(
+        &self,
+        writer: &mut W,
+        component: &str,
+        identifier: &str,
+        values: &HashMap<String, PlaceholderValue>,
+        language_tag: &RefCount<LanguageTag>,
+        fallback: Option<bool>, // true = fallback to default language, None = use the Localiser default.
+        caching: Option<bool>, // true = cache the resultant Formatter for repeating use with different values.
+    ) -> Result<RefCount<LanguageTag>, LocaliserError> {
+        #[cfg(feature = "logging")]
+        debug!("Localiser is using format_into().");
+
+        #[cfg(not(feature = "sync"))]
+        let bool_fallback = fallback.unwrap_or(*self.fallback.borrow());
+
+        #[cfg(not(feature = "sync"))]
+        let bool_caching = caching.unwrap_or(*self.caching.borrow());
+
+        #[cfg(feature = "sync")]
+        let bool_fallback = fallback.unwrap_or(*self.fallback.read().unwrap());
+
+        #[cfg(feature = "sync")]
+        let bool_caching = caching.unwrap_or(*self.caching.read().unwrap());
+
+        match self.cache_data(
+            component,
+            identifier,
+            language_tag,
+            bool_fallback,
+            bool_caching,
+        )? {
+            CacheData::Localised(string, tag) => {
+                writer
+                    .write_str(string.as_str())
+                    .map_err(FormatterError::from)?;
+                Ok(tag)
+            }
+            CacheData::Formatter(formatter) => {
+                let tag = formatter.format_into(self, values, writer)?;
+                Ok(RefCount::clone(tag))
+            }
+        }
+    }
+
+    /// Create a lazy [`Message`], which is only formatted when displayed, using `format_into()`. Thus the message can
+    /// be passed to logging macros and `write!()`, and is streamed into the output without allocating the formatted
+    /// string, nor formatting at all when the output is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_lexer::{IcuDataProvider, DataProvider};
+    /// use i18n_utility::{LanguageTagRegistry, Values};
+    /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+    /// use i18n_localiser::{CommandRegistry, Localiser};
+    /// use std::fmt::Write;
+    /// use std::rc::Rc;
+    /// use std::error::Error;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let icu_data_provider = Rc::new( IcuDataProvider::try_new( DataProvider::Internal )? );
+    ///     let language_tag_registry = Rc::new( LanguageTagRegistry::new() );
+    ///     let localisation_provider = LocalisationProviderSqlite3::try_new(
+    ///         "./l10n/", &language_tag_registry, false
+    ///     )?;
+    ///     let command_registry = Rc::new( CommandRegistry::new() );
+    ///     let localiser = Localiser::try_new(
+    ///         &icu_data_provider, &language_tag_registry, Box::new( localisation_provider ),
+    ///         &command_registry, true, true, "en-ZA",
+    ///     )?;
+    ///     let tag = language_tag_registry.tag( "en-ZA" )?;
+    ///     let values = Values::new().with( "node_type", "Root" );
+    ///     let message = localiser.message( "i18n_localiser", "invalid_node", &values, &tag );
+    ///     let mut line = String::new();
+    ///     write!( line, "[error] {}", message )?;
+    ///     assert_eq!(
+    ///         line.as_str(),
+    ///         "[error] Invalid child node found in the ‘Root’ node.",
+    ///         "Check displayed message."
+    ///     );
+    ///     Ok( () )
+    /// }
+    /// ```
+    pub fn message<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+        values: &'a HashMap<String, PlaceholderValue>,
+        language_tag: &'a RefCount<LanguageTag>,
+    ) -> Message<'a> {
+        Message {
+            localiser: self,
+            component,
+            identifier,
+            values,
+            language_tag,
+            fallback: None,
+            caching: None,
+        }
+    }
+
+    /// Create a new `Localiser` instance (using feature `async`), that is only connected to an asynchronous localisation
+    /// provider [`AsyncLocalisationProviderTrait`], such as a provider of a database server. The parameters are the
+    /// same as those of `try_new()`.
+    ///
+    /// As there is no synchronous localisation provider, only `format_async()` obtains strings from the provider. The
+    /// synchronous methods, such as `format()` and `literal()`, only obtain the strings that `format_async()` has
+    /// cached, else report the component as not found [`ProviderError::ComponentNotFound`].
+    #[cfg(feature = "async")]
+    pub fn try_new_async(
+        icu_data_provider: &RefCount<IcuDataProvider>,
+        language_tag_registry: &RefCount<LanguageTagRegistry>,
+        async_localisation_provider: Box<dyn AsyncLocalisationProviderTrait>,
+        command_registry: &RefCount<CommandRegistry>,
+        fallback: bool,
+        caching: bool,
+        language_tag: &str,
+    ) -> Result<Localiser, LocaliserError> {
+        Ok(Localiser::try_new(
+            icu_data_provider,
+            language_tag_registry,
+            Box::new(NoLocalisationProvider),
+            command_registry,
+            fallback,
+            caching,
+            language_tag,
+        )?
+        .with_async_localisation_provider(async_localisation_provider))
+    }
+
+    /// Set the asynchronous localisation provider [`AsyncLocalisationProviderTrait`] used by `format_async()`, such as
+    /// a provider of a database server, or a synchronous provider wrapped in a `BlockingProvider` of the
+    /// `i18n_provider` crate (using feature `async`).
+    ///
+    /// The asynchronous provider must contain the same data as the synchronous provider of the `Localiser`, as the
+    /// cache is shared by the formatting methods. Use `try_new_async()` when there is no synchronous provider.
+    #[cfg(feature = "async")]
+    pub fn with_async_localisation_provider(
+        mut self,
+        async_localisation_provider: Box<dyn AsyncLocalisationProviderTrait>,
+    ) -> Self {
+        self.async_localisation_provider = Some(async_localisation_provider);
+        self
+    }
+
+    /// The asynchronous counterpart of `format()` (using feature `async`), where the pattern string is obtained from
+    /// the asynchronous localisation provider (see `with_async_localisation_provider()`), else when not set from the
+    /// synchronous localisation provider. Strings found in the cache are formatted without awaiting the provider.
+    #[cfg(feature = "async")]
+    pub async fn format_async(
+        &self,
+        component: &str,
+        identifier: &str,
+        values: &HashMap<String, PlaceholderValue>,
+        language_tag: &RefCount<LanguageTag>,
+        fallback: Option<bool>, // true = fallback to default language, None = use the Localiser default.
+        caching: Option<bool>, // true = cache the resultant Formatter for repeating use with different values.
+    ) -> Result<(RefCount<String>, RefCount<LanguageTag>), LocaliserError> {
+        #[cfg(feature = "logging")]
+        debug!("Localiser is using format_async().");
+
+        let Some(provider) = self.async_localisation_provider.as_ref() else {
+            return self.format(
+                component,
+                identifier,
+                values,
+                language_tag,
+                fallback,
+                caching,
+            );
+        };
+        let bool_fallback = fallback.unwrap_or(*self.fallback.read().unwrap());
+        let bool_caching = caching.unwrap_or(*self.caching.read().unwrap());
+        let key = CacheKey::new(language_tag, component, identifier, bool_fallback);
+        let data = match self.cached(&key) {
+            Some(data) => data,
+            None => {
+                let localised = self
+                    .pattern_string_async(
+                        provider.as_ref(),
+                        component,
+                        identifier,
+                        language_tag,
+                        bool_fallback,
+                    )
+                    .await?;
+                self.prepare_pattern_string(key, localised, language_tag, bool_caching)?
+            }
+        };
+        self.format_cache_data(data, values)
+    }
+
     /// For the specified string identifier, format a string for the specified language tag with the supplied values
     /// for the placeholders using the `Localiser` instance defaults.
     ///
+    /// The `values` may also be a `&Values` of the `i18n_utility` crate, which dereferences to the `HashMap`.
+    ///
     /// # Examples
     ///
     /// ```
     /// use i18n_lexer::{IcuDataProvider, DataProvider};
-    /// use i18n_utility::{LanguageTagRegistry, PlaceholderValue};
+    /// use i18n_utility::{LanguageTagRegistry, Values};
     /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
     /// use i18n_localiser::{CommandRegistry, Localiser};
-    /// use std::collections::HashMap;
     /// use std::rc::Rc;
     /// use std::error::Error;
     ///
@@ -279,15 +656,9 @@ impl Localiser {
     ///         &icu_data_provider, &language_tag_registry, Box::new( localisation_provider ),
     ///         &command_registry, true, true, "en-ZA",
     ///     )?;
-    ///     let mut values = HashMap::<String, PlaceholderValue>::new();
-    ///     values.insert(
-    ///         "component".to_string(),
-    ///         PlaceholderValue::String( "i18n_localiser".to_string() )
-    ///     );
-    ///     values.insert(
-    ///         "identifier".to_string(),
-    ///         PlaceholderValue::String( "cache_entry".to_string() )
-    ///     );
+    ///     let values = Values::new()
+    ///         .with( "component", "i18n_localiser" )
+    ///         .with( "identifier", "cache_entry" );
     ///     let lstring = message_system.format_with_defaults(
     ///         "i18n_localiser",
     ///         "cache_entry",
@@ -539,21 +910,415 @@ impl Localiser {
         Ok(())
     }
 
-    /// Obtain the default language for the `Localiser` instance.
-    pub fn default_language(&self) -> RefCount<LanguageTag> {
+    /// Set the capacity of the cache, that is the maximum number of cached strings. When the capacity is exceeded,
+    /// the least recently used strings are evicted. A capacity of `None` indicates an unbounded cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_lexer::{IcuDataProvider, DataProvider};
+    /// use i18n_utility::LanguageTagRegistry;
+    /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+    /// use i18n_localiser::{CommandRegistry, Localiser};
+    /// use std::rc::Rc;
+    /// use std::error::Error;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let icu_data_provider = Rc::new( IcuDataProvider::try_new( DataProvider::Internal )? );
+    ///     let language_tag_registry = Rc::new( LanguageTagRegistry::new() );
+    ///     let localisation_provider = LocalisationProviderSqlite3::try_new(
+    ///         "./l10n/", &language_tag_registry, false
+    ///     )?;
+    ///     let command_registry = Rc::new( CommandRegistry::new() );
+    ///     let localiser = Localiser::try_new(
+    ///         &icu_data_provider, &language_tag_registry, Box::new( localisation_provider ),
+    ///         &command_registry, true, true, "en-ZA",
+    ///     )?;
+    ///     localiser.set_cache_capacity( Some( 1 ) );
+    ///     localiser.literal_with_defaults( "i18n_localiser", "no_grammar" )?;
+    ///     localiser.literal_with_defaults( "i18n_localiser", "cache_entry" )?;
+    ///     let statistics = localiser.cache_statistics();
+    ///     assert_eq!( statistics.entries, 1, "Check number of cached strings." );
+    ///     assert_eq!( statistics.evictions, 1, "Check number of evicted strings." );
+    ///     Ok( () )
+    /// }
+    /// ```
+    pub fn set_cache_capacity(&self, capacity: Option<usize>) {
         #[cfg(not(feature = "sync"))]
-        let binding = self.language_tag.borrow();
+        self.cache.borrow_mut().set_capacity(capacity);
 
         #[cfg(feature = "sync")]
-        let binding = self.language_tag.read().unwrap();
-
-        RefCount::clone(&binding)
+        self.cache.write().unwrap().set_capacity(capacity);
     }
 
-    /// Obtain the localisation provider for the `Localiser` instance.
-    #[allow(clippy::borrowed_box)]
-    pub fn localisation_provider(&self) -> &Box<dyn LocalisationProviderTrait> {
-        &self.localisation_provider
+    /// Obtain the statistics of the cache, consisting of the hit, miss and eviction counters, the number of cached
+    /// strings and the capacity.
+    pub fn cache_statistics(&self) -> CacheStatistics {
+        #[cfg(not(feature = "sync"))]
+        let statistics = self.cache.borrow().statistics();
+
+        #[cfg(feature = "sync")]
+        let statistics = self.cache.read().unwrap().statistics();
+
+        statistics
+    }
+
+    /// Reset the hit, miss and eviction counters of the cache.
+    pub fn reset_cache_statistics(&self) {
+        #[cfg(not(feature = "sync"))]
+        self.cache.borrow_mut().reset_statistics();
+
+        #[cfg(feature = "sync")]
+        self.cache.write().unwrap().reset_statistics();
+    }
+
+    /// Remove all the cached strings.
+    pub fn clear_cache(&self) {
+        #[cfg(not(feature = "sync"))]
+        self.cache.borrow_mut().clear();
+
+        #[cfg(feature = "sync")]
+        self.cache.write().unwrap().clear();
+    }
+
+    /// Remove the cached strings of the component for all languages, such as after the component's strings were
+    /// updated. Returns the number of cached strings that were removed.
+    pub fn evict(&self, component: &str) -> usize {
+        #[cfg(not(feature = "sync"))]
+        let evicted = self.cache.borrow_mut().evict(component);
+
+        #[cfg(feature = "sync")]
+        let evicted = self.cache.write().unwrap().evict(component);
+
+        evicted
+    }
+
+    /// Reload the localisation provider's data repository if it has changed, such as updated catalogues, and remove
+    /// the cached strings of the changed components. Returns the components that changed, where an empty [`Vec`]
+    /// indicates there were no changes.
+    ///
+    /// The cache is locked while the provider swaps its data and the cached strings are removed, thus no string of
+    /// a changed component is obtained from the cache after the provider's data has been replaced.
+    ///
+    /// Return of [`LocaliserError`] indicates the provider failed to read its data repository, and both the
+    /// provider's data and the cache remain unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_lexer::{IcuDataProvider, DataProvider};
+    /// use i18n_utility::LanguageTagRegistry;
+    /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+    /// use i18n_localiser::{CommandRegistry, Localiser};
+    /// use std::rc::Rc;
+    /// use std::error::Error;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let icu_data_provider = Rc::new( IcuDataProvider::try_new( DataProvider::Internal )? );
+    ///     let language_tag_registry = Rc::new( LanguageTagRegistry::new() );
+    ///     let localisation_provider = LocalisationProviderSqlite3::try_new(
+    ///         "./l10n/", &language_tag_registry, false
+    ///     )?;
+    ///     let command_registry = Rc::new( CommandRegistry::new() );
+    ///     let localiser = Localiser::try_new(
+    ///         &icu_data_provider, &language_tag_registry, Box::new( localisation_provider ),
+    ///         &command_registry, true, true, "en-ZA",
+    ///     )?;
+    ///     localiser.literal_with_defaults( "i18n_localiser", "no_grammar" )?;
+    ///     assert!( localiser.reload()?.is_empty(), "Should be no changes." );
+    ///     assert_eq!( localiser.cache_statistics().entries, 1, "Check number of cached strings." );
+    ///     Ok( () )
+    /// }
+    /// ```
+    pub fn reload(&self) -> Result<Vec<String>, LocaliserError> {
+        #[cfg(not(feature = "sync"))]
+        let mut binding = self.cache.borrow_mut();
+
+        #[cfg(feature = "sync")]
+        let mut binding = self.cache.write().unwrap();
+
+        let changed = self.localisation_provider.reload()?;
+        for component in changed.iter() {
+            binding.evict(component);
+        }
+        Ok(changed)
+    }
+
+    /// Set the fallback chain of the language tag, which is tried in order when there is no string for the language
+    /// tag (and its less specific language tags), before falling back to the component's default language. For
+    /// example the chain `pt-PT`, `es`, `en` for `pt-BR`, or `hr` for `sr-Latn`.
+    ///
+    /// When `component` is `Some`, the chain only applies to the component, and overrides the chain of the
+    /// `Localiser` instance for the language tag. An empty `chain` removes the chain. The chain is also used for the
+    /// more specific language tags without a chain, such as `sr-Latn-RS` for the chain of `sr-Latn`.
+    ///
+    /// The fallback chains are only used when fallback is enabled. The cache is cleared, as the cached strings may
+    /// have been obtained using the previous chains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_lexer::{IcuDataProvider, DataProvider};
+    /// use i18n_utility::LanguageTagRegistry;
+    /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+    /// use i18n_localiser::{CommandRegistry, Localiser};
+    /// use std::rc::Rc;
+    /// use std::error::Error;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let icu_data_provider = Rc::new( IcuDataProvider::try_new( DataProvider::Internal )? );
+    ///     let language_tag_registry = Rc::new( LanguageTagRegistry::new() );
+    ///     let localisation_provider = LocalisationProviderSqlite3::try_new(
+    ///         "./l10n/", &language_tag_registry, false
+    ///     )?;
+    ///     let command_registry = Rc::new( CommandRegistry::new() );
+    ///     let localiser = Localiser::try_new(
+    ///         &icu_data_provider, &language_tag_registry, Box::new( localisation_provider ),
+    ///         &command_registry, true, true, "en-ZA",
+    ///     )?;
+    ///     let romansh = language_tag_registry.tag( "rm" )?;
+    ///     localiser.set_fallback_chain(
+    ///         None,
+    ///         &romansh,
+    ///         &[ language_tag_registry.tag( "de" )?, language_tag_registry.tag( "it" )? ],
+    ///     );
+    ///     let lstring = localiser.literal( "i18n_localiser", "no_grammar", &romansh, None, None )?;
+    ///     assert_eq!( lstring.1.as_str(), "it", "Check the language of the fallback chain." );
+    ///     Ok( () )
+    /// }
+    /// ```
+    pub fn set_fallback_chain(
+        &self,
+        component: Option<&str>,
+        language_tag: &RefCount<LanguageTag>,
+        chain: &[RefCount<LanguageTag>],
+    ) {
+        let key = language_tag.as_str().to_string();
+        match component {
+            None => {
+                #[cfg(not(feature = "sync"))]
+                let mut binding = self.fallback_chains.borrow_mut();
+
+                #[cfg(feature = "sync")]
+                let mut binding = self.fallback_chains.write().unwrap();
+
+                match chain.is_empty() {
+                    true => binding.remove(&key),
+                    false => binding.insert(key, chain.to_vec()),
+                };
+            }
+            Some(component) => {
+                #[cfg(not(feature = "sync"))]
+                let mut binding = self.component_fallback_chains.borrow_mut();
+
+                #[cfg(feature = "sync")]
+                let mut binding = self.component_fallback_chains.write().unwrap();
+
+                let chains = binding.entry(component.to_string()).or_default();
+                match chain.is_empty() {
+                    true => chains.remove(&key),
+                    false => chains.insert(key, chain.to_vec()),
+                };
+            }
+        }
+
+        self.clear_cache();
+    }
+
+    /// Obtain the fallback chain of the language tag for the component, which is either the component's chain or
+    /// else the chain of the `Localiser` instance.
+    ///
+    /// When there is no chain for the language tag, the chains of the less specific language tags are used, such as
+    /// the chain of `sr-Latn` for `sr-Latn-RS`. The chain of the more specific language tag is always preferred, even
+    /// if the chain is of the `Localiser` instance and the less specific chain is of the component.
+    ///
+    /// [`None`] indicates there is no fallback chain for the language tag.
+    pub fn fallback_chain(
+        &self,
+        component: &str,
+        language_tag: &RefCount<LanguageTag>,
+    ) -> Option<Vec<RefCount<LanguageTag>>> {
+        #[cfg(not(feature = "sync"))]
+        let component_binding = self.component_fallback_chains.borrow();
+
+        #[cfg(feature = "sync")]
+        let component_binding = self.component_fallback_chains.read().unwrap();
+
+        #[cfg(not(feature = "sync"))]
+        let binding = self.fallback_chains.borrow();
+
+        #[cfg(feature = "sync")]
+        let binding = self.fallback_chains.read().unwrap();
+
+        let component_chains = component_binding.get(component);
+        let mut tag = language_tag.as_str();
+        loop {
+            if let Some(chain) = component_chains.and_then(|chains| chains.get(tag)) {
+                return Some(chain.clone());
+            }
+            if let Some(chain) = binding.get(tag) {
+                return Some(chain.clone());
+            }
+            tag = less_specific(tag)?;
+        }
+    }
+
+    /// Enable pseudo-localisation with the settings [`PseudoLocalisation`], or disable it with [`None`].
+    ///
+    /// When enabled, the language tags `en-XA` and `ar-XB` of [`PseudoLocale`] are produced from the string of the
+    /// component's default language, where only the text of the string is transformed, leaving the placeholders
+    /// intact, and the string is wrapped with the brackets. The pseudo-locale's language tag is returned with the
+    /// localised string. The cache is cleared, as the cached strings may have been obtained using the previous
+    /// settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_lexer::{IcuDataProvider, DataProvider};
+    /// use i18n_utility::{LanguageTagRegistry, Values};
+    /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+    /// use i18n_localiser::{CommandRegistry, Localiser, PseudoLocalisation};
+    /// use std::rc::Rc;
+    /// use std::error::Error;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let icu_data_provider = Rc::new( IcuDataProvider::try_new( DataProvider::Internal )? );
+    ///     let language_tag_registry = Rc::new( LanguageTagRegistry::new() );
+    ///     let localisation_provider = LocalisationProviderSqlite3::try_new(
+    ///         "./l10n/", &language_tag_registry, false
+    ///     )?;
+    ///     let command_registry = Rc::new( CommandRegistry::new() );
+    ///     let localiser = Localiser::try_new(
+    ///         &icu_data_provider, &language_tag_registry, Box::new( localisation_provider ),
+    ///         &command_registry, true, true, "en-ZA",
+    ///     )?;
+    ///     localiser.set_pseudo_localisation( Some( PseudoLocalisation::new( 0 ) ) );
+    ///     let values = Values::new()
+    ///         .with( "component", "i18n_localiser" )
+    ///         .with( "identifier", "string_not_found" )
+    ///         .with( "language_tag", "en-XA" )
+    ///         .with( "fallback", "true" );
+    ///     let lstring = localiser.format(
+    ///         "i18n_localiser",
+    ///         "string_not_found",
+    ///         &values,
+    ///         &language_tag_registry.tag( "en-XA" )?,
+    ///         None,
+    ///         None,
+    ///     )?;
+    ///     assert_eq!(
+    ///         lstring.0.as_str(),
+    ///         "[Ñö šţŕîñĝ ŵåš ƒöûñð ƒöŕ ţĥé çöɱþöñéñţ ‘i18n_localiser’ ŵîţĥ îðéñţîƒîéŕ ‘string_not_found’ ƒöŕ ţĥé \
+    ///             ļåñĝûåĝé ţåĝ ‘en-XA’. Ƒåļļƀåçķ ŵåš ûšéð: Ţŕûé.]",
+    ///         "Check pseudo-localised string."
+    ///     );
+    ///     assert_eq!( lstring.1.as_str(), "en-XA", "Check language tag of pseudo-locale." );
+    ///     Ok( () )
+    /// }
+    /// ```
+    pub fn set_pseudo_localisation(&self, pseudo_localisation: Option<PseudoLocalisation>) {
+        #[cfg(not(feature = "sync"))]
+        self.pseudo_localisation.replace(pseudo_localisation);
+
+        #[cfg(feature = "sync")]
+        {
+            *self.pseudo_localisation.write().unwrap() = pseudo_localisation;
+        }
+
+        self.clear_cache();
+    }
+
+    /// Obtain the settings of pseudo-localisation, where [`None`] indicates pseudo-localisation is disabled.
+    pub fn pseudo_localisation(&self) -> Option<PseudoLocalisation> {
+        #[cfg(not(feature = "sync"))]
+        let binding = self.pseudo_localisation.borrow();
+
+        #[cfg(feature = "sync")]
+        let binding = self.pseudo_localisation.read().unwrap();
+
+        binding.clone()
+    }
+
+    /// Enable or disable the wrapping of the substituted placeholder values with the Unicode directional isolates, so
+    /// that text of another direction, such as an English product name within an Arabic sentence, is displayed
+    /// correctly. Initially disabled.
+    ///
+    /// Only the values of placeholders without a keyword are wrapped. The direction of the script of a
+    /// `TaggedString` or `Localised` value's language tag is compared with the direction of the pattern string's
+    /// language (see `script_data_for_language_tag()`). When the directions differ, the value is wrapped with either
+    /// LRI (U+2066) or RLI (U+2067) and PDI (U+2069). A `String` value, or a value whose direction is unknown, is
+    /// wrapped with FSI (U+2068) and PDI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_lexer::{IcuDataProvider, DataProvider};
+    /// use i18n_utility::{LanguageTagRegistry, TaggedString, Values};
+    /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+    /// use i18n_localiser::{CommandRegistry, Localiser};
+    /// use std::rc::Rc;
+    /// use std::error::Error;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let icu_data_provider = Rc::new( IcuDataProvider::try_new( DataProvider::Internal )? );
+    ///     let language_tag_registry = Rc::new( LanguageTagRegistry::new() );
+    ///     let localisation_provider = LocalisationProviderSqlite3::try_new(
+    ///         "./l10n/", &language_tag_registry, false
+    ///     )?;
+    ///     let command_registry = Rc::new( CommandRegistry::new() );
+    ///     let localiser = Localiser::try_new(
+    ///         &icu_data_provider, &language_tag_registry, Box::new( localisation_provider ),
+    ///         &command_registry, true, true, "en-ZA",
+    ///     )?;
+    ///     localiser.set_bidi_isolation( true );
+    ///     let hebrew = language_tag_registry.tag( "he" )?;
+    ///     let values = Values::new()
+    ///         .with( "node_type", TaggedString::new( "שורש", &hebrew ) );
+    ///     let lstring = localiser.format_with_defaults( "i18n_localiser", "invalid_node", &values )?;
+    ///     assert_eq!(
+    ///         lstring.0.as_str(),
+    ///         "Invalid child node found in the ‘\u{2067}שורש\u{2069}’ node.",
+    ///         "Check the right-to-left isolate."
+    ///     );
+    ///     Ok( () )
+    /// }
+    /// ```
+    pub fn set_bidi_isolation(&self, bidi_isolation: bool) {
+        #[cfg(not(feature = "sync"))]
+        self.bidi_isolation.replace(bidi_isolation);
+
+        #[cfg(feature = "sync")]
+        {
+            *self.bidi_isolation.write().unwrap() = bidi_isolation;
+        }
+    }
+
+    /// Indicates whether the substituted placeholder values are wrapped with the Unicode directional isolates.
+    pub fn bidi_isolation(&self) -> bool {
+        #[cfg(not(feature = "sync"))]
+        let bidi_isolation = *self.bidi_isolation.borrow();
+
+        #[cfg(feature = "sync")]
+        let bidi_isolation = *self.bidi_isolation.read().unwrap();
+
+        bidi_isolation
+    }
+
+    /// Obtain the default language for the `Localiser` instance.
+    pub fn default_language(&self) -> RefCount<LanguageTag> {
+        #[cfg(not(feature = "sync"))]
+        let binding = self.language_tag.borrow();
+
+        #[cfg(feature = "sync")]
+        let binding = self.language_tag.read().unwrap();
+
+        RefCount::clone(&binding)
+    }
+
+    /// Obtain the localisation provider for the `Localiser` instance.
+    #[allow(clippy::borrowed_box)]
+    pub fn localisation_provider(&self) -> &LocalisationProvider {
+        &self.localisation_provider
     }
 
     /// Obtain the language tag registry for the `Localiser` instance.
@@ -932,6 +1697,82 @@ impl Localiser {
         get_script_data(tag.as_str(), &self.script_data, &self.expander)
     }
 
+    /// Negotiates the best supported language of the component for the user's prioritised language preferences, such
+    /// as obtained from the `Accept-Language` HTTP header or the operating system's language list.
+    ///
+    /// The `preferences` are pairs of language tag and weight (quality value `0.0` to `1.0`). The languages of the
+    /// component are compared with each preference after adding the likely subtags (for example `en` becomes
+    /// `en-Latn-US`), where a different language or script is not a match, and a different region or variant is a
+    /// close match. The language with the highest weighted closeness is selected, and when there is no match the
+    /// component's default language is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_lexer::{IcuDataProvider, DataProvider};
+    /// use i18n_utility::LanguageTagRegistry;
+    /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+    /// use i18n_localiser::{CommandRegistry, Localiser};
+    /// use std::rc::Rc;
+    /// use std::error::Error;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let icu_data_provider = Rc::new( IcuDataProvider::try_new( DataProvider::Internal )? );
+    ///     let language_tag_registry = Rc::new( LanguageTagRegistry::new() );
+    ///     let localisation_provider = LocalisationProviderSqlite3::try_new(
+    ///         "./l10n/", &language_tag_registry, false
+    ///     )?;
+    ///     let command_registry = Rc::new( CommandRegistry::new() );
+    ///     let localiser = Localiser::try_new(
+    ///         &icu_data_provider, &language_tag_registry, Box::new( localisation_provider ),
+    ///         &command_registry, true, true, "en-ZA",
+    ///     )?;
+    ///     let preferences = vec![
+    ///         ( language_tag_registry.tag( "fr-FR" )?, 1.0 ),
+    ///         ( language_tag_registry.tag( "it-CH" )?, 0.8 ),
+    ///         ( language_tag_registry.tag( "en" )?, 0.5 ),
+    ///     ];
+    ///     let language = localiser.negotiate( "i18n_localiser", &preferences )?;
+    ///     assert_eq!( language.as_str(), "it", "Check negotiated language." );
+    ///     Ok( () )
+    /// }
+    /// ```
+    pub fn negotiate(
+        &self,
+        component: &str,
+        preferences: &[(RefCount<LanguageTag>, f32)],
+    ) -> Result<RefCount<LanguageTag>, LocaliserError> {
+        let details = self.localisation_provider.component_details(component)?;
+        let supported = details
+            .languages
+            .keys()
+            .cloned()
+            .collect::<Vec<RefCount<LanguageTag>>>();
+        Ok(negotiate(&self.expander, preferences, &supported)
+            .unwrap_or_else(|| RefCount::clone(&details.default)))
+    }
+
+    /// Negotiates the best supported language of every component of the localisation provider, for the user's
+    /// prioritised language preferences. See [`negotiate()`](Self::negotiate) for details.
+    pub fn negotiate_components(
+        &self,
+        preferences: &[(RefCount<LanguageTag>, f32)],
+    ) -> Result<HashMap<String, RefCount<LanguageTag>>, LocaliserError> {
+        let mut languages = HashMap::<String, RefCount<LanguageTag>>::new();
+        for component in self
+            .localisation_provider
+            .repository_details()?
+            .components
+            .iter()
+        {
+            languages.insert(
+                component.clone(),
+                self.negotiate(component.as_str(), preferences)?,
+            );
+        }
+        Ok(languages)
+    }
+
     // Internal methods
 
     // For the specified string identifier, format a string for the specified language tag with the supplied values
@@ -947,186 +1788,225 @@ impl Localiser {
         fallback: bool, // true = fallback to default language.
         caching: bool, // true = cache the resultant Formatter for repeating use with different values.
     ) -> Result<(RefCount<String>, RefCount<LanguageTag>), LocaliserError> {
-        let mut combined = component.to_string();
-        combined.push('/');
-        combined.push_str(identifier.as_ref());
-        let mut _language_entry = false;
-        {
-            #[cfg(not(feature = "sync"))]
-            let binding = self.cache.borrow();
-
-            #[cfg(feature = "sync")]
-            let binding = self.cache.read().unwrap();
-
-            if let Some(result) = binding.get(language_tag) {
-                _language_entry = true;
-                if let Some(result2) = result.get(&combined) {
-                    return match result2 {
-                        CacheData::Localised(string, tag) => {
-                            Ok((RefCount::clone(string), RefCount::clone(tag)))
-                        }
-
-                        #[cfg(not(feature = "sync"))]
-                        CacheData::Formatter(formatter) => {
-                            match formatter.borrow_mut().format(self, values) {
-                                Err(error) => Err(LocaliserError::Formatter(error)),
-                                Ok(localised) => {
-                                    Ok((RefCount::new(localised.0), RefCount::clone(localised.1)))
-                                }
-                            }
-                        }
+        let data = self.cache_data(component, identifier, language_tag, fallback, caching)?;
+        self.format_cache_data(data, values)
+    }
 
-                        #[cfg(feature = "sync")]
-                        CacheData::Formatter(formatter) => {
-                            match formatter.write().unwrap().format(self, values) {
-                                Err(error) => Err(LocaliserError::Formatter(error)),
-                                Ok(localised) => {
-                                    Ok((RefCount::new(localised.0), RefCount::clone(localised.1)))
-                                }
-                            }
-                        }
-                    };
-                }
-            }
+    // Obtain the cached entry, else get the pattern string for specified language and prepare it for formatting,
+    // though the pattern string may be for another language.
+    fn cache_data(
+        &self,
+        component: &str,
+        identifier: &str,
+        language_tag: &RefCount<LanguageTag>,
+        fallback: bool,
+        caching: bool,
+    ) -> Result<CacheData, LocaliserError> {
+        let key = CacheKey::new(language_tag, component, identifier, fallback);
+        if let Some(data) = self.cached(&key) {
+            return Ok(data);
         }
 
         // Not in cache.
-        // Get pattern string for specified language, though returned `(String, RefCount<LanguageTag>)`
-        // may be for another language.
-        let localised =
-            match self
-                .localisation_provider
-                .string(component, identifier, language_tag)?
-            {
-                Some(result) => result,
-                None => {
-                    if !fallback {
-                        return Err(LocaliserError::StringNotFound(
-                            component.to_string(),
-                            identifier.to_string(),
-                            language_tag.as_str().to_string(),
-                            false,
-                        ));
-                    }
-                    let default_language = &self
-                        .localisation_provider
-                        .component_details(component.as_ref())?
-                        .default;
-                    match self.localisation_provider.string(
-                        component.as_ref(),
-                        identifier.as_ref(),
-                        default_language,
-                    )? {
-                        Some(result) => result,
-                        None => {
-                            return Err(LocaliserError::StringNotFound(
-                                component.to_string(),
-                                identifier.to_string(),
-                                language_tag.as_str().to_owned(),
-                                true,
-                            ))
-                        }
-                    }
-                }
-            };
+        let localised = self.pattern_string(component, identifier, language_tag, fallback)?;
+        self.prepare_pattern_string(key, localised, language_tag, caching)
+    }
 
+    // Prepare the pattern string obtained from the provider, caching the result (if allowed) under the key.
+    fn prepare_pattern_string(
+        &self,
+        key: CacheKey,
+        localised: (String, RefCount<LanguageTag>),
+        language_tag: &RefCount<LanguageTag>,
+        caching: bool,
+    ) -> Result<CacheData, LocaliserError> {
         // Tokenise the pattern string.
         // If string is empty, skip over formatter, simply cache (if allowed) and return the empty string.
         // If pattern string has no grammar syntax characters, simply cache (if allowed) and return the string.
+        let pseudo = self.pseudo(language_tag);
         if localised.0.is_empty() {
+            let localised = self.pseudo_literal(localised, &pseudo, language_tag);
             let string = RefCount::new(localised.0);
             if caching {
-                self.add_string_to_cache(_language_entry, combined, &string, &localised.1);
-                return Ok((string, localised.1));
+                self.add_string_to_cache(key, &string, &localised.1);
             }
-            return Ok((string, localised.1));
+            return Ok(CacheData::Localised(string, localised.1));
         }
-        let mut formatter = match Formatter::try_new(self, localised.0.as_str(), &localised.1) {
-            Ok(value) => value,
-            Err(error) => match error {
-                FormatterError::NoGrammar => {
-                    // No grammar syntax was found, thus a literal string.
-                    let string = RefCount::new(localised.0);
-                    if caching {
-                        self.add_string_to_cache(_language_entry, combined, &string, &localised.1);
-                        return Ok((string, localised.1));
+        let mut formatter =
+            match Formatter::try_new(self, localised.0.as_str(), &localised.1, language_tag) {
+                Ok(value) => value,
+                Err(error) => match error {
+                    FormatterError::NoGrammar => {
+                        // No grammar syntax was found, thus a literal string.
+                        let localised = self.pseudo_literal(localised, &pseudo, language_tag);
+                        let string = RefCount::new(localised.0);
+                        if caching {
+                            self.add_string_to_cache(key, &string, &localised.1);
+                        }
+                        return Ok(CacheData::Localised(string, localised.1));
                     }
-                    return Ok((string, localised.1));
-                }
-                _ => return Err(LocaliserError::Formatter(error)),
-            },
-        };
-
-        // If caching is not allowed, simple use `Formatter` to get the (String, RefCount<LanguageTag>).
-        if !caching {
-            let localised = formatter.format(self, values)?;
-            return Ok((RefCount::new(localised.0), RefCount::clone(localised.1)));
+                    _ => return Err(LocaliserError::Formatter(error)),
+                },
+            };
+        if let Some((pseudo_localisation, locale)) = pseudo.as_ref() {
+            formatter.pseudo_localise(pseudo_localisation, *locale, language_tag);
         }
-
-        // Cache the `Formatter`.
-        {
+        let formatter = RefCount::new(formatter);
+        if caching {
             #[cfg(feature = "logging")]
             debug!("Caching formatting string.");
 
-            if !_language_entry {
-                let mut data_entry = HashMap::<String, CacheData>::new();
-                data_entry.insert(
-                    combined.clone(),
-                    CacheData::Formatter(MutCell::new(formatter)),
-                );
-
-                #[cfg(not(feature = "sync"))]
-                self.cache
-                    .borrow_mut()
-                    .insert(RefCount::clone(language_tag), data_entry);
+            #[cfg(not(feature = "sync"))]
+            let mut binding = self.cache.borrow_mut();
 
-                #[cfg(feature = "sync")]
-                self.cache
-                    .write()
-                    .unwrap()
-                    .insert(RefCount::clone(language_tag), data_entry);
-            } else {
-                #[cfg(not(feature = "sync"))]
-                let mut binding = self.cache.borrow_mut();
+            #[cfg(feature = "sync")]
+            let mut binding = self.cache.write().unwrap();
 
-                #[cfg(feature = "sync")]
-                let mut binding = self.cache.write().unwrap();
+            binding.insert(key, CacheData::Formatter(RefCount::clone(&formatter)));
+        }
+        Ok(CacheData::Formatter(formatter))
+    }
 
-                let data_entry = binding.get_mut(language_tag);
-                data_entry.unwrap().insert(
-                    combined.clone(),
-                    CacheData::Formatter(MutCell::new(formatter)),
-                );
-            }
+    // Format the cached entry, where a literal string is simply returned.
+    fn format_cache_data(
+        &self,
+        data: CacheData,
+        values: &HashMap<String, PlaceholderValue>,
+    ) -> Result<(RefCount<String>, RefCount<LanguageTag>), LocaliserError> {
+        match data {
+            CacheData::Localised(string, tag) => Ok((string, tag)),
+            CacheData::Formatter(formatter) => self.format_cached(&formatter, values),
         }
+    }
 
-        // Get `Formatter` and use it to get the (String, RefCount<LanguageTag>).
+    // Obtain a copy of the cached entry, thus the cache is not borrowed while formatting. The lookup only needs
+    // shared access to the cache.
+    fn cached(&self, key: &CacheKey) -> Option<CacheData> {
         #[cfg(not(feature = "sync"))]
         let binding = self.cache.borrow();
 
         #[cfg(feature = "sync")]
         let binding = self.cache.read().unwrap();
 
-        let result = binding.get(language_tag).unwrap();
-        let result2 = result.get(&combined).unwrap();
-        match result2 {
-            // This branch should never be reached.
-            CacheData::Localised(_, _) => Err(LocaliserError::NeverReached),
+        binding.get(key).cloned()
+    }
 
-            #[cfg(not(feature = "sync"))]
-            CacheData::Formatter(formatter) => match formatter.borrow_mut().format(self, values) {
-                Err(error) => Err(LocaliserError::Formatter(error)),
-                Ok(localised) => Ok((RefCount::new(localised.0), RefCount::clone(localised.1))),
-            },
+    // The `Formatter` is not mutated while formatting, thus a cached `Formatter` is shared without locking.
+    fn format_cached(
+        &self,
+        formatter: &Formatter,
+        values: &HashMap<String, PlaceholderValue>,
+    ) -> Result<(RefCount<String>, RefCount<LanguageTag>), LocaliserError> {
+        let localised = formatter.format(self, values)?;
+        Ok((RefCount::new(localised.0), RefCount::clone(localised.1)))
+    }
 
-            #[cfg(feature = "sync")]
-            CacheData::Formatter(formatter) => {
-                match formatter.write().unwrap().format(self, values) {
-                    Err(error) => Err(LocaliserError::Formatter(error)),
-                    Ok(localised) => Ok((RefCount::new(localised.0), RefCount::clone(localised.1))),
+    // Get the pattern string for the language tag, else when `fallback` is `true` walk the fallback chain of the
+    // language tag (the component's chain, else the `Localiser` chain), and finally try the component's default
+    // language. The returned language tag is the language of the string that was found.
+    fn pattern_string(
+        &self,
+        component: &str,
+        identifier: &str,
+        language_tag: &RefCount<LanguageTag>,
+        fallback: bool,
+    ) -> Result<(String, RefCount<LanguageTag>), LocaliserError> {
+        // The pseudo-locales are produced from the string of the component's default language.
+        let pseudo = self.pseudo(language_tag).is_some();
+        if !pseudo {
+            if let Some(result) =
+                self.localisation_provider
+                    .string(component, identifier, language_tag)?
+            {
+                return Ok(result);
+            }
+        }
+        if !fallback && !pseudo {
+            return Err(LocaliserError::StringNotFound(
+                component.to_string(),
+                identifier.to_string(),
+                language_tag.as_str().to_string(),
+                false,
+            ));
+        }
+        if let Some(chain) = self
+            .fallback_chain(component, language_tag)
+            .filter(|_| !pseudo)
+        {
+            for tag in chain.iter() {
+                if let Some(result) = self
+                    .localisation_provider
+                    .string(component, identifier, tag)?
+                {
+                    return Ok(result);
                 }
             }
         }
+        let default_language = &self
+            .localisation_provider
+            .component_details(component)?
+            .default;
+        match self
+            .localisation_provider
+            .string(component, identifier, default_language)?
+        {
+            Some(result) => Ok(result),
+            None => Err(LocaliserError::StringNotFound(
+                component.to_string(),
+                identifier.to_string(),
+                language_tag.as_str().to_owned(),
+                !pseudo,
+            )),
+        }
+    }
+
+    // The asynchronous counterpart of `pattern_string()`, using the asynchronous provider.
+    #[cfg(feature = "async")]
+    async fn pattern_string_async(
+        &self,
+        provider: &dyn AsyncLocalisationProviderTrait,
+        component: &str,
+        identifier: &str,
+        language_tag: &RefCount<LanguageTag>,
+        fallback: bool,
+    ) -> Result<(String, RefCount<LanguageTag>), LocaliserError> {
+        let pseudo = self.pseudo(language_tag).is_some();
+        if !pseudo {
+            if let Some(result) = provider.string(component, identifier, language_tag).await? {
+                return Ok(result);
+            }
+        }
+        if !fallback && !pseudo {
+            return Err(LocaliserError::StringNotFound(
+                component.to_string(),
+                identifier.to_string(),
+                language_tag.as_str().to_string(),
+                false,
+            ));
+        }
+        if let Some(chain) = self
+            .fallback_chain(component, language_tag)
+            .filter(|_| !pseudo)
+        {
+            for tag in chain.iter() {
+                if let Some(result) = provider.string(component, identifier, tag).await? {
+                    return Ok(result);
+                }
+            }
+        }
+        let default_language = &provider.component_details(component).await?.default;
+        match provider
+            .string(component, identifier, default_language)
+            .await?
+        {
+            Some(result) => Ok(result),
+            None => Err(LocaliserError::StringNotFound(
+                component.to_string(),
+                identifier.to_string(),
+                language_tag.as_str().to_owned(),
+                !pseudo,
+            )),
+        }
     }
 
     // Simply get the language string without any formatting being done.
@@ -1138,76 +2018,26 @@ impl Localiser {
         fallback: bool, // true = fallback to default language, None = use the Localiser default.
         caching: bool, // true = cache the resultant Formatter for repeating use with different values.
     ) -> Result<(RefCount<String>, RefCount<LanguageTag>), LocaliserError> {
-        let mut combined = component.to_string();
-        combined.push('/');
-        combined.push_str(identifier);
-        let mut _language_entry = false;
-        {
-            #[cfg(not(feature = "sync"))]
-            let binding = self.cache.borrow();
-
-            #[cfg(feature = "sync")]
-            let binding = self.cache.read().unwrap();
-
-            if let Some(result) = binding.get(language_tag) {
-                _language_entry = true;
-                if let Some(result2) = result.get(&combined) {
-                    return match result2 {
-                        CacheData::Localised(string, tag) => {
-                            Ok((RefCount::clone(string), RefCount::clone(tag)))
-                        } //lstring.clone()),
-                        CacheData::Formatter(_formatter) => Err(LocaliserError::CacheEntry(
-                            component.to_string(),
-                            identifier.to_string(),
-                        )),
-                    };
-                }
-            }
+        let key = CacheKey::new(language_tag, component, identifier, fallback);
+        if let Some(data) = self.cached(&key) {
+            return match data {
+                CacheData::Localised(string, tag) => Ok((string, tag)),
+                CacheData::Formatter(_formatter) => Err(LocaliserError::CacheEntry(
+                    component.to_string(),
+                    identifier.to_string(),
+                )),
+            };
         }
 
         // Not in cache.
         // Get pattern string for specified language, though returned `(String, RefCount<LanguageTag>)` may be for another language.
-        let localised =
-            match self
-                .localisation_provider
-                .string(component, identifier, language_tag)?
-            {
-                Some(result) => result,
-                None => {
-                    if !fallback {
-                        return Err(LocaliserError::StringNotFound(
-                            component.to_string(),
-                            identifier.to_string(),
-                            language_tag.as_str().to_string(),
-                            false,
-                        ));
-                    }
-                    let default_language = &self
-                        .localisation_provider
-                        .component_details(component.as_ref())?
-                        .default;
-                    match self.localisation_provider.string(
-                        component.as_ref(),
-                        identifier.as_ref(),
-                        default_language,
-                    )? {
-                        Some(result) => result,
-                        None => {
-                            return Err(LocaliserError::StringNotFound(
-                                component.to_string(),
-                                identifier.to_string(),
-                                language_tag.as_str().to_owned(),
-                                true,
-                            ))
-                        }
-                    }
-                }
-            };
+        let localised = self.pattern_string(component, identifier, language_tag, fallback)?;
+        let localised = self.pseudo_literal(localised, &self.pseudo(language_tag), language_tag);
 
         // Since pattern string is treated as literal, simply cache (if allowed) and return the string.
         let string = RefCount::new(localised.0);
         if caching {
-            self.add_string_to_cache(_language_entry, combined, &string, &localised.1);
+            self.add_string_to_cache(key, &string, &localised.1);
         }
         Ok((string, RefCount::clone(&localised.1)))
     }
@@ -1219,96 +2049,177 @@ impl Localiser {
         fallback: bool, // true = fallback to default language
         caching: bool, // true = cache the resultant Formatter for repeating use with different values.
     ) -> Result<(RefCount<String>, RefCount<LanguageTag>), LocaliserError> {
-        Ok(if data.values.is_none() {
-            self.actual_literal(
+        Ok(match data.values.as_ref() {
+            None => self.actual_literal(
                 data.component.as_str(),
                 data.identifier.as_str(),
                 language_tag,
                 fallback,
                 caching,
-            )?
-        } else {
-            let mut values_new = HashMap::<String, PlaceholderValue>::new();
-            for (placeholder, value) in data.values.as_ref().unwrap() {
-                match value {
-                    PlaceholderValue::LocalisationData(inner) => {
-                        let localised = self.actual_format_localisation_data(
-                            inner,
-                            language_tag,
-                            fallback,
-                            caching,
-                        )?;
-                        let _ = values_new.insert(
-                            placeholder.clone(),
-                            PlaceholderValue::Localised(localised.0, localised.1),
-                        );
-                    }
-                    _ => {
-                        let _ = values_new.insert(placeholder.to_string(), value.clone());
+            )?,
+            Some(values) => {
+                let mut values_new = HashMap::<String, PlaceholderValue>::new();
+                for (placeholder, value) in values {
+                    match value {
+                        PlaceholderValue::LocalisationData(inner) => {
+                            let localised = self.actual_format_localisation_data(
+                                inner,
+                                language_tag,
+                                fallback,
+                                caching,
+                            )?;
+                            let _ = values_new.insert(
+                                placeholder.clone(),
+                                PlaceholderValue::Localised(localised.0, localised.1),
+                            );
+                        }
+                        _ => {
+                            let _ = values_new.insert(placeholder.to_string(), value.clone());
+                        }
                     }
                 }
+                self.actual_format(
+                    data.component.as_str(),
+                    data.identifier.as_str(),
+                    &values_new,
+                    language_tag,
+                    fallback,
+                    caching,
+                )?
             }
-            self.actual_format(
-                data.component.as_str(),
-                data.identifier.as_str(),
-                &values_new,
-                language_tag,
-                fallback,
-                caching,
-            )?
         })
     }
 
+    // Obtain the pseudo-localisation settings, when enabled and the language tag is a pseudo-locale.
+    fn pseudo(
+        &self,
+        language_tag: &RefCount<LanguageTag>,
+    ) -> Option<(PseudoLocalisation, PseudoLocale)> {
+        let locale = PseudoLocale::from_language_tag(language_tag)?;
+        self.pseudo_localisation()
+            .map(|pseudo_localisation| (pseudo_localisation, locale))
+    }
+
+    // Transform the whole literal string for the pseudo-locale, and wrap it with the brackets.
+    fn pseudo_literal(
+        &self,
+        localised: (String, RefCount<LanguageTag>),
+        pseudo: &Option<(PseudoLocalisation, PseudoLocale)>,
+        language_tag: &RefCount<LanguageTag>,
+    ) -> (String, RefCount<LanguageTag>) {
+        match pseudo {
+            None => localised,
+            Some((pseudo_localisation, locale)) => (
+                format!(
+                    "{}{}{}",
+                    pseudo_localisation.brackets.0,
+                    pseudo_localisation.transform(*locale, localised.0.as_str()),
+                    pseudo_localisation.brackets.1
+                ),
+                RefCount::clone(language_tag),
+            ),
+        }
+    }
+
     fn add_string_to_cache(
         &self,
-        language_exists: bool,
-        combined: String,
+        key: CacheKey,
         string: &RefCount<String>,
         tag: &RefCount<LanguageTag>,
     ) {
         #[cfg(feature = "logging")]
         debug!("Caching literal string.");
 
-        if !language_exists {
-            // Language tag does not exist in cache
-            let mut data_entry = HashMap::<String, CacheData>::new();
-            data_entry.insert(
-                combined.to_string(),
-                CacheData::Localised(RefCount::clone(string), RefCount::clone(tag)),
-            );
-
-            #[cfg(not(feature = "sync"))]
-            self.cache
-                .borrow_mut()
-                .insert(RefCount::clone(tag), data_entry);
-
-            #[cfg(feature = "sync")]
-            self.cache
-                .write()
-                .unwrap()
-                .insert(RefCount::clone(tag), data_entry);
-        } else {
-            // Language tag does exist in cache
-            #[cfg(not(feature = "sync"))]
-            let mut binding = self.cache.borrow_mut();
+        #[cfg(not(feature = "sync"))]
+        let mut binding = self.cache.borrow_mut();
 
-            #[cfg(feature = "sync")]
-            let mut binding = self.cache.write().unwrap();
+        #[cfg(feature = "sync")]
+        let mut binding = self.cache.write().unwrap();
 
-            let data_entry = binding.get_mut(tag);
-            data_entry.unwrap().insert(
-                combined,
-                CacheData::Localised(RefCount::clone(string), RefCount::clone(tag)),
-            );
-        }
+        binding.insert(
+            key,
+            CacheData::Localised(RefCount::clone(string), RefCount::clone(tag)),
+        );
     }
 }
 
 // Internal structs, enums, etc
 
+#[derive(Clone)]
 enum CacheData {
     Localised(RefCount<String>, RefCount<LanguageTag>),
-    Formatter(MutCell<Formatter>),
+    Formatter(RefCount<Formatter>),
+}
+
+// The synchronous localisation provider of a `Localiser` created by `try_new_async()`, which has no components.
+#[cfg(feature = "async")]
+struct NoLocalisationProvider;
+
+#[cfg(feature = "async")]
+impl LocalisationProviderTrait for NoLocalisationProvider {
+    fn string(
+        &self,
+        _component: &str,
+        _identifier: &str,
+        _language_tag: &RefCount<LanguageTag>,
+    ) -> Result<Option<(String, RefCount<LanguageTag>)>, ProviderError> {
+        Ok(None)
+    }
+
+    fn string_exact_match(
+        &self,
+        _component: &str,
+        _identifier: &str,
+        _language_tag: &RefCount<LanguageTag>,
+    ) -> Result<Option<(String, RefCount<LanguageTag>)>, ProviderError> {
+        Ok(None)
+    }
+
+    fn strings(
+        &self,
+        _component: &str,
+        _identifier: &str,
+        _language_tag: &RefCount<LanguageTag>,
+    ) -> Result<Vec<(String, RefCount<LanguageTag>)>, ProviderError> {
+        Ok(Vec::<(String, RefCount<LanguageTag>)>::new())
+    }
+
+    fn identifier_details(
+        &self,
+        component: &str,
+        _identifier: &str,
+    ) -> Result<IdentifierDetails, ProviderError> {
+        Err(ProviderError::ComponentNotFound(component.to_string()))
+    }
+
+    fn component_details(
+        &self,
+        component: &str,
+    ) -> Result<RefCount<ComponentDetails>, ProviderError> {
+        Err(ProviderError::ComponentNotFound(component.to_string()))
+    }
+
+    fn repository_details(&self) -> Result<RefCount<RepositoryDetails>, ProviderError> {
+        Ok(RefCount::new(RepositoryDetails {
+            languages: HashMap::new(),
+            default: None,
+            total_strings: 0,
+            components: Vec::<String>::new(),
+            contributors: Vec::<String>::new(),
+        }))
+    }
+}
+
+// Removes the last subtag of the language tag, together with a singleton that would be left as the last subtag, such
+// as `u` of `-u-ca-buddhist`. `None` indicates there is only the language subtag.
+fn less_specific(tag: &str) -> Option<&str> {
+    let mut tag = &tag[..tag.rfind('-')?];
+    if let Some(position) = tag.rfind('-') {
+        if tag.len() - position == 2 {
+            tag = &tag[..position];
+        }
+    }
+    Some(tag)
 }
 
 fn get_script_data(
@@ -1319,34 +2230,27 @@ fn get_script_data(
     #[cfg(feature = "icu_extended")]
     {
         let mut locale = Locale::try_from_bytes(tag.as_bytes()).unwrap();
-        if locale.id.script.is_some() {
-            return script_data.get(locale.id.script.unwrap().as_str()).cloned();
-        } else {
-            match expander.maximize(locale.as_mut()) {
-                TransformResult::Modified => {
-                    return script_data.get(locale.id.script.unwrap().as_str()).cloned()
-                }
-                TransformResult::Unmodified => None,
+        if locale.id.script.is_none() {
+            if let TransformResult::Unmodified = expander.maximize(locale.as_mut()) {
+                return None;
             }
         }
+        locale
+            .id
+            .script
+            .and_then(|script| script_data.get(script.as_str()).cloned())
     }
 
     #[cfg(not(feature = "icu_extended"))]
     {
         let mut identifier = LanguageIdentifier::try_from_bytes(tag.as_bytes()).unwrap();
-        if identifier.script.is_some() {
-            return script_data
-                .get(identifier.script.unwrap().as_str())
-                .cloned();
-        } else {
-            match expander.maximize(identifier.as_mut()) {
-                TransformResult::Modified => {
-                    return script_data
-                        .get(identifier.script.unwrap().as_str())
-                        .cloned()
-                }
-                TransformResult::Unmodified => None,
+        if identifier.script.is_none() {
+            if let TransformResult::Unmodified = expander.maximize(identifier.as_mut()) {
+                return None;
             }
         }
+        identifier
+            .script
+            .and_then(|script| script_data.get(script.as_str()).cloned())
     }
 }
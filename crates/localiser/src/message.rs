@@ -0,0 +1,70 @@
+// This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
+
+use crate::{Localiser, LocaliserError};
+use core::fmt::{Display, Formatter, Result as FmtResult, Write};
+use i18n_utility::{LanguageTag, PlaceholderValue};
+use std::collections::HashMap;
+
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as RefCount;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::Arc as RefCount;
+
+/// A lazy localised message created by `Localiser::message()`, which borrows the arguments of `Localiser::format()`
+/// and is only formatted when displayed. The [`Display`] streams the formatted string into the output using
+/// `Localiser::format_into()`, thus no string is allocated for the message.
+///
+/// As [`Display`] can't return the error, a failed formatting is only reported as [`core::fmt::Error`], and
+/// `to_string()` will panic. Use `write_to()` to obtain the [`LocaliserError`].
+#[derive(Clone, Copy)]
+pub struct Message<'a> {
+    pub(crate) localiser: &'a Localiser,
+    pub(crate) component: &'a str,
+    pub(crate) identifier: &'a str,
+    pub(crate) values: &'a HashMap<String, PlaceholderValue>,
+    pub(crate) language_tag: &'a RefCount<LanguageTag>,
+    pub(crate) fallback: Option<bool>,
+    pub(crate) caching: Option<bool>,
+}
+
+impl<'a> Message<'a> {
+    /// Set whether to fallback to the default language, where [`None`] uses the `Localiser` default.
+    pub fn with_fallback(mut self, fallback: Option<bool>) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Set whether to cache the parsed string, where [`None`] uses the `Localiser` default.
+    pub fn with_caching(mut self, caching: Option<bool>) -> Self {
+        self.caching = caching;
+        self
+    }
+
+    /// Write the formatted message into the `writer`, returning the language tag of the pattern string.
+    pub fn write_to<W: Write + ?Sized>(
+        &self,
+        writer: &mut W,
+    ) -> Result<RefCount<LanguageTag>, LocaliserError> {
+        self.localiser.format_into(
+            writer,
+            self.component,
+            self.identifier,
+            self.values,
+            self.language_tag,
+            self.fallback,
+            self.caching,
+        )
+    }
+}
+
+impl Display for Message<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        match self.write_to(formatter) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(core::fmt::Error),
+        }
+    }
+}
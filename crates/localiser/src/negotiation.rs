@@ -0,0 +1,118 @@
+// This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
+
+use i18n_utility::LanguageTag;
+use icu_locid::{LanguageIdentifier, Locale};
+use icu_locid_transform::LocaleExpander;
+
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as RefCount;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::Arc as RefCount;
+
+/// Distances equal or above this value are not considered a match, that is different languages and different scripts
+/// of the same language are not mutually intelligible.
+pub(crate) const DISTANCE_THRESHOLD: u8 = 50;
+
+// Distances of the subtags, loosely based on the default values of the CLDR language matching data.
+const DISTANCE_LANGUAGE: u8 = 100;
+const DISTANCE_SCRIPT: u8 = 50;
+const DISTANCE_REGION: u8 = 4;
+const DISTANCE_VARIANT: u8 = 1;
+const DISTANCE_LIKELY: u8 = 1;
+
+/// Selects the best supported language for the weighted preferences.
+///
+/// Each supported language is scored by the weight of the preference multiplied by the closeness of the languages
+/// (100 less the distance). The highest score wins, and for equal scores the earlier preference wins. Preferences
+/// with a weight of zero or less are ignored, as they indicate the language is not acceptable.
+///
+/// Returns `None` when no supported language is within the distance threshold of any preference.
+pub(crate) fn negotiate(
+    expander: &LocaleExpander,
+    preferences: &[(RefCount<LanguageTag>, f32)],
+    supported: &[RefCount<LanguageTag>],
+) -> Option<RefCount<LanguageTag>> {
+    let mut supported = supported
+        .iter()
+        .filter_map(|tag| identifier(tag.as_str()).map(|identifier| (tag, identifier)))
+        .map(|(tag, identifier)| {
+            let mut maximised = identifier.clone();
+            expander.maximize(&mut maximised);
+            (tag, identifier, maximised)
+        })
+        .collect::<Vec<(
+            &RefCount<LanguageTag>,
+            LanguageIdentifier,
+            LanguageIdentifier,
+        )>>();
+    supported.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+    let mut best: Option<(&RefCount<LanguageTag>, f32)> = None;
+    for (tag, weight) in preferences.iter() {
+        if *weight <= 0.0 {
+            continue;
+        }
+        let Some(desired) = identifier(tag.as_str()) else {
+            continue;
+        };
+        let mut desired_maximised = desired.clone();
+        expander.maximize(&mut desired_maximised);
+        for (supported_tag, supported_identifier, supported_maximised) in supported.iter() {
+            let distance = distance(
+                &desired,
+                &desired_maximised,
+                supported_identifier,
+                supported_maximised,
+            );
+            if distance >= DISTANCE_THRESHOLD {
+                continue;
+            }
+            let score = weight * (100 - distance) as f32;
+            if best.map_or(true, |(_, best_score)| score > best_score) {
+                best = Some((supported_tag, score));
+            }
+        }
+    }
+    best.map(|(tag, _)| RefCount::clone(tag))
+}
+
+// Internal functions.
+
+// Language tags with extensions (`-u-`, `-t-`, etc.) are not valid language identifiers.
+fn identifier(tag: &str) -> Option<LanguageIdentifier> {
+    Locale::try_from_bytes(tag.as_bytes())
+        .ok()
+        .map(|locale| locale.id)
+}
+
+fn distance(
+    desired: &LanguageIdentifier,
+    desired_maximised: &LanguageIdentifier,
+    supported: &LanguageIdentifier,
+    supported_maximised: &LanguageIdentifier,
+) -> u8 {
+    if desired == supported {
+        return 0;
+    }
+    if desired_maximised.language != supported_maximised.language {
+        return DISTANCE_LANGUAGE;
+    }
+    if desired_maximised.script != supported_maximised.script {
+        return DISTANCE_SCRIPT;
+    }
+    let mut distance = 0u8;
+    if desired_maximised.region != supported_maximised.region {
+        distance += DISTANCE_REGION;
+    }
+    if desired_maximised.variants != supported_maximised.variants {
+        distance += DISTANCE_VARIANT;
+    }
+
+    // Same language after adding the likely subtags, such as `en` and `en-US`, is preferred over other regions.
+    if distance == 0 {
+        distance = DISTANCE_LIKELY;
+    }
+    distance
+}
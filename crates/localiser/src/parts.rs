@@ -0,0 +1,261 @@
+// This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
+
+use core::fmt::{Display, Formatter, Result as FmtResult, Write};
+use std::collections::HashMap;
+
+/// A segment of a string formatted by `Localiser::format_to_parts()`, allowing user interfaces to style the
+/// placeholder values, and to apply the markup tags of the pattern string.
+///
+/// * `Text`: The literal text of the pattern string,
+///
+/// * `Placeholder`: The placeholder name and the formatted value, of placeholders without a keyword and of the
+///   `date_time` keyword,
+///
+/// * `Number`: The placeholder name and the [`NumberPart`]s of the formatted number, of the `decimal` keyword and of
+///   the number sign `#` of the `plural` and `ordinal` keywords,
+///
+/// * `Command`: The command name and the string returned by a delayed command `{#command # ...}`, as the other
+///   commands are part of the text,
+///
+/// * `MarkupOpen`: The name and options of an opening markup tag `{name open}`, which has no text,
+///
+/// * `MarkupClose`: The name of a closing markup tag `{name close}`, which has no text.
+///
+/// The [`Display`] of the parts is the text of the parts, thus the concatenation of the parts is the string of
+/// `Localiser::format()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormattedPart {
+    Text(String),
+    Placeholder {
+        placeholder: String,
+        value: String,
+    },
+    Number {
+        placeholder: String,
+        parts: Vec<NumberPart>,
+    },
+    Command {
+        command: String,
+        value: String,
+    },
+    MarkupOpen {
+        name: String,
+        options: HashMap<String, String>,
+    },
+    MarkupClose {
+        name: String,
+    },
+}
+
+impl Display for FormattedPart {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        match self {
+            FormattedPart::Text(text) => formatter.write_str(text),
+            FormattedPart::Placeholder { value, .. } | FormattedPart::Command { value, .. } => {
+                formatter.write_str(value)
+            }
+            FormattedPart::Number { parts, .. } => {
+                for part in parts.iter() {
+                    part.fmt(formatter)?;
+                }
+                Ok(())
+            }
+            FormattedPart::MarkupOpen { .. } | FormattedPart::MarkupClose { .. } => Ok(()),
+        }
+    }
+}
+
+/// A segment of a formatted number.
+///
+/// * `Sign`: The characters before and after the digits, such as the minus and plus signs,
+///
+/// * `Integer`: A run of digits of the integer,
+///
+/// * `Group`: The grouping separator between the runs of digits of the integer,
+///
+/// * `Decimal`: The decimal separator,
+///
+/// * `Fraction`: The digits of the fraction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumberPart {
+    Sign(String),
+    Integer(String),
+    Group(String),
+    Decimal(String),
+    Fraction(String),
+}
+
+impl Display for NumberPart {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        match self {
+            NumberPart::Sign(string)
+            | NumberPart::Integer(string)
+            | NumberPart::Group(string)
+            | NumberPart::Decimal(string)
+            | NumberPart::Fraction(string) => formatter.write_str(string),
+        }
+    }
+}
+
+/// Split the formatted number into its parts, where `fraction` is the number of fraction digits of the number. The
+/// digits of any numbering system are supported.
+pub(crate) fn number_parts(number: &str, fraction: usize) -> Vec<NumberPart> {
+    let characters = number.chars().collect::<Vec<char>>();
+    let mut parts = Vec::<NumberPart>::new();
+    let Some(first) = characters
+        .iter()
+        .position(|character| character.is_numeric())
+    else {
+        parts.push(NumberPart::Sign(number.to_string()));
+        return parts;
+    };
+    let last = characters
+        .iter()
+        .rposition(|character| character.is_numeric())
+        .unwrap();
+    if first > 0 {
+        parts.push(NumberPart::Sign(characters[..first].iter().collect()));
+    }
+
+    // Locate the fraction digits and the decimal separator from the end of the digits.
+    let mut end = last + 1;
+    let mut fraction_part: Option<(NumberPart, NumberPart)> = None;
+    if fraction > 0 && end - first > fraction {
+        let start = end - fraction;
+        let mut separator = start;
+        while separator > first && !characters[separator - 1].is_numeric() {
+            separator -= 1;
+        }
+        if separator < start {
+            fraction_part = Some((
+                NumberPart::Decimal(characters[separator..start].iter().collect()),
+                NumberPart::Fraction(characters[start..end].iter().collect()),
+            ));
+            end = separator;
+        }
+    }
+
+    // The runs of digits of the integer are separated by the grouping separators.
+    let mut run = String::new();
+    let mut numeric = true;
+    for character in characters[first..end].iter() {
+        if character.is_numeric() != numeric && !run.is_empty() {
+            parts.push(match numeric {
+                true => NumberPart::Integer(run),
+                false => NumberPart::Group(run),
+            });
+            run = String::new();
+        }
+        numeric = character.is_numeric();
+        run.push(*character);
+    }
+    if !run.is_empty() {
+        parts.push(NumberPart::Integer(run));
+    }
+    if let Some((decimal, fraction)) = fraction_part {
+        parts.push(decimal);
+        parts.push(fraction);
+    }
+    if last + 1 < characters.len() {
+        parts.push(NumberPart::Sign(characters[last + 1..].iter().collect()));
+    }
+    parts
+}
+
+/// The destination of the formatted pattern string, being either a [`Write`] (such as a flat string) or the
+/// [`FormattedPart`]s. Only a `Write` may fail.
+pub(crate) trait FormatSink {
+    fn text(&mut self, text: &str) -> FmtResult;
+
+    fn placeholder(&mut self, placeholder: &str, value: &str) -> FmtResult;
+
+    fn number(&mut self, placeholder: &str, number: &str, fraction: usize) -> FmtResult;
+
+    fn command(&mut self, command: &str, value: &str) -> FmtResult;
+
+    fn markup_open(&mut self, name: &str, options: &HashMap<String, String>) -> FmtResult;
+
+    fn markup_close(&mut self, name: &str) -> FmtResult;
+}
+
+/// Writes the text of the formatted pattern string directly into the wrapped [`Write`], such as a [`String`] or a
+/// [`Formatter`], without the intermediate strings of the parts.
+pub(crate) struct WriteSink<'a, W: Write + ?Sized>(pub(crate) &'a mut W);
+
+impl<W: Write + ?Sized> FormatSink for WriteSink<'_, W> {
+    fn text(&mut self, text: &str) -> FmtResult {
+        self.0.write_str(text)
+    }
+
+    fn placeholder(&mut self, _placeholder: &str, value: &str) -> FmtResult {
+        self.0.write_str(value)
+    }
+
+    fn number(&mut self, _placeholder: &str, number: &str, _fraction: usize) -> FmtResult {
+        self.0.write_str(number)
+    }
+
+    fn command(&mut self, _command: &str, value: &str) -> FmtResult {
+        self.0.write_str(value)
+    }
+
+    fn markup_open(&mut self, _name: &str, _options: &HashMap<String, String>) -> FmtResult {
+        Ok(())
+    }
+
+    fn markup_close(&mut self, _name: &str) -> FmtResult {
+        Ok(())
+    }
+}
+
+impl FormatSink for Vec<FormattedPart> {
+    fn text(&mut self, text: &str) -> FmtResult {
+        // Consecutive texts, such as of a selected branch, are joined.
+        if let Some(FormattedPart::Text(last)) = self.last_mut() {
+            last.push_str(text);
+        } else if !text.is_empty() {
+            self.push(FormattedPart::Text(text.to_string()));
+        }
+        Ok(())
+    }
+
+    fn placeholder(&mut self, placeholder: &str, value: &str) -> FmtResult {
+        self.push(FormattedPart::Placeholder {
+            placeholder: placeholder.to_string(),
+            value: value.to_string(),
+        });
+        Ok(())
+    }
+
+    fn number(&mut self, placeholder: &str, number: &str, fraction: usize) -> FmtResult {
+        self.push(FormattedPart::Number {
+            placeholder: placeholder.to_string(),
+            parts: number_parts(number, fraction),
+        });
+        Ok(())
+    }
+
+    fn command(&mut self, command: &str, value: &str) -> FmtResult {
+        self.push(FormattedPart::Command {
+            command: command.to_string(),
+            value: value.to_string(),
+        });
+        Ok(())
+    }
+
+    fn markup_open(&mut self, name: &str, options: &HashMap<String, String>) -> FmtResult {
+        self.push(FormattedPart::MarkupOpen {
+            name: name.to_string(),
+            options: options.clone(),
+        });
+        Ok(())
+    }
+
+    fn markup_close(&mut self, name: &str) -> FmtResult {
+        self.push(FormattedPart::MarkupClose {
+            name: name.to_string(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+// This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
+
+use i18n_utility::LanguageTag;
+
+/// The pseudo-locales produced by the `Localiser` from the strings of the component's default language, for
+/// detecting hard-coded strings and layout issues before the translations exist.
+///
+/// * `Accented`: The language tag `en-XA`, where the Latin letters are replaced with accented letters,
+///
+/// * `Bidi`: The language tag `ar-XB`, where each word is embedded as right-to-left text, thus the text is displayed
+///   mirrored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PseudoLocale {
+    Accented,
+    Bidi,
+}
+
+impl PseudoLocale {
+    /// Obtain the pseudo-locale of the language tag, where [`None`] indicates the language tag is not a
+    /// pseudo-locale.
+    pub fn from_language_tag(language_tag: &LanguageTag) -> Option<PseudoLocale> {
+        match language_tag.as_str() {
+            "en-XA" => Some(PseudoLocale::Accented),
+            "ar-XB" => Some(PseudoLocale::Bidi),
+            _ => None,
+        }
+    }
+
+    /// Obtain the language tag of the pseudo-locale.
+    pub fn language_tag(&self) -> &'static str {
+        match self {
+            PseudoLocale::Accented => "en-XA",
+            PseudoLocale::Bidi => "ar-XB",
+        }
+    }
+}
+
+/// The settings of pseudo-localisation, which transforms the text of a pattern string without changing the
+/// placeholders, commands and grammar of the pattern.
+///
+/// `expansion`: Is the percentage of padding added to each text, as translations are commonly longer than the
+/// source strings. The whitespace of the text is not counted.
+///
+/// `brackets`: Is the opening and closing brackets wrapping the whole string, thus truncated strings and strings
+/// concatenated in code are visible.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_localiser::{PseudoLocale, PseudoLocalisation};
+///
+/// let pseudo = PseudoLocalisation::new(50);
+/// assert_eq!(
+///     pseudo.transform(PseudoLocale::Accented, "Save file"),
+///     "Šåṽé ƒîļé~~~~",
+///     "Check accented text."
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PseudoLocalisation {
+    pub expansion: usize,
+    pub brackets: (String, String),
+}
+
+impl PseudoLocalisation {
+    /// Creates the settings for the `expansion` percentage, using the brackets `[` and `]`.
+    pub fn new(expansion: usize) -> Self {
+        PseudoLocalisation {
+            expansion,
+            brackets: ("[".to_string(), "]".to_string()),
+        }
+    }
+
+    /// Transform the text for the pseudo-locale, and add the padding of the expansion percentage.
+    pub fn transform(&self, locale: PseudoLocale, text: &str) -> String {
+        let mut string = String::with_capacity(text.len() * 2);
+        let mut letters = 0usize;
+        let mut word = false;
+        for character in text.chars() {
+            if character.is_whitespace() {
+                if word {
+                    string.push(POP_DIRECTIONAL_FORMATTING);
+                    word = false;
+                }
+                string.push(character);
+                continue;
+            }
+            letters += 1;
+            match locale {
+                PseudoLocale::Accented => string.push(accented(character)),
+                PseudoLocale::Bidi => {
+                    if !word {
+                        string.push(RIGHT_TO_LEFT_OVERRIDE);
+                        word = true;
+                    }
+                    string.push(character);
+                }
+            }
+        }
+        if word {
+            string.push(POP_DIRECTIONAL_FORMATTING);
+        }
+        let padding = (letters * self.expansion + 99) / 100;
+        string.extend(std::iter::repeat(PADDING).take(padding));
+        string
+    }
+}
+
+impl Default for PseudoLocalisation {
+    /// The default expansion is 30 percent.
+    fn default() -> Self {
+        PseudoLocalisation::new(30)
+    }
+}
+
+// Internal constants and functions
+
+const RIGHT_TO_LEFT_OVERRIDE: char = '\u{202E}';
+const POP_DIRECTIONAL_FORMATTING: char = '\u{202C}';
+const PADDING: char = '~';
+
+fn accented(character: char) -> char {
+    match character {
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Ð',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Î',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ŕ',
+        'S' => 'Š',
+        'T' => 'Ţ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        'a' => 'å',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'ð',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'î',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ɱ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        _ => character,
+    }
+}
@@ -161,7 +161,7 @@ impl Tree {
             nested_states: Vec::<ParserState>::new(),
         };
         let mut named_strings = HashMap::<String, usize>::new();
-        let mut patterns = HashMap::<String, usize>::new();
+        let mut patterns = HashMap::<String, Vec<usize>>::new();
 
         // Process the tokens
         let mut lexer = LexerIterator::try_new(string, grammar, icu_data_provider)?.enumerate();
@@ -687,6 +687,21 @@ impl Tree {
         if !parser.nested_states.is_empty() {
             return Err(TreeError::EndedAbruptly);
         }
+
+        // The keywords of the patterns are only known once parsed. A name may only be repeated by markup tags, and
+        // the first repeated name in the string is reported.
+        if let Some((name, _)) = patterns
+            .iter()
+            .filter(|(_, indices)| {
+                indices.len() > 1
+                    && !indices
+                        .iter()
+                        .all(|pattern| Tree::markup(&nodes, &tokens, pattern))
+            })
+            .min_by_key(|(_, indices)| indices[1])
+        {
+            return Err(TreeError::UniquePattern(name.to_string()));
+        }
         Ok(Tree {
             tokens,
             has_grammar,
@@ -847,24 +862,42 @@ impl Tree {
         parser.current = nodes.get(parser.current.take().unwrap()).unwrap().parent;
     }
 
+    // Check if the pattern is a markup tag, having the keyword `open` or `close`.
+    fn markup(nodes: &[Node], tokens: &[Token], pattern: &usize) -> bool {
+        let Some(keyword) = nodes[*pattern]
+            .children
+            .as_ref()
+            .and_then(|children| children.get(1))
+        else {
+            return false;
+        };
+        if nodes[*keyword].node_type != NodeType::Identifier {
+            return false;
+        }
+        nodes[*keyword]
+            .tokens
+            .as_ref()
+            .and_then(|node_tokens| node_tokens.first())
+            .is_some_and(|first| matches!(tokens[*first].string.as_str(), "open" | "close"))
+    }
+
     // Check if start of pattern is valid.
     fn pattern_start(
         nodes: &mut Vec<Node>,
         parser: &mut ParserStates,
         tokens: &mut [Token],
         token: &usize,
-        patterns: &mut HashMap<String, usize>,
+        patterns: &mut HashMap<String, Vec<usize>>,
     ) -> Result<(), TreeError> {
         if tokens[*token].token_type == TokenType::Identifier {
             // Multilingual pattern
             Tree::create_node(nodes, parser, NodeType::Pattern);
-            if patterns.contains_key(&tokens[*token].string) {
-                return Err(TreeError::UniquePattern(tokens[*token].string.to_string()));
-            }
-            patterns.insert(
-                tokens[*token].string.as_str().to_string(),
-                *parser.current.as_ref().unwrap(),
-            );
+
+            // Uniqueness is checked once the tree is parsed, as the markup tags of a name may be repeated.
+            patterns
+                .entry(tokens[*token].string.as_str().to_string())
+                .or_default()
+                .push(*parser.current.as_ref().unwrap());
             Tree::create_node_add_token(nodes, parser, NodeType::Identifier, token);
             Tree::move_to_container(nodes, parser); // Move back to Pattern node.
             parser.nested_states.push(parser.state);
@@ -0,0 +1,396 @@
+// This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
+
+//! Testing the asynchronous formatting of `Localiser` (using feature `async`).
+
+#![cfg(feature = "async")]
+
+use i18n_lexer::{DataProvider, IcuDataProvider};
+use i18n_localiser::{CommandRegistry, Localiser, LocaliserError};
+use i18n_provider::{
+    AsyncLocalisationProviderTrait, BlockingProvider, ComponentDetails, IdentifierDetails,
+    ProviderError, ProviderFuture, RepositoryDetails,
+};
+use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+use i18n_utility::{LanguageTag, LanguageTagRegistry, Values};
+use std::collections::HashMap;
+use std::error::Error;
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc as RefCount;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+// A minimal executor, running the future on the current thread.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: RefCount<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = pin!(future);
+    let waker = Waker::from(RefCount::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+// The response of the stand-in server, which is pending once before being ready, as a remote request would be.
+struct Response<T> {
+    result: Option<Result<T, ProviderError>>,
+    pending: bool,
+}
+
+impl<T: Unpin> Future for Response<T> {
+    type Output = Result<T, ProviderError>;
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.pending {
+            self.pending = false;
+            context.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        Poll::Ready(self.result.take().unwrap())
+    }
+}
+
+// An in-process stand-in of a database server, having the `server` component, whose default language is `en-ZA`.
+struct ServerStandIn {
+    registry: RefCount<LanguageTagRegistry>,
+    strings: HashMap<(String, String), String>, // (identifier, language tag)
+    requests: AtomicUsize,
+}
+
+impl ServerStandIn {
+    fn new(registry: &RefCount<LanguageTagRegistry>) -> Self {
+        let mut strings = HashMap::<(String, String), String>::new();
+        for (identifier, tag, string) in [
+            ("greeting", "en-ZA", "Hello {name}!"),
+            ("greeting", "de", "Hallo {name}!"),
+            ("farewell", "en-ZA", "Goodbye."),
+        ] {
+            strings.insert(
+                (identifier.to_string(), tag.to_string()),
+                string.to_string(),
+            );
+        }
+        ServerStandIn {
+            registry: RefCount::clone(registry),
+            strings,
+            requests: AtomicUsize::new(0),
+        }
+    }
+
+    fn respond<'a, T: Send + Unpin + 'a>(
+        &self,
+        result: Result<T, ProviderError>,
+    ) -> ProviderFuture<'a, T> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        Box::pin(Response {
+            result: Some(result),
+            pending: true,
+        })
+    }
+
+    fn find(
+        &self,
+        component: &str,
+        identifier: &str,
+        language_tag: &RefCount<LanguageTag>,
+    ) -> Result<Option<(String, RefCount<LanguageTag>)>, ProviderError> {
+        if component != "server" {
+            return Err(ProviderError::ComponentNotFound(component.to_string()));
+        }
+        Ok(self
+            .strings
+            .get(&(identifier.to_string(), language_tag.as_str().to_string()))
+            .map(|string| (string.to_string(), RefCount::clone(language_tag))))
+    }
+}
+
+impl AsyncLocalisationProviderTrait for ServerStandIn {
+    fn string<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+        language_tag: &'a RefCount<LanguageTag>,
+    ) -> ProviderFuture<'a, Option<(String, RefCount<LanguageTag>)>> {
+        self.respond(self.find(component, identifier, language_tag))
+    }
+
+    fn string_exact_match<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+        language_tag: &'a RefCount<LanguageTag>,
+    ) -> ProviderFuture<'a, Option<(String, RefCount<LanguageTag>)>> {
+        self.respond(self.find(component, identifier, language_tag))
+    }
+
+    fn strings<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+        language_tag: &'a RefCount<LanguageTag>,
+    ) -> ProviderFuture<'a, Vec<(String, RefCount<LanguageTag>)>> {
+        self.respond(
+            self.find(component, identifier, language_tag)
+                .map(|found| found.into_iter().collect()),
+        )
+    }
+
+    fn identifiers<'a>(&'a self, _component: &'a str) -> ProviderFuture<'a, Vec<String>> {
+        let mut identifiers = self
+            .strings
+            .keys()
+            .map(|key| key.0.to_string())
+            .collect::<Vec<String>>();
+        identifiers.sort();
+        identifiers.dedup();
+        self.respond(Ok(identifiers))
+    }
+
+    fn identifier_details<'a>(
+        &'a self,
+        _component: &'a str,
+        identifier: &'a str,
+    ) -> ProviderFuture<'a, IdentifierDetails> {
+        let languages = self
+            .strings
+            .keys()
+            .filter(|key| key.0 == identifier)
+            .map(|key| self.registry.tag(key.1.as_str()).unwrap())
+            .collect();
+        self.respond(Ok(IdentifierDetails {
+            languages,
+            default: self.registry.tag("en-ZA").unwrap(),
+        }))
+    }
+
+    fn component_details<'a>(
+        &'a self,
+        _component: &'a str,
+    ) -> ProviderFuture<'a, RefCount<ComponentDetails>> {
+        self.respond(Ok(RefCount::new(ComponentDetails {
+            languages: HashMap::new(),
+            default: self.registry.tag("en-ZA").unwrap(),
+            total_strings: self.strings.len(),
+        })))
+    }
+
+    fn repository_details(&self) -> ProviderFuture<'_, RefCount<RepositoryDetails>> {
+        self.respond(Ok(RefCount::new(RepositoryDetails {
+            languages: HashMap::new(),
+            default: Some(self.registry.tag("en-ZA").unwrap()),
+            total_strings: self.strings.len(),
+            components: vec!["server".to_string()],
+            contributors: Vec::new(),
+        })))
+    }
+}
+
+fn localiser(registry: &RefCount<LanguageTagRegistry>) -> Result<Localiser, Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let lstring_provider = LocalisationProviderSqlite3::try_new("./l10n/", registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    Ok(Localiser::try_new(
+        &icu_data_provider,
+        registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?)
+}
+
+#[test]
+fn blocking_provider() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let provider = LocalisationProviderSqlite3::try_new("./l10n/", &registry, false)?;
+    let localiser = localiser(&registry)?
+        .with_async_localisation_provider(Box::new(BlockingProvider::new(provider, 2)));
+    let tag = registry.tag("en-ZA")?;
+    let values = Values::new()
+        .with("component", "i18n_localiser")
+        .with("identifier", "string_not_found")
+        .with("language_tag", "en-ZA")
+        .with("fallback", "true");
+    let future = localiser.format_async(
+        "i18n_localiser",
+        "string_not_found",
+        &values,
+        &tag,
+        None,
+        None,
+    );
+    fn is_send<T: Send>(_future: &T) {}
+    is_send(&future);
+    let lstring = block_on(future)?;
+    assert_eq!(
+        lstring.0.as_str(),
+        "No string was found for the component ‘i18n_localiser’ with identifier ‘string_not_found’ for the \
+            language tag ‘en-ZA’. Fallback was used: True.",
+        "Check placeholder values."
+    );
+    Ok(())
+}
+
+#[test]
+fn server_stand_in() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let server = RefCount::new(ServerStandIn::new(&registry));
+
+    // The stand-in is shared with the test for counting the requests.
+    struct Shared(RefCount<ServerStandIn>);
+
+    impl AsyncLocalisationProviderTrait for Shared {
+        fn string<'a>(
+            &'a self,
+            component: &'a str,
+            identifier: &'a str,
+            language_tag: &'a RefCount<LanguageTag>,
+        ) -> ProviderFuture<'a, Option<(String, RefCount<LanguageTag>)>> {
+            self.0.string(component, identifier, language_tag)
+        }
+
+        fn string_exact_match<'a>(
+            &'a self,
+            component: &'a str,
+            identifier: &'a str,
+            language_tag: &'a RefCount<LanguageTag>,
+        ) -> ProviderFuture<'a, Option<(String, RefCount<LanguageTag>)>> {
+            self.0
+                .string_exact_match(component, identifier, language_tag)
+        }
+
+        fn strings<'a>(
+            &'a self,
+            component: &'a str,
+            identifier: &'a str,
+            language_tag: &'a RefCount<LanguageTag>,
+        ) -> ProviderFuture<'a, Vec<(String, RefCount<LanguageTag>)>> {
+            self.0.strings(component, identifier, language_tag)
+        }
+
+        fn identifiers<'a>(&'a self, component: &'a str) -> ProviderFuture<'a, Vec<String>> {
+            self.0.identifiers(component)
+        }
+
+        fn identifier_details<'a>(
+            &'a self,
+            component: &'a str,
+            identifier: &'a str,
+        ) -> ProviderFuture<'a, IdentifierDetails> {
+            self.0.identifier_details(component, identifier)
+        }
+
+        fn component_details<'a>(
+            &'a self,
+            component: &'a str,
+        ) -> ProviderFuture<'a, RefCount<ComponentDetails>> {
+            self.0.component_details(component)
+        }
+
+        fn repository_details(&self) -> ProviderFuture<'_, RefCount<RepositoryDetails>> {
+            self.0.repository_details()
+        }
+    }
+
+    let localiser = localiser(&registry)?
+        .with_async_localisation_provider(Box::new(Shared(RefCount::clone(&server))));
+    let values = Values::new().with("name", "Ada");
+    let de = registry.tag("de")?;
+    let greeting =
+        block_on(localiser.format_async("server", "greeting", &values, &de, None, None))?;
+    let requests = server.requests.load(Ordering::SeqCst);
+
+    // The cached formatter is used without awaiting the server.
+    let values = Values::new().with("name", "Grace");
+    let cached = block_on(localiser.format_async("server", "greeting", &values, &de, None, None))?;
+    let cached_requests = server.requests.load(Ordering::SeqCst);
+
+    // Falls back to the component's default language.
+    let it = registry.tag("it")?;
+    let farewell =
+        block_on(localiser.format_async("server", "farewell", &values, &it, None, None))?;
+    let not_found =
+        block_on(localiser.format_async("server", "farewell", &values, &it, Some(false), None));
+    assert_eq!(greeting.0.as_str(), "Hallo Ada!", "Check greeting.");
+    assert_eq!(cached.0.as_str(), "Hallo Grace!", "Check cached greeting.");
+    assert_eq!(requests, 1, "Check requests.");
+    assert_eq!(cached_requests, 1, "Check requests of cached string.");
+    assert_eq!(farewell.0.as_str(), "Goodbye.", "Check farewell.");
+    assert_eq!(farewell.1.as_str(), "en-ZA", "Check language of fallback.");
+    assert!(
+        matches!(not_found, Err(LocaliserError::StringNotFound(..))),
+        "Check string not found."
+    );
+    Ok(())
+}
+
+#[test]
+fn asynchronous_provider_only() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new_async(
+        &icu_data_provider,
+        &registry,
+        Box::new(ServerStandIn::new(&registry)),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?;
+    let tag = registry.tag("en-ZA")?;
+    let values = Values::new().with("name", "Ada");
+
+    // Only the strings cached by `format_async()` are available to `format()`.
+    let not_cached = localiser.format("server", "greeting", &values, &tag, None, None);
+    let greeting =
+        block_on(localiser.format_async("server", "greeting", &values, &tag, None, None))?;
+    let cached = localiser.format("server", "greeting", &values, &tag, None, None)?;
+    assert!(
+        matches!(
+            not_cached,
+            Err(LocaliserError::Provider(ProviderError::ComponentNotFound(
+                _
+            )))
+        ),
+        "Check no synchronous provider."
+    );
+    assert_eq!(greeting.0.as_str(), "Hello Ada!", "Check greeting.");
+    assert_eq!(cached.0.as_str(), "Hello Ada!", "Check cached greeting.");
+    Ok(())
+}
+
+#[test]
+fn synchronous_provider() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let localiser = localiser(&registry)?;
+    let tag = registry.tag("en-ZA")?;
+    let values = Values::new();
+    let lstring = block_on(localiser.format_async(
+        "i18n_localiser",
+        "no_grammar",
+        &values,
+        &tag,
+        None,
+        None,
+    ))?;
+    assert_eq!(
+        lstring.0.as_str(),
+        "No grammar syntax characters was found in the provided localisation string.",
+        "Check string of synchronous provider."
+    );
+    Ok(())
+}
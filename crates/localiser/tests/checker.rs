@@ -0,0 +1,101 @@
+// This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
+
+//! Testing `PatternChecker`.
+
+use i18n_lexer::{DataProvider, IcuDataProvider};
+use i18n_localiser::{CommandRegistry, Localiser, MismatchType, PatternChecker};
+use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+use i18n_utility::LanguageTagRegistry;
+
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as RefCount;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::Arc as RefCount;
+
+use std::error::Error;
+
+#[test]
+fn check_identifier() -> Result<(), Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?;
+    let checker = PatternChecker::new(&localiser);
+    let mismatches = checker.check_identifier("i18n_localiser", "multi_number_sign")?;
+    assert_eq!(mismatches.len(), 2, "Should be 2 mismatches.");
+    assert_eq!(
+        mismatches[0].mismatch,
+        MismatchType::MissingPlaceholder("position".to_string()),
+        "Should be missing position."
+    );
+    assert_eq!(
+        mismatches[1].mismatch,
+        MismatchType::UnknownPlaceholder("posizione".to_string()),
+        "Should be unknown posizione."
+    );
+    let span = mismatches[1].span.as_ref().unwrap();
+    assert_eq!(span.start_grapheme, 43, "Should start at grapheme 43.");
+    assert_eq!(span.length_graphemes, 9, "Should be 9 graphemes long.");
+    Ok(())
+}
+
+#[cfg(feature = "icu_compiled_data")]
+#[test]
+fn check_strings_branches() -> Result<(), Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?;
+    let checker = PatternChecker::new(&localiser);
+    let mismatches = checker.check_strings(
+        "application",
+        "dogs",
+        "There {dogs plural one#one_dog other#dogs} {kind select big#big other#other}. {#file_path}\
+            #{one_dog is 1 dog}{dogs are # dogs}{big large}{other small}",
+        &language_tag_registry.tag("en-ZA")?,
+        "Ci sono {dogs plural many#tanti other#molti} {kind select other#altro}.\
+            #{tanti sono # cani}{molti sono # cani}{altro piccolo}",
+        &language_tag_registry.tag("it")?,
+    )?;
+    assert_eq!(mismatches.len(), 3, "Should be 3 mismatches.");
+    assert_eq!(
+        mismatches[0].mismatch,
+        MismatchType::MissingBranches("dogs".to_string(), vec!["one".to_string()]),
+        "Should be missing one, as Italian's many is optional."
+    );
+    assert_eq!(
+        mismatches[1].mismatch,
+        MismatchType::MissingBranches("kind".to_string(), vec!["big".to_string()]),
+        "Should be missing big."
+    );
+    assert_eq!(
+        mismatches[2].mismatch,
+        MismatchType::MissingCommand("file_path".to_string()),
+        "Should be missing file_path."
+    );
+    assert!(mismatches[2].span.is_none(), "Should have no span.");
+    Ok(())
+}
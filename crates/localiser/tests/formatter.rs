@@ -0,0 +1,668 @@
+// This file is part of `i18n_localiser-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_localiser-rizzen-yazston` crate.
+
+//! Testing the formatting of pattern strings.
+
+use i18n_lexer::{DataProvider, IcuDataProvider};
+use i18n_localiser::{
+    file_path, CommandRegistry, FormattedPart, FormatterError, Localiser, LocaliserError, TreeError,
+};
+use i18n_provider::{
+    ComponentDetails, IdentifierDetails, LocalisationProviderTrait, ProviderError,
+    RepositoryDetails,
+};
+use i18n_utility::{LanguageTag, LanguageTagRegistry, Values};
+use std::collections::HashMap;
+
+#[cfg(feature = "icu_compiled_data")]
+use i18n_utility::ZonedDateTime;
+
+#[cfg(feature = "icu_compiled_data")]
+use icu_calendar::DateTime;
+
+#[cfg(feature = "icu_compiled_data")]
+use i18n_localiser::NumberPart;
+
+#[cfg(feature = "icu_compiled_data")]
+use core::fmt::{Result as FmtResult, Write};
+
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as RefCount;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::Arc as RefCount;
+
+use std::error::Error;
+
+// A provider of the `test` component, whose only language is `en-ZA`.
+struct MemoryProvider {
+    registry: RefCount<LanguageTagRegistry>,
+    strings: HashMap<String, String>,
+}
+
+impl MemoryProvider {
+    fn new(registry: &RefCount<LanguageTagRegistry>) -> Self {
+        let mut strings = HashMap::<String, String>::new();
+        for (identifier, string) in [
+            ("number", "{n decimal}"),
+            ("time", "{t date_time time#short}"),
+            ("date", "{d date_time date#long}"),
+            ("buddhist", "{d date_time date#long calendar#buddhist}"),
+            (
+                "japanese",
+                "{d date_time date#long time#short calendar#japanese}",
+            ),
+            ("ethiopian", "{d date_time date#full calendar#ethiopian}"),
+            ("unknown_calendar", "{d date_time calendar#julian}"),
+            ("zoned", "{d date_time date#long time#short}"),
+            (
+                "specific",
+                "{d date_time date#long time#short zone#specific}",
+            ),
+            ("generic", "{d date_time date#long time#short zone#generic}"),
+            ("offset", "{d date_time date#long time#short zone#offset}"),
+            (
+                "location",
+                "{d date_time date#long time#short zone#location}",
+            ),
+            ("unknown_zone", "{d date_time zone#abbreviated}"),
+            ("zoned_fields", "{d date_time fields#MMMdjmm zone#location}"),
+            ("month_day", "{d date_time fields#MMMd}"),
+            ("weekday_time", "{d date_time fields#EEEEjmm}"),
+            ("year_month", "{d date_time fields#yMMMM}"),
+            ("hour_12", "{d date_time fields#hmm}"),
+            ("invalid_fields", "{d date_time fields#MMMMMM}"),
+            ("fields_length", "{d date_time fields#MMMd date#long}"),
+            ("hour_cycle_24", "{t date_time time#short hour#24}"),
+            ("hour_cycle_23", "{t date_time time#short hour#23}"),
+            ("hour_cycle_12", "{t date_time time#short hour#12}"),
+            ("hour_cycle_11", "{t date_time time#short hour#11}"),
+            ("hour_fields", "{t date_time fields#jmm hour#12}"),
+            ("invalid_hour", "{t date_time hour#13}"),
+            ("invalid_option", "{t date_time minute#2}"),
+            (
+                "dogs",
+                "{n plural one#one other#many}#{one # dog}{many # dogs}",
+            ),
+            (
+                "markup",
+                "Press {link open target#help}{key}{link close} for {#file_path # manual}.",
+            ),
+            ("close_option", "{link close target#help}"),
+            (
+                "markup_repeated",
+                "{b open}One{b close} and {b open}two{b close}.",
+            ),
+            ("markup_placeholder", "{link open}Help{link}"),
+            ("markup_decimal", "{x open}{x decimal}"),
+            ("markup_unclosed", "{link open}Help"),
+            (
+                "markup_interleaved",
+                "{a open}{b open}Help{a close}{b close}",
+            ),
+            ("literal", "No pattern."),
+            (
+                "parts",
+                "{n decimal} of {total plural one#one other#many}#{one # file}{many # files}",
+            ),
+        ] {
+            strings.insert(identifier.to_string(), string.to_string());
+        }
+        MemoryProvider {
+            registry: RefCount::clone(registry),
+            strings,
+        }
+    }
+
+    fn default_language(&self) -> RefCount<LanguageTag> {
+        self.registry.tag("en-ZA").unwrap()
+    }
+}
+
+impl LocalisationProviderTrait for MemoryProvider {
+    fn string(
+        &self,
+        component: &str,
+        identifier: &str,
+        language_tag: &RefCount<LanguageTag>,
+    ) -> Result<Option<(String, RefCount<LanguageTag>)>, ProviderError> {
+        // Similar language tags, such as `en-ZA-u-nu-arab`, match the only language.
+        if language_tag.as_str().starts_with("en-ZA") {
+            return self.string_exact_match(component, identifier, &self.default_language());
+        }
+        Ok(None)
+    }
+
+    fn string_exact_match(
+        &self,
+        component: &str,
+        identifier: &str,
+        language_tag: &RefCount<LanguageTag>,
+    ) -> Result<Option<(String, RefCount<LanguageTag>)>, ProviderError> {
+        if component != "test" {
+            return Err(ProviderError::ComponentNotFound(component.to_string()));
+        }
+        if language_tag != &self.default_language() {
+            return Ok(None);
+        }
+        Ok(self
+            .strings
+            .get(identifier)
+            .map(|string| (string.to_string(), RefCount::clone(language_tag))))
+    }
+
+    fn strings(
+        &self,
+        component: &str,
+        identifier: &str,
+        language_tag: &RefCount<LanguageTag>,
+    ) -> Result<Vec<(String, RefCount<LanguageTag>)>, ProviderError> {
+        Ok(self
+            .string(component, identifier, language_tag)?
+            .into_iter()
+            .collect())
+    }
+
+    fn identifiers(&self, _component: &str) -> Result<Vec<String>, ProviderError> {
+        Ok(self.strings.keys().cloned().collect())
+    }
+
+    fn identifier_details(
+        &self,
+        _component: &str,
+        _identifier: &str,
+    ) -> Result<IdentifierDetails, ProviderError> {
+        Ok(IdentifierDetails {
+            languages: vec![self.default_language()],
+            default: self.default_language(),
+        })
+    }
+
+    fn component_details(
+        &self,
+        _component: &str,
+    ) -> Result<RefCount<ComponentDetails>, ProviderError> {
+        Ok(RefCount::new(ComponentDetails {
+            languages: HashMap::new(),
+            default: self.default_language(),
+            total_strings: self.strings.len(),
+        }))
+    }
+
+    fn repository_details(&self) -> Result<RefCount<RepositoryDetails>, ProviderError> {
+        Ok(RefCount::new(RepositoryDetails {
+            languages: HashMap::new(),
+            default: Some(self.default_language()),
+            total_strings: self.strings.len(),
+            components: vec!["test".to_string()],
+            contributors: Vec::new(),
+        }))
+    }
+}
+
+fn localiser(registry: &RefCount<LanguageTagRegistry>) -> Result<Localiser, Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let command_registry = RefCount::new(CommandRegistry::new());
+    Ok(Localiser::try_new(
+        &icu_data_provider,
+        registry,
+        Box::new(MemoryProvider::new(registry)),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?)
+}
+
+#[cfg(feature = "icu_compiled_data")]
+#[test]
+fn calendar_option() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let localiser = localiser(&registry)?;
+    let values = Values::new().with("d", "2024-03-05");
+    let string = localiser.format(
+        "test",
+        "buddhist",
+        &values,
+        &registry.tag("en-ZA")?,
+        None,
+        None,
+    )?;
+    assert_eq!(
+        string.0.as_str(),
+        "05 March 2567 BE",
+        "Check calendar option."
+    );
+    Ok(())
+}
+
+#[cfg(feature = "icu_compiled_data")]
+#[test]
+fn calendar_conversion() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let localiser = localiser(&registry)?;
+    let tag = registry.tag("en-ZA")?;
+    let values = Values::new().with("d", "2024-03-05T17:07");
+    let string = localiser.format("test", "japanese", &values, &tag, None, None)?;
+    assert_eq!(
+        string.0.as_str(),
+        "March 5, 6 Reiwa, 17:07",
+        "Check Japanese calendar."
+    );
+    let values = Values::new().with("d", "2024-03-05");
+    let string = localiser.format("test", "ethiopian", &values, &tag, None, None)?;
+    assert_eq!(
+        string.0.as_str(),
+        "Tuesday, 26 Yekatit 2016 ERA0",
+        "Check Ethiopian calendar."
+    );
+    match localiser.format("test", "unknown_calendar", &values, &tag, None, None) {
+        Err(LocaliserError::Formatter(FormatterError::InvalidOptionValue(value, _, _))) => {
+            assert_eq!(value.as_str(), "julian", "Check unknown calendar.")
+        }
+        _ => panic!("Must fail as ‘julian’ is not a supported calendar."),
+    }
+    Ok(())
+}
+
+#[cfg(feature = "icu_compiled_data")]
+#[test]
+fn time_zone() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let localiser = localiser(&registry)?;
+    let tag = registry.tag("en-ZA")?;
+    let date_time =
+        DateTime::try_new_iso_datetime(2024, 7, 5, 17, 7, 0).map_err(FormatterError::from)?;
+    let zoned = ZonedDateTime::new(date_time, 7200)
+        .with_time_zone("Europe/Berlin")
+        .with_daylight(true);
+    let values = Values::new().with("d", zoned);
+    for (identifier, expected) in [
+        ("zoned", "5 July 2024, 17:07 Central European Summer Time"),
+        (
+            "specific",
+            "5 July 2024, 17:07 Central European Summer Time",
+        ),
+        ("generic", "5 July 2024, 17:07 Central European Time"),
+        ("offset", "5 July 2024, 17:07 GMT+02:00"),
+        ("location", "5 July 2024, 17:07 Berlin Time"),
+        ("zoned_fields", "05 Jul, 17:07 Berlin Time"),
+    ] {
+        let string = localiser.format("test", identifier, &values, &tag, None, None)?;
+        assert_eq!(string.0.as_str(), expected, "Check zone option.");
+    }
+
+    // The offset and daylight saving time are obtained from the bundled time zone database.
+    let winter =
+        DateTime::try_new_iso_datetime(2024, 1, 5, 17, 7, 0).map_err(FormatterError::from)?;
+    for (date_time, expected) in [
+        (date_time, "5 July 2024, 17:07 Central European Summer Time"),
+        (
+            winter,
+            "5 January 2024, 17:07 Central European Standard Time",
+        ),
+    ] {
+        let values = Values::new().with(
+            "d",
+            ZonedDateTime::from_time_zone(date_time, "Europe/Berlin"),
+        );
+        let string = localiser.format("test", "zoned", &values, &tag, None, None)?;
+        assert_eq!(string.0.as_str(), expected, "Check time zone database.");
+    }
+    let values = Values::new().with(
+        "d",
+        ZonedDateTime::from_time_zone(date_time, "Europe/Atlantis"),
+    );
+    match localiser.format("test", "zoned", &values, &tag, None, None) {
+        Err(LocaliserError::Formatter(FormatterError::UnknownTimeZone(time_zone))) => {
+            assert_eq!(
+                time_zone.as_str(),
+                "Europe/Atlantis",
+                "Check unknown time zone."
+            )
+        }
+        _ => panic!("Must fail as ‘Europe/Atlantis’ is not a time zone."),
+    }
+
+    // Without the IANA time zone only the offset is known.
+    let values = Values::new().with("d", ZonedDateTime::new(date_time, -16200));
+    let string = localiser.format("test", "location", &values, &tag, None, None)?;
+    assert_eq!(
+        string.0.as_str(),
+        "5 July 2024, 17:07 GMT-04:30",
+        "Check offset fallback."
+    );
+    match localiser.format("test", "unknown_zone", &values, &tag, None, None) {
+        Err(LocaliserError::Formatter(FormatterError::InvalidOptionValue(value, _, _))) => {
+            assert_eq!(value.as_str(), "abbreviated", "Check unknown zone format.")
+        }
+        _ => panic!("Must fail as ‘abbreviated’ is not a supported zone format."),
+    }
+
+    // The zone option requires a zoned date-time.
+    let values = Values::new().with("d", date_time);
+    assert!(
+        localiser
+            .format("test", "offset", &values, &tag, None, None)
+            .is_err(),
+        "Must fail as the date-time has no zone."
+    );
+    Ok(())
+}
+
+#[cfg(feature = "icu_compiled_data")]
+#[test]
+fn fields_option() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let localiser = localiser(&registry)?;
+    let tag = registry.tag("en-ZA")?;
+    let values = Values::new().with("d", "2024-03-05T17:07");
+    for (identifier, expected) in [
+        ("month_day", "05 Mar"),
+        ("weekday_time", "Tuesday 17:07"),
+        ("year_month", "March 2024"),
+        ("hour_12", "5:07\u{202f}pm"),
+    ] {
+        let string = localiser.format("test", identifier, &values, &tag, None, None)?;
+        assert_eq!(string.0.as_str(), expected, "Check fields option.");
+    }
+
+    // Dates and times are also supported.
+    let values = Values::new().with("d", "T09:30");
+    let string = localiser.format("test", "hour_12", &values, &tag, None, None)?;
+    assert_eq!(string.0.as_str(), "9:30\u{202f}am", "Check time only.");
+    match localiser.format("test", "invalid_fields", &values, &tag, None, None) {
+        Err(LocaliserError::Formatter(FormatterError::InvalidOptionValue(value, _, _))) => {
+            assert_eq!(value.as_str(), "MMMMMM", "Check invalid skeleton.")
+        }
+        _ => panic!("Must fail as ‘MMMMMM’ is not a valid skeleton."),
+    }
+    match localiser.format("test", "fields_length", &values, &tag, None, None) {
+        Err(LocaliserError::Formatter(FormatterError::InvalidOption(option, _, _))) => {
+            assert_eq!(option.as_str(), "fields", "Check fields with lengths.")
+        }
+        _ => panic!("Must fail as ‘fields’ can't be combined with ‘date’ and ‘time’."),
+    }
+    Ok(())
+}
+
+#[cfg(feature = "icu_compiled_data")]
+#[test]
+fn hour_option() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let localiser = localiser(&registry)?;
+    let tag = registry.tag("en-ZA")?;
+    let values = Values::new().with("t", "T00:07");
+    for (identifier, expected) in [
+        ("hour_cycle_24", "24:07"),
+        ("hour_cycle_23", "00:07"),
+        ("hour_cycle_12", "12:07\u{202f}am"),
+        ("hour_cycle_11", "0:07\u{202f}am"),
+        ("hour_fields", "12:07\u{202f}am"),
+    ] {
+        let string = localiser.format("test", identifier, &values, &tag, None, None)?;
+        assert_eq!(string.0.as_str(), expected, "Check hour option.");
+    }
+    match localiser.format("test", "invalid_hour", &values, &tag, None, None) {
+        Err(LocaliserError::Formatter(FormatterError::InvalidOptionValue(value, option, _))) => {
+            assert_eq!(value.as_str(), "13", "Check invalid hour value.");
+            assert_eq!(option.as_str(), "hour", "Check hour option.");
+        }
+        _ => panic!("Must fail as ‘13’ is not a valid hour cycle."),
+    }
+    match localiser.format("test", "invalid_option", &values, &tag, None, None) {
+        Err(LocaliserError::Formatter(FormatterError::InvalidOption(option, _, _))) => {
+            assert_eq!(option.as_str(), "minute", "Check invalid option.")
+        }
+        _ => panic!("Must fail as ‘minute’ is not a valid option."),
+    }
+    Ok(())
+}
+
+#[cfg(all(feature = "icu_compiled_data", feature = "icu_extended"))]
+#[test]
+fn unicode_extensions() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let localiser = localiser(&registry)?;
+    let tag = registry.tag("en-ZA-u-ca-japanese-hc-h12-nu-arab")?;
+    let values = Values::new()
+        .with("n", 1234567u32)
+        .with("t", "T17:07")
+        .with("d", "2024-03-05");
+    let string = localiser.format("test", "number", &values, &tag, None, None)?;
+    assert_eq!(
+        string.0.as_str(),
+        "١\u{a0}٢٣٤\u{a0}٥٦٧",
+        "Check numbering system."
+    );
+    let string = localiser.format("test", "time", &values, &tag, None, None)?;
+    assert_eq!(string.0.as_str(), "٥:٠٧\u{202f}pm", "Check hour cycle.");
+    let string = localiser.format("test", "date", &values, &tag, None, None)?;
+    assert_eq!(string.0.as_str(), "March ٥, ٦ Reiwa", "Check calendar.");
+
+    // The option of the pattern takes precedence over the extension.
+    let string = localiser.format("test", "buddhist", &values, &tag, None, None)?;
+    assert_eq!(
+        string.0.as_str(),
+        "٠٥ March ٢٥٦٧ BE",
+        "Check calendar option."
+    );
+    let values = Values::new().with("n", 1u32);
+    let string = localiser.format("test", "dogs", &values, &tag, None, None)?;
+    assert_eq!(string.0.as_str(), "١ dog", "Check plural.");
+    Ok(())
+}
+
+#[test]
+fn markup() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let localiser = localiser(&registry)?;
+    localiser
+        .command_registry()
+        .insert("file_path", file_path)?;
+    let tag = registry.tag("en-ZA")?;
+    let values = Values::new().with("key", "F1");
+    let string = localiser.format("test", "markup", &values, &tag, None, None)?;
+    let parts = localiser.format_to_parts("test", "markup", &values, &tag, None, None)?;
+    let mut options = HashMap::<String, String>::new();
+    options.insert("target".to_string(), "help".to_string());
+    assert_eq!(
+        string.0.as_str(),
+        "Press F1 for manual.",
+        "Check markup has no text."
+    );
+    assert_eq!(
+        parts.0,
+        vec![
+            FormattedPart::Text("Press ".to_string()),
+            FormattedPart::MarkupOpen {
+                name: "link".to_string(),
+                options,
+            },
+            FormattedPart::Placeholder {
+                placeholder: "key".to_string(),
+                value: "F1".to_string(),
+            },
+            FormattedPart::MarkupClose {
+                name: "link".to_string(),
+            },
+            FormattedPart::Text(" for ".to_string()),
+            FormattedPart::Command {
+                command: "file_path".to_string(),
+                value: "manual".to_string(),
+            },
+            FormattedPart::Text(".".to_string()),
+        ],
+        "Check parts."
+    );
+    assert_eq!(
+        parts
+            .0
+            .iter()
+            .map(|part| part.to_string())
+            .collect::<String>(),
+        string.0.as_str(),
+        "Check concatenated parts."
+    );
+    match localiser.format("test", "close_option", &values, &tag, None, None) {
+        Err(LocaliserError::Formatter(FormatterError::InvalidOption(option, keyword, _))) => {
+            assert_eq!(option.as_str(), "target", "Check option.");
+            assert_eq!(keyword.as_str(), "close", "Check keyword.");
+        }
+        _ => panic!("Must fail as closing tags have no options."),
+    }
+    let repeated = localiser.format("test", "markup_repeated", &values, &tag, None, None)?;
+    assert_eq!(
+        repeated.0.as_str(),
+        "One and two.",
+        "Check repeated markup."
+    );
+    for identifier in ["markup_placeholder", "markup_decimal"] {
+        match localiser.format("test", identifier, &values, &tag, None, None) {
+            Err(LocaliserError::Formatter(FormatterError::Tree(TreeError::UniquePattern(
+                name,
+            )))) => {
+                assert!(
+                    matches!(name.as_str(), "link" | "x"),
+                    "Check repeated name."
+                );
+            }
+            _ => panic!("Must fail as only markup tags may repeat a name."),
+        }
+    }
+    for (identifier, expected) in [("markup_unclosed", "link"), ("markup_interleaved", "a")] {
+        match localiser.format("test", identifier, &values, &tag, None, None) {
+            Err(LocaliserError::Formatter(FormatterError::UnbalancedMarkup(name))) => {
+                assert_eq!(name.as_str(), expected, "Check unbalanced markup.");
+            }
+            _ => panic!("Must fail as the markup tags are not balanced."),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "icu_compiled_data")]
+#[test]
+fn number_parts() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let localiser = localiser(&registry)?;
+    let values = Values::new().with("n", -1234567.25).with("total", 1500);
+    let parts = localiser.format_to_parts(
+        "test",
+        "parts",
+        &values,
+        &registry.tag("en-ZA")?,
+        None,
+        None,
+    )?;
+    assert_eq!(
+        parts.0,
+        vec![
+            FormattedPart::Number {
+                placeholder: "n".to_string(),
+                parts: vec![
+                    NumberPart::Sign("-".to_string()),
+                    NumberPart::Integer("1".to_string()),
+                    NumberPart::Group("\u{a0}".to_string()),
+                    NumberPart::Integer("234".to_string()),
+                    NumberPart::Group("\u{a0}".to_string()),
+                    NumberPart::Integer("567".to_string()),
+                    NumberPart::Decimal(",".to_string()),
+                    NumberPart::Fraction("25".to_string()),
+                ],
+            },
+            FormattedPart::Text(" of ".to_string()),
+            FormattedPart::Number {
+                placeholder: "total".to_string(),
+                parts: vec![
+                    NumberPart::Integer("1".to_string()),
+                    NumberPart::Group("\u{a0}".to_string()),
+                    NumberPart::Integer("500".to_string()),
+                ],
+            },
+            FormattedPart::Text(" files".to_string()),
+        ],
+        "Check number parts."
+    );
+    Ok(())
+}
+
+// A writer that fails after accepting `capacity` bytes.
+#[cfg(feature = "icu_compiled_data")]
+struct LimitedWriter {
+    string: String,
+    capacity: usize,
+}
+
+#[cfg(feature = "icu_compiled_data")]
+impl Write for LimitedWriter {
+    fn write_str(&mut self, string: &str) -> FmtResult {
+        if self.string.len() + string.len() > self.capacity {
+            return Err(core::fmt::Error);
+        }
+        self.string.push_str(string);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "icu_compiled_data")]
+#[test]
+fn format_into() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let localiser = localiser(&registry)?;
+    let tag = registry.tag("en-ZA")?;
+    let values = Values::new().with("n", 1500).with("total", 1);
+    let mut buffer = String::new();
+    for identifier in ["parts", "literal"] {
+        buffer.clear();
+        let string = localiser.format("test", identifier, &values, &tag, None, None)?;
+        let language_tag =
+            localiser.format_into(&mut buffer, "test", identifier, &values, &tag, None, None)?;
+        assert_eq!(buffer.as_str(), string.0.as_str(), "Check written string.");
+        assert_eq!(language_tag, string.1, "Check language tag.");
+    }
+    let mut writer = LimitedWriter {
+        string: String::new(),
+        capacity: 4,
+    };
+    match localiser.format_into(&mut writer, "test", "parts", &values, &tag, None, None) {
+        Err(LocaliserError::Formatter(FormatterError::Write(_))) => {}
+        _ => panic!("Must fail as the writer is full."),
+    }
+    Ok(())
+}
+
+#[cfg(feature = "icu_compiled_data")]
+#[test]
+fn message() -> Result<(), Box<dyn Error>> {
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let localiser = localiser(&registry)?;
+    let tag = registry.tag("en-ZA")?;
+    let values = Values::new().with("n", 2);
+    let message = localiser.message("test", "dogs", &values, &tag);
+    assert_eq!(
+        format!("<{}>", message),
+        "<2 dogs>",
+        "Check displayed message."
+    );
+    let mut buffer = String::new();
+    let language_tag = message.with_caching(Some(false)).write_to(&mut buffer)?;
+    assert_eq!(buffer.as_str(), "2 dogs", "Check written message.");
+    assert_eq!(language_tag.as_str(), "en-ZA", "Check language tag.");
+
+    // Formatting errors are only reported as `core::fmt::Error` by `Display`.
+    let missing = Values::new();
+    let message = localiser.message("test", "dogs", &missing, &tag);
+    assert!(
+        write!(buffer, "{}", message).is_err(),
+        "Must fail as the placeholder value is missing."
+    );
+    match message.write_to(&mut buffer) {
+        Err(LocaliserError::Formatter(FormatterError::PlaceholderValue(_, placeholder))) => {
+            assert_eq!(placeholder.as_str(), "n", "Check placeholder.");
+        }
+        _ => panic!("Must fail as the placeholder value is missing."),
+    }
+    Ok(())
+}
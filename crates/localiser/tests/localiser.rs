@@ -4,9 +4,11 @@
 //! Testing `Localiser`.
 
 use i18n_lexer::{DataProvider, IcuDataProvider};
-use i18n_localiser::{CommandRegistry, Localiser, LocaliserError};
+use i18n_localiser::{
+    CacheStatistics, CommandRegistry, Localiser, LocaliserError, PseudoLocalisation,
+};
 use i18n_provider_sqlite3::LocalisationProviderSqlite3;
-use i18n_utility::{LanguageTagRegistry, LocalisationData, PlaceholderValue};
+use i18n_utility::{LanguageTagRegistry, LocalisationData, PlaceholderValue, TaggedString, Values};
 use std::collections::HashMap;
 
 #[cfg(not(feature = "sync"))]
@@ -16,7 +18,9 @@ use std::rc::Rc as RefCount;
 #[cfg(target_has_atomic = "ptr")]
 use std::sync::Arc as RefCount;
 
+use std::env::temp_dir;
 use std::error::Error;
+use std::fs::{copy, create_dir_all, remove_dir_all, remove_file};
 
 #[test]
 fn format() -> Result<(), Box<dyn Error>> {
@@ -103,6 +107,54 @@ fn format_with_defaults() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn format_values_builder() -> Result<(), Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?;
+    let values = Values::new()
+        .with("component", "i18n_localiser")
+        .with("identifier", "cache_entry".to_string());
+    let lstring = localiser.format_with_defaults("i18n_localiser", "cache_entry", &values)?;
+    assert_eq!(
+        lstring.0.as_str(),
+        "Unable to get the string for the component ‘i18n_localiser’ with the identifier ‘cache_entry’ as the cache \
+        entry requires values for formatting.",
+        "Check placeholder values."
+    );
+    let values = Values::new()
+        .with("component", "i18n_localiser")
+        .with("identifier", "string_not_found")
+        .with("language_tag", "en-ZA")
+        .with("fallback", String::from("false"));
+    let lstring = localiser.format(
+        "i18n_localiser",
+        "string_not_found",
+        &values,
+        &language_tag_registry.tag("en-ZA")?,
+        None,
+        None,
+    )?;
+    assert_eq!(
+        lstring.0.as_str(),
+        "No string was found for the component ‘i18n_localiser’ with identifier ‘string_not_found’ for the language \
+        tag ‘en-ZA’. Fallback was used: False.",
+        "Check placeholder values."
+    );
+    Ok(())
+}
+
 #[test]
 fn literal() -> Result<(), Box<dyn Error>> {
     let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
@@ -325,3 +377,385 @@ fn format_error_with_defaults() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn negotiate() -> Result<(), Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?;
+    let preferences = vec![
+        (language_tag_registry.tag("fr")?, 1.0),
+        (language_tag_registry.tag("en-GB")?, 0.8),
+        (language_tag_registry.tag("it")?, 0.5),
+    ];
+    let language = localiser.negotiate("i18n_localiser", &preferences)?;
+    assert_eq!(language.as_str(), "en-ZA", "Check region distance.");
+    let preferences = vec![
+        (language_tag_registry.tag("en-GB")?, 0.3),
+        (language_tag_registry.tag("it")?, 0.9),
+    ];
+    let language = localiser.negotiate("i18n_localiser", &preferences)?;
+    assert_eq!(language.as_str(), "it", "Check weights.");
+    let preferences = vec![
+        (language_tag_registry.tag("sr-Latn")?, 1.0),
+        (language_tag_registry.tag("it")?, 0.0),
+    ];
+    let language = localiser.negotiate("i18n_localiser", &preferences)?;
+    assert_eq!(language.as_str(), "en-ZA", "Check component default.");
+    let languages =
+        localiser.negotiate_components(&[(language_tag_registry.tag("it-IT")?, 1.0)])?;
+    assert_eq!(
+        languages["i18n_localiser"].as_str(),
+        "it",
+        "Check components."
+    );
+    Ok(())
+}
+
+#[test]
+fn fallback_chain() -> Result<(), Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?;
+    let romansh = language_tag_registry.tag("rm-CH")?;
+    let chain = vec![
+        language_tag_registry.tag("de")?,
+        language_tag_registry.tag("it-CH")?,
+    ];
+    localiser.set_fallback_chain(None, &romansh, &chain);
+    let lstring = localiser.literal("i18n_localiser", "no_grammar", &romansh, None, None)?;
+    assert_eq!(lstring.1.as_str(), "it", "Check chain of the localiser.");
+    let lstring = localiser.literal("i18n_localiser", "error_format", &romansh, None, None)?;
+    assert_eq!(lstring.1.as_str(), "en-ZA", "Check component default.");
+    let result = localiser.literal("i18n_localiser", "no_grammar", &romansh, Some(false), None);
+    assert!(
+        matches!(result, Err(LocaliserError::StringNotFound(_, _, _, false))),
+        "Check chain is not used without fallback."
+    );
+    localiser.set_fallback_chain(
+        Some("i18n_localiser"),
+        &romansh,
+        &[language_tag_registry.tag("fr")?],
+    );
+    assert_eq!(
+        localiser
+            .fallback_chain("i18n_localiser", &romansh)
+            .map(|chain| chain.len()),
+        Some(1),
+        "Check component chain."
+    );
+    let lstring = localiser.literal("i18n_localiser", "no_grammar", &romansh, None, None)?;
+    assert_eq!(
+        lstring.1.as_str(),
+        "en-ZA",
+        "Check component chain override."
+    );
+    localiser.set_fallback_chain(Some("i18n_localiser"), &romansh, &[]);
+    let lstring = localiser.literal("i18n_localiser", "no_grammar", &romansh, None, None)?;
+    assert_eq!(lstring.1.as_str(), "it", "Check component chain removed.");
+    let sursilvan = language_tag_registry.tag("rm-CH-sursilv")?;
+    let lstring = localiser.literal("i18n_localiser", "no_grammar", &sursilvan, None, None)?;
+    assert_eq!(
+        lstring.1.as_str(),
+        "it",
+        "Check chain of the less specific language tag."
+    );
+    localiser.set_fallback_chain(
+        Some("i18n_localiser"),
+        &language_tag_registry.tag("rm")?,
+        &[language_tag_registry.tag("fr")?],
+    );
+    assert_eq!(
+        localiser
+            .fallback_chain("i18n_localiser", &sursilvan)
+            .map(|chain| chain[0].as_str().to_string()),
+        Some("de".to_string()),
+        "Check the more specific chain is preferred."
+    );
+    Ok(())
+}
+
+#[test]
+fn cache() -> Result<(), Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?;
+    localiser.set_cache_capacity(Some(2));
+    let values = Values::new()
+        .with("component", "i18n_localiser")
+        .with("identifier", "string_not_found")
+        .with("language_tag", "en-ZA")
+        .with("fallback", "true");
+    localiser.format_with_defaults("i18n_localiser", "string_not_found", &values)?;
+    localiser.literal_with_defaults("i18n_localiser", "no_grammar")?;
+
+    // The cached `Formatter` is reused with other values.
+    let values = values.with("language_tag", "en-GB");
+    let lstring = localiser.format_with_defaults("i18n_localiser", "string_not_found", &values)?;
+    assert_eq!(
+        lstring.0.as_str(),
+        "No string was found for the component ‘i18n_localiser’ with identifier ‘string_not_found’ for the \
+            language tag ‘en-GB’. Fallback was used: True.",
+        "Check cached formatter."
+    );
+
+    // The least recently used `no_grammar` is evicted.
+    localiser.literal_with_defaults("i18n_localiser", "cache_entry")?;
+    localiser.literal_with_defaults("i18n_localiser", "no_grammar")?;
+    let statistics = localiser.cache_statistics();
+    assert_eq!(statistics.hits, 1, "Check hits.");
+    assert_eq!(statistics.misses, 4, "Check misses.");
+    assert_eq!(statistics.evictions, 2, "Check evictions.");
+    assert_eq!(statistics.entries, 2, "Check entries.");
+    assert_eq!(statistics.capacity, Some(2), "Check capacity.");
+
+    assert_eq!(localiser.evict("i18n_localiser"), 2, "Check evict.");
+    assert_eq!(localiser.evict("i18n_localiser"), 0, "Check evicted.");
+    localiser.literal_with_defaults("i18n_localiser", "no_grammar")?;
+    localiser.clear_cache();
+    localiser.reset_cache_statistics();
+    assert_eq!(
+        localiser.cache_statistics(),
+        CacheStatistics {
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+            entries: 0,
+            capacity: Some(2),
+        },
+        "Check cleared cache."
+    );
+    Ok(())
+}
+
+#[test]
+fn reload() -> Result<(), Box<dyn Error>> {
+    let directory = temp_dir().join(format!("i18n_localiser_{}", std::process::id()));
+    create_dir_all(&directory)?;
+    copy(
+        "./l10n/i18n_localiser.sqlite3",
+        directory.join("i18n_localiser.sqlite3"),
+    )?;
+    copy(
+        "../provider/sqlite3/l10n/i18n_provider_sqlite3.sqlite3",
+        directory.join("i18n_provider_sqlite3.sqlite3"),
+    )?;
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new(directory.as_path(), &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?;
+    localiser.literal_with_defaults("i18n_localiser", "no_grammar")?;
+    localiser.literal_with_defaults("i18n_provider_sqlite3", "path_conversion")?;
+    let unchanged = localiser.reload()?;
+
+    // Only the cached strings of the removed component are evicted.
+    remove_file(directory.join("i18n_provider_sqlite3.sqlite3"))?;
+    let changed = localiser.reload()?;
+    let entries = localiser.cache_statistics().entries;
+    let removed = localiser.literal_with_defaults("i18n_provider_sqlite3", "path_conversion");
+    remove_dir_all(&directory)?;
+    assert!(unchanged.is_empty(), "Should be no changes.");
+    assert_eq!(
+        changed,
+        vec!["i18n_provider_sqlite3".to_string()],
+        "Check changed components."
+    );
+    assert_eq!(entries, 1, "Check entries.");
+    assert!(removed.is_err(), "Component should be removed.");
+    Ok(())
+}
+
+#[test]
+fn pseudo_localisation() -> Result<(), Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?;
+    let tag = language_tag_registry.tag("en-XA")?;
+    let values = Values::new().with("node_type", "Root");
+
+    // Without pseudo-localisation, `en-XA` is just another language.
+    let disabled = localiser.format("i18n_localiser", "invalid_node", &values, &tag, None, None)?;
+    localiser.set_pseudo_localisation(Some(PseudoLocalisation::new(50)));
+    let accented = localiser.format(
+        "i18n_localiser",
+        "invalid_node",
+        &values,
+        &tag,
+        Some(false),
+        None,
+    )?;
+    let literal = localiser.literal("i18n_localiser", "no_grammar", &tag, None, None)?;
+    assert_eq!(
+        disabled.0.as_str(),
+        "Invalid child node found in the ‘Root’ node.",
+        "Check string of default language."
+    );
+    assert_eq!(disabled.1.as_str(), "en-ZA", "Check default language.");
+    assert_eq!(
+        accented.0.as_str(),
+        "[Îñṽåļîð çĥîļð ñöðé ƒöûñð îñ ţĥé ‘~~~~~~~~~~~~~~Root’ ñöðé.~~~]",
+        "Check accented string."
+    );
+    assert_eq!(accented.1.as_str(), "en-XA", "Check pseudo-locale.");
+    assert_eq!(
+        literal.0.as_str(),
+        "[Ñö ĝŕåɱɱåŕ šýñţåẋ çĥåŕåçţéŕš ŵåš ƒöûñð îñ ţĥé þŕöṽîðéð ļöçåļîšåţîöñ šţŕîñĝ.~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~]",
+        "Check accented literal string."
+    );
+    assert_eq!(
+        literal.1.as_str(),
+        "en-XA",
+        "Check pseudo-locale of literal."
+    );
+    Ok(())
+}
+
+#[test]
+fn pseudo_localisation_bidi() -> Result<(), Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?;
+    localiser.set_pseudo_localisation(Some(PseudoLocalisation::new(0)));
+    let tag = language_tag_registry.tag("ar-XB")?;
+    let values = Values::new()
+        .with("type", "TreeError")
+        .with("message", "Invalid");
+    let lstring = localiser.format("i18n_localiser", "error_format", &values, &tag, None, None)?;
+    assert_eq!(
+        lstring.0.as_str(),
+        "[TreeError\u{202E}:\u{202C} \u{202E}‘\u{202C}Invalid\u{202E}’.\u{202C}]",
+        "Check right-to-left words, with the placeholder values intact."
+    );
+    assert_eq!(lstring.1.as_str(), "ar-XB", "Check pseudo-locale.");
+    Ok(())
+}
+
+#[test]
+fn bidi_isolation() -> Result<(), Box<dyn Error>> {
+    let icu_data_provider = RefCount::new(IcuDataProvider::try_new(DataProvider::Internal)?);
+    let language_tag_registry = RefCount::new(LanguageTagRegistry::new());
+    let lstring_provider =
+        LocalisationProviderSqlite3::try_new("./l10n/", &language_tag_registry, false)?;
+    let command_registry = RefCount::new(CommandRegistry::new());
+    let localiser = Localiser::try_new(
+        &icu_data_provider,
+        &language_tag_registry,
+        Box::new(lstring_provider),
+        &command_registry,
+        true,
+        true,
+        "en-ZA",
+    )?;
+    let english = language_tag_registry.tag("en")?;
+    let arabic = language_tag_registry.tag("ar")?;
+    let tagged = Values::new()
+        .with("type", TaggedString::new("TreeError", &english))
+        .with("message", TaggedString::new("خطأ", &arabic));
+    let untagged = Values::new()
+        .with("type", "TreeError")
+        .with("message", "Invalid");
+    let disabled = localiser.format_with_defaults("i18n_localiser", "error_format", &tagged)?;
+    localiser.set_bidi_isolation(true);
+    let left_to_right =
+        localiser.format_with_defaults("i18n_localiser", "error_format", &tagged)?;
+    let first_strong =
+        localiser.format_with_defaults("i18n_localiser", "error_format", &untagged)?;
+
+    // The right-to-left pattern of the pseudo-locale `ar-XB`.
+    localiser.set_pseudo_localisation(Some(PseudoLocalisation::new(0)));
+    let right_to_left = localiser.format(
+        "i18n_localiser",
+        "error_format",
+        &tagged,
+        &language_tag_registry.tag("ar-XB")?,
+        None,
+        None,
+    )?;
+    assert!(localiser.bidi_isolation(), "Check isolation is enabled.");
+    assert_eq!(
+        disabled.0.as_str(),
+        "TreeError: ‘خطأ’.",
+        "Check values are not wrapped."
+    );
+    assert_eq!(
+        left_to_right.0.as_str(),
+        "TreeError: ‘\u{2067}خطأ\u{2069}’.",
+        "Check only the right-to-left value is wrapped."
+    );
+    assert_eq!(
+        first_strong.0.as_str(),
+        "\u{2068}TreeError\u{2069}: ‘\u{2068}Invalid\u{2069}’.",
+        "Check values of unknown direction."
+    );
+    assert_eq!(
+        right_to_left.0.as_str(),
+        "[\u{2066}TreeError\u{2069}\u{202E}:\u{202C} \u{202E}‘\u{202C}خطأ\u{202E}’.\u{202C}]",
+        "Check only the left-to-right value is wrapped."
+    );
+    Ok(())
+}
@@ -0,0 +1,102 @@
+// This file is part of `i18n_macros-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_macros-rizzen-yazston` crate.
+
+//! Welcome to the **`i18n_macros`** crate of the *Internationalisation* (i18n) project.
+//!
+//! This crate consists of one procedural macro:
+//!
+//! * [`Localisation`]: Derive macro for implementing `LocalisationTrait`, `LocalisationErrorTrait` and `Display` for
+//!   error enums.
+//!
+//! The generated code refers to the `i18n_utility` crate, thus the crate using the macros must have the
+//! `i18n_utility-rizzen-yazston` crate as a dependency.
+//!
+//! # Macros
+//!
+//! ## `Localisation`: Derive macro for error enums.
+//!
+//! The derive macro generates the same localisation data as the hand written error enums of the project: each variant
+//! is formatted using one of the `error_format_enum` or `error_format_enum_embedded` patterns of the `i18n_localiser`
+//! component, where the `type` placeholder is the enum name and `variant` placeholder is the variant name.
+//!
+//! The enum attribute `#[localisation( component = "..." )]` provides the default component for the variants. Each
+//! variant requires exactly one of the following attributes:
+//!
+//! * `#[localisation( identifier = "...", display = "..." )]`: The message is the localisation string of the
+//!   component (optionally overridden with `component = "..."`) and identifier. The `display` string is the default
+//!   language message used for `Display`, where named arguments (such as `{path}` or `{node-type}`) refer to the
+//!   placeholders,
+//!
+//! * `#[localisation( embedded )]`: The first field is an error without localisation, and is embedded as a string
+//!   using the error's `Display`,
+//!
+//! * `#[localisation( nested )]`: The first field implements `LocalisationTrait`, and its localisation data is used
+//!   as the message.
+//!
+//! The `Display` of `embedded` and `nested` variants is `Type::Variant: [error].`, unless a `display` string is
+//! provided.
+//!
+//! Fields are mapped to the message placeholders using `#[localisation( placeholder = "name" )]`, with an optional
+//! `convert = "..."` to select the conversion into a `PlaceholderValue`:
+//!
+//! * `string` (default): Uses `to_string()` for a `PlaceholderValue::String`,
+//!
+//! * `path`: Uses `display().to_string()` for a `PlaceholderValue::String`,
+//!
+//! * `unsigned`: Casts to `u128` for a `PlaceholderValue::Unsigned`,
+//!
+//! * `integer`: Casts to `i128` for a `PlaceholderValue::Integer`,
+//!
+//! * `localisation`: Uses `localisation_data()` for a `PlaceholderValue::LocalisationData`.
+//!
+//! Variant `#[cfg( ... )]` attributes are applied to the generated match arms.
+//!
+//! ### Examples
+//!
+//! ```
+//! use i18n_macros::Localisation;
+//! use i18n_utility::{LocalisationTrait, PlaceholderValue};
+//! use std::error::Error;
+//! use std::path::PathBuf;
+//!
+//! #[derive( Debug, Localisation )]
+//! #[localisation( component = "i18n_provider_sqlite3" )]
+//! pub enum ExampleError {
+//!     #[localisation( identifier = "path_not_exist", display = "Provided path ‘{path}’ does not exist." )]
+//!     NotExists( #[localisation( placeholder = "path", convert = "path" )] PathBuf ),
+//!     #[localisation( embedded )]
+//!     Io( std::io::Error ),
+//! }
+//!
+//! impl Error for ExampleError {}
+//!
+//! let error = ExampleError::NotExists( PathBuf::from( "l10n" ) );
+//! assert_eq!(
+//!     error.to_string(),
+//!     "ExampleError::NotExists: Provided path ‘l10n’ does not exist.",
+//!     "Check display."
+//! );
+//! let data = error.localisation_data();
+//! assert_eq!( data.identifier, "error_format_enum", "Check identifier." );
+//! let Some( PlaceholderValue::LocalisationData( message ) ) = data.values.as_ref().unwrap().get( "message" ) else {
+//!     panic!( "Expected message." );
+//! };
+//! assert_eq!( message.identifier, "path_not_exist", "Check message identifier." );
+//! ```
+
+mod localisation;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives `LocalisationTrait`, `LocalisationErrorTrait` and `Display` for an error enum.
+///
+/// See the crate documentation for the attributes.
+#[proc_macro_derive(Localisation, attributes(localisation))]
+pub fn derive_localisation(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match localisation::derive(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
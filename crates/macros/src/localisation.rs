@@ -0,0 +1,452 @@
+// This file is part of `i18n_macros-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_macros-rizzen-yazston` crate.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, LitStr, Result, Variant};
+
+// The conversion of a field's value into a `PlaceholderValue`.
+enum Convert {
+    String,
+    Path,
+    Unsigned,
+    Integer,
+    Localisation,
+}
+
+struct Placeholder {
+    name: String,
+    binding: Ident,
+    convert: Convert,
+}
+
+// The kind of localisation of a variant.
+enum Kind {
+    Message(LitStr, LitStr), // component, identifier
+    Embedded,
+    Nested,
+}
+
+/// Generates the `LocalisationTrait`, `LocalisationErrorTrait` and `Display` implementations for an enum.
+pub(crate) fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(
+            Span::call_site(),
+            "`Localisation` can only be derived for enums.",
+        ));
+    };
+    let mut component = None::<LitStr>;
+    for attribute in localisation_attributes(&input.attrs) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("component") {
+                component = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("Unsupported `localisation` attribute for the enum."))
+            }
+        })?;
+    }
+    let name = &input.ident;
+    let type_name = name.to_string();
+    let mut localisation_arms = Vec::<TokenStream>::new();
+    let mut display_arms = Vec::<TokenStream>::new();
+    for variant in data.variants.iter() {
+        let (kind, display) = variant_attributes(variant, component.as_ref())?;
+        let (pattern, placeholders) = variant_pattern(name, variant)?;
+        let variant_name = variant.ident.to_string();
+        let cfgs = variant
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("cfg"))
+            .collect::<Vec<&Attribute>>();
+        let (localisation, message) = match kind {
+            Kind::Message(component, identifier) => {
+                let inserts = placeholders.iter().map(|placeholder| {
+                    let key = placeholder.name.as_str();
+                    let value = placeholder_value(placeholder);
+                    quote! { message_values.insert(#key.to_string(), #value); }
+                });
+                let values = match placeholders.is_empty() {
+                    true => quote! { None },
+                    false => quote! {
+                        {
+                            let mut message_values =
+                                ::std::collections::HashMap::<String, ::i18n_utility::PlaceholderValue>::new();
+                            #( #inserts )*
+                            Some(message_values)
+                        }
+                    },
+                };
+                let localisation = quote! {
+                    let message = ::i18n_utility::LocalisationData {
+                        component: #component.to_string(),
+                        identifier: #identifier.to_string(),
+                        values: #values,
+                    };
+                    let mut values =
+                        ::std::collections::HashMap::<String, ::i18n_utility::PlaceholderValue>::new();
+                    values.insert("type".to_string(), type_string);
+                    values.insert(
+                        "variant".to_string(),
+                        ::i18n_utility::PlaceholderValue::String(#variant_name.to_string()),
+                    );
+                    values.insert(
+                        "message".to_string(),
+                        ::i18n_utility::PlaceholderValue::LocalisationData(message),
+                    );
+                    ::i18n_utility::LocalisationData {
+                        component: "i18n_localiser".to_string(),
+                        identifier: "error_format_enum".to_string(),
+                        values: Some(values),
+                    }
+                };
+                let Some(display) = display else {
+                    return Err(Error::new_spanned(
+                        &variant.ident,
+                        "The `display` attribute is required for variants with an `identifier`.",
+                    ));
+                };
+                (
+                    localisation,
+                    display_message(&type_name, variant, &display, &placeholders)?,
+                )
+            }
+            Kind::Embedded | Kind::Nested => {
+                let Some(binding) = first_binding(variant) else {
+                    return Err(Error::new_spanned(
+                        &variant.ident,
+                        "The `embedded` and `nested` variants require a field containing the error.",
+                    ));
+                };
+                let localisation = match kind {
+                    Kind::Embedded => quote! {
+                        let mut values =
+                            ::std::collections::HashMap::<String, ::i18n_utility::PlaceholderValue>::new();
+                        values.insert("type".to_string(), type_string);
+                        values.insert(
+                            "variant".to_string(),
+                            ::i18n_utility::PlaceholderValue::String(#variant_name.to_string()),
+                        );
+                        values.insert(
+                            "error".to_string(),
+                            ::i18n_utility::PlaceholderValue::String(#binding.to_string()),
+                        );
+                        ::i18n_utility::LocalisationData {
+                            component: "i18n_localiser".to_string(),
+                            identifier: "error_format_enum_embedded".to_string(),
+                            values: Some(values),
+                        }
+                    },
+                    _ => quote! {
+                        let mut values =
+                            ::std::collections::HashMap::<String, ::i18n_utility::PlaceholderValue>::new();
+                        values.insert("type".to_string(), type_string);
+                        values.insert(
+                            "variant".to_string(),
+                            ::i18n_utility::PlaceholderValue::String(#variant_name.to_string()),
+                        );
+                        values.insert(
+                            "message".to_string(),
+                            ::i18n_utility::PlaceholderValue::LocalisationData(
+                                ::i18n_utility::LocalisationTrait::localisation_data(#binding),
+                            ),
+                        );
+                        ::i18n_utility::LocalisationData {
+                            component: "i18n_localiser".to_string(),
+                            identifier: "error_format_enum".to_string(),
+                            values: Some(values),
+                        }
+                    },
+                };
+                let message = match display {
+                    Some(display) => display_message(&type_name, variant, &display, &placeholders)?,
+                    None => {
+                        let string = LitStr::new(
+                            format!("{}::{}: [{{}}].", type_name, variant_name).as_str(),
+                            Span::call_site(),
+                        );
+                        quote! { write!(formatter, #string, #binding) }
+                    }
+                };
+                (localisation, message)
+            }
+        };
+        localisation_arms.push(quote! {
+            #( #cfgs )*
+            #pattern => { #localisation }
+        });
+        display_arms.push(quote! {
+            #( #cfgs )*
+            #pattern => #message,
+        });
+    }
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::i18n_utility::LocalisationErrorTrait for #name #type_generics #where_clause {}
+
+        impl #impl_generics ::i18n_utility::LocalisationTrait for #name #type_generics #where_clause {
+            fn localisation_data(&self) -> ::i18n_utility::LocalisationData {
+                let type_string = ::i18n_utility::PlaceholderValue::String(#type_name.to_string());
+                match *self {
+                    #( #localisation_arms )*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #type_generics #where_clause {
+            fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                match *self {
+                    #( #display_arms )*
+                }
+            }
+        }
+    })
+}
+
+// Internal functions.
+
+fn localisation_attributes(attributes: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("localisation"))
+}
+
+fn variant_attributes(
+    variant: &Variant,
+    default_component: Option<&LitStr>,
+) -> Result<(Kind, Option<LitStr>)> {
+    let mut component = default_component.cloned();
+    let mut identifier = None::<LitStr>;
+    let mut display = None::<LitStr>;
+    let mut embedded = false;
+    let mut nested = false;
+    for attribute in localisation_attributes(&variant.attrs) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("component") {
+                component = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("identifier") {
+                identifier = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("display") {
+                display = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("embedded") {
+                embedded = true;
+            } else if meta.path.is_ident("nested") {
+                nested = true;
+            } else {
+                return Err(meta.error("Unsupported `localisation` attribute for the variant."));
+            }
+            Ok(())
+        })?;
+    }
+    let kind = match (identifier, embedded, nested) {
+        (Some(identifier), false, false) => {
+            let Some(component) = component else {
+                return Err(Error::new_spanned(
+                    &variant.ident,
+                    "No `component` attribute for the variant or the enum.",
+                ));
+            };
+            Kind::Message(component, identifier)
+        }
+        (None, true, false) => Kind::Embedded,
+        (None, false, true) => Kind::Nested,
+        _ => {
+            return Err(Error::new_spanned(
+                &variant.ident,
+                "The variant requires exactly one of: `identifier`, `embedded` or `nested`.",
+            ))
+        }
+    };
+    Ok((kind, display))
+}
+
+// Builds the match pattern binding every field by reference, and collects the placeholder fields.
+fn variant_pattern(name: &Ident, variant: &Variant) -> Result<(TokenStream, Vec<Placeholder>)> {
+    let variant_ident = &variant.ident;
+    let mut placeholders = Vec::<Placeholder>::new();
+    let pattern = match &variant.fields {
+        Fields::Unit => quote! { #name::#variant_ident },
+        Fields::Unnamed(fields) => {
+            let mut bindings = Vec::<Ident>::new();
+            for (index, field) in fields.unnamed.iter().enumerate() {
+                let binding = format_ident!("_field_{}", index);
+                if let Some(placeholder) = field_placeholder(&field.attrs, &binding)? {
+                    placeholders.push(placeholder);
+                }
+                bindings.push(binding);
+            }
+            quote! { #name::#variant_ident( #( ref #bindings ),* ) }
+        }
+        Fields::Named(fields) => {
+            let mut members = Vec::<TokenStream>::new();
+            for field in fields.named.iter() {
+                let Some(ident) = field.ident.as_ref() else {
+                    continue;
+                };
+                let binding = format_ident!("_field_{}", ident);
+                if let Some(placeholder) = field_placeholder(&field.attrs, &binding)? {
+                    placeholders.push(placeholder);
+                }
+                members.push(quote! { #ident: ref #binding });
+            }
+            quote! { #name::#variant_ident { #( #members ),* } }
+        }
+    };
+    Ok((pattern, placeholders))
+}
+
+fn first_binding(variant: &Variant) -> Option<Ident> {
+    match &variant.fields {
+        Fields::Unit => None,
+        Fields::Unnamed(fields) => fields.unnamed.first().map(|_| format_ident!("_field_0")),
+        Fields::Named(fields) => fields
+            .named
+            .first()
+            .and_then(|field| field.ident.as_ref())
+            .map(|ident| format_ident!("_field_{}", ident)),
+    }
+}
+
+fn field_placeholder(attributes: &[Attribute], binding: &Ident) -> Result<Option<Placeholder>> {
+    let mut name = None::<LitStr>;
+    let mut convert = Convert::String;
+    for attribute in localisation_attributes(attributes) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("placeholder") {
+                name = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("convert") {
+                let value: LitStr = meta.value()?.parse()?;
+                convert = match value.value().as_str() {
+                    "string" => Convert::String,
+                    "path" => Convert::Path,
+                    "unsigned" => Convert::Unsigned,
+                    "integer" => Convert::Integer,
+                    "localisation" => Convert::Localisation,
+                    _ => {
+                        return Err(Error::new_spanned(
+                            value,
+                            "Expected one of: `string`, `path`, `unsigned`, `integer` or `localisation`.",
+                        ))
+                    }
+                };
+            } else {
+                return Err(meta.error("Unsupported `localisation` attribute for the field."));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(name.map(|name| Placeholder {
+        name: name.value(),
+        binding: binding.clone(),
+        convert,
+    }))
+}
+
+fn placeholder_value(placeholder: &Placeholder) -> TokenStream {
+    let binding = &placeholder.binding;
+    match placeholder.convert {
+        Convert::String => {
+            quote! { ::i18n_utility::PlaceholderValue::String(#binding.to_string()) }
+        }
+        Convert::Path => {
+            quote! { ::i18n_utility::PlaceholderValue::String(#binding.display().to_string()) }
+        }
+        Convert::Unsigned => {
+            quote! { ::i18n_utility::PlaceholderValue::Unsigned(*#binding as u128) }
+        }
+        Convert::Integer => quote! { ::i18n_utility::PlaceholderValue::Integer(*#binding as i128) },
+        Convert::Localisation => quote! {
+            ::i18n_utility::PlaceholderValue::LocalisationData(
+                ::i18n_utility::LocalisationTrait::localisation_data(#binding),
+            )
+        },
+    }
+}
+
+// The `display` string is prefixed with `Type::Variant: `, and the `{name}` arguments refer to the placeholders. The
+// arguments are passed by position, as placeholder names need not be Rust identifiers, such as `node-type` or `type`.
+fn display_message(
+    type_name: &str,
+    variant: &Variant,
+    display: &LitStr,
+    placeholders: &[Placeholder],
+) -> Result<TokenStream> {
+    let (format, names) = positional_format(display.value().as_str());
+    let string = LitStr::new(
+        format!("{}::{}: {}", type_name, variant.ident, format).as_str(),
+        display.span(),
+    );
+    let mut arguments = Vec::<TokenStream>::new();
+    for name in names {
+        if name.is_empty() {
+            return Err(Error::new_spanned(
+                display,
+                "The `display` arguments must be named using the placeholders of the variant.",
+            ));
+        }
+        let Some(placeholder) = placeholders
+            .iter()
+            .find(|placeholder| placeholder.name == name)
+        else {
+            return Err(Error::new_spanned(
+                display,
+                format!(
+                    "The `display` argument ‘{}’ is not a placeholder of the variant.",
+                    name
+                ),
+            ));
+        };
+        let binding = &placeholder.binding;
+        arguments.push(match placeholder.convert {
+            Convert::Path => quote! { #binding.display() },
+            _ => quote! { #binding },
+        });
+    }
+    Ok(quote! { write!(formatter, #string, #( #arguments ),* ) })
+}
+
+// Replaces the named arguments of a format string with positional arguments, ignoring escaped braces. Returns the
+// rewritten format string and the unique names, where the position is the index of the name.
+fn positional_format(string: &str) -> (String, Vec<String>) {
+    let mut format = String::with_capacity(string.len());
+    let mut names = Vec::<String>::new();
+    let mut characters = string.chars().peekable();
+    while let Some(character) = characters.next() {
+        format.push(character);
+        if character == '{' {
+            if characters.peek() == Some(&'{') {
+                format.push('{');
+                characters.next();
+                continue;
+            }
+            let mut argument = String::new();
+            for next in characters.by_ref() {
+                if next == '}' {
+                    break;
+                }
+                argument.push(next);
+            }
+            let (name, specification) = match argument.split_once(':') {
+                Some((name, specification)) => (name.trim().to_string(), Some(specification)),
+                None => (argument.trim().to_string(), None),
+            };
+            let position = match names.iter().position(|previous| *previous == name) {
+                Some(position) => position,
+                None => {
+                    names.push(name);
+                    names.len() - 1
+                }
+            };
+            format.push_str(position.to_string().as_str());
+            if let Some(specification) = specification {
+                format.push(':');
+                format.push_str(specification);
+            }
+            format.push('}');
+        } else if character == '}' && characters.peek() == Some(&'}') {
+            format.push('}');
+            characters.next();
+        }
+    }
+    (format, names)
+}
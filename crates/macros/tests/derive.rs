@@ -0,0 +1,160 @@
+// This file is part of `i18n_macros-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_macros-rizzen-yazston` crate.
+
+//! Testing the `Localisation` derive macro.
+
+use i18n_macros::Localisation;
+use i18n_utility::{LocalisationData, LocalisationTrait, PlaceholderValue};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[derive(Debug)]
+struct InnerError;
+
+impl LocalisationTrait for InnerError {
+    fn localisation_data(&self) -> LocalisationData {
+        LocalisationData {
+            component: "inner".to_string(),
+            identifier: "inner_error".to_string(),
+            values: None,
+        }
+    }
+}
+
+impl Display for InnerError {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(formatter, "InnerError: Failed.")
+    }
+}
+
+#[derive(Debug, Localisation)]
+#[localisation(component = "application")]
+enum TestError {
+    #[localisation(
+        identifier = "position",
+        display = "Invalid ‘{character}’ at {position}."
+    )]
+    Position(
+        #[localisation(placeholder = "character")] char,
+        #[localisation(placeholder = "position", convert = "unsigned")] usize,
+    ),
+    #[localisation(
+        component = "other",
+        identifier = "named",
+        display = "The name ‘{name}’ is unknown."
+    )]
+    Named {
+        #[localisation(placeholder = "name")]
+        name: String,
+        #[allow(dead_code)]
+        ignored: bool,
+    },
+    #[localisation(
+        identifier = "node",
+        display = "The {type} node ‘{node-type}’ ({node-type:?}) has {{braces}}."
+    )]
+    Node(
+        #[localisation(placeholder = "node-type")] String,
+        #[localisation(placeholder = "type")] String,
+    ),
+    #[localisation(embedded)]
+    Embedded(std::num::ParseIntError),
+    #[localisation(nested)]
+    Nested(InnerError),
+    #[cfg(not(test))]
+    #[localisation(identifier = "never", display = "Never.")]
+    Never,
+}
+
+impl Error for TestError {}
+
+#[test]
+fn display() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        TestError::Position('x', 3).to_string(),
+        "TestError::Position: Invalid ‘x’ at 3.",
+        "Check position."
+    );
+    assert_eq!(
+        TestError::Named {
+            name: "test".to_string(),
+            ignored: true
+        }
+        .to_string(),
+        "TestError::Named: The name ‘test’ is unknown.",
+        "Check named."
+    );
+    assert_eq!(
+        TestError::Node("text".to_string(), "leaf".to_string()).to_string(),
+        "TestError::Node: The leaf node ‘text’ (\"text\") has {braces}.",
+        "Check placeholder names that are not identifiers."
+    );
+    let parse_error = "a".parse::<u8>().unwrap_err();
+    assert_eq!(
+        TestError::Embedded(parse_error.clone()).to_string(),
+        format!("TestError::Embedded: [{}].", parse_error),
+        "Check embedded."
+    );
+    assert_eq!(
+        TestError::Nested(InnerError).to_string(),
+        "TestError::Nested: [InnerError: Failed.].",
+        "Check nested."
+    );
+    Ok(())
+}
+
+#[test]
+fn localisation_data() -> Result<(), Box<dyn Error>> {
+    let data = TestError::Position('x', 3).localisation_data();
+    assert_eq!(data.component, "i18n_localiser", "Check component.");
+    assert_eq!(data.identifier, "error_format_enum", "Check identifier.");
+    let values = data.values.as_ref().unwrap();
+    let Some(PlaceholderValue::String(variant)) = values.get("variant") else {
+        panic!("Expected variant.");
+    };
+    assert_eq!(variant, "Position", "Check variant.");
+    let Some(PlaceholderValue::LocalisationData(message)) = values.get("message") else {
+        panic!("Expected message.");
+    };
+    assert_eq!(message.component, "application", "Check message component.");
+    let message_values = message.values.as_ref().unwrap();
+    let Some(PlaceholderValue::Unsigned(position)) = message_values.get("position") else {
+        panic!("Expected position.");
+    };
+    assert_eq!(*position, 3, "Check position.");
+
+    let data = TestError::Named {
+        name: "test".to_string(),
+        ignored: false,
+    }
+    .localisation_data();
+    let Some(PlaceholderValue::LocalisationData(message)) =
+        data.values.as_ref().unwrap().get("message")
+    else {
+        panic!("Expected message.");
+    };
+    assert_eq!(message.component, "other", "Check overridden component.");
+    assert_eq!(
+        message.values.as_ref().unwrap().len(),
+        1,
+        "Only mapped fields are placeholders."
+    );
+
+    let data = TestError::Embedded("a".parse::<u8>().unwrap_err()).localisation_data();
+    assert_eq!(
+        data.identifier, "error_format_enum_embedded",
+        "Check embedded identifier."
+    );
+
+    let data = TestError::Nested(InnerError).localisation_data();
+    let Some(PlaceholderValue::LocalisationData(message)) =
+        data.values.as_ref().unwrap().get("message")
+    else {
+        panic!("Expected message.");
+    };
+    assert_eq!(
+        message.identifier, "inner_error",
+        "Check nested identifier."
+    );
+    Ok(())
+}
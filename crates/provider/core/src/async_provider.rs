@@ -0,0 +1,322 @@
+// This file is part of `i18n_provider-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_provider-rizzen-yazston` crate.
+
+use crate::{
+    ComponentDetails, IdentifierDetails, LocalisationProviderTrait, ProviderError,
+    RepositoryDetails,
+};
+use i18n_utility::LanguageTag;
+use std::future::Future;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc as RefCount, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, JoinHandle};
+
+/// The boxed future returned by the methods of [`AsyncLocalisationProviderTrait`].
+pub type ProviderFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, ProviderError>> + Send + 'a>>;
+
+/// The asynchronous counterpart of [`LocalisationProviderTrait`], for data repositories that are accessed
+/// asynchronously, such as a database server. The methods have the same meaning as those of
+/// `LocalisationProviderTrait`, though return a [`ProviderFuture`].
+///
+/// Any synchronous provider can be used asynchronously by wrapping it in a [`BlockingProvider`].
+pub trait AsyncLocalisationProviderTrait: Send + Sync {
+    fn string<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+        language_tag: &'a RefCount<LanguageTag>,
+    ) -> ProviderFuture<'a, Option<(String, RefCount<LanguageTag>)>>;
+
+    fn string_exact_match<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+        language_tag: &'a RefCount<LanguageTag>,
+    ) -> ProviderFuture<'a, Option<(String, RefCount<LanguageTag>)>>;
+
+    fn strings<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+        language_tag: &'a RefCount<LanguageTag>,
+    ) -> ProviderFuture<'a, Vec<(String, RefCount<LanguageTag>)>>;
+
+    fn identifiers<'a>(&'a self, component: &'a str) -> ProviderFuture<'a, Vec<String>>;
+
+    fn identifier_details<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+    ) -> ProviderFuture<'a, IdentifierDetails>;
+
+    fn component_details<'a>(
+        &'a self,
+        component: &'a str,
+    ) -> ProviderFuture<'a, RefCount<ComponentDetails>>;
+
+    fn repository_details(&self) -> ProviderFuture<'_, RefCount<RepositoryDetails>>;
+}
+
+/// `BlockingProvider` is an adapter implementing [`AsyncLocalisationProviderTrait`] for any synchronous provider,
+/// by running the blocking calls of the provider on a [`BlockingPool`], thus the calls do not block the threads of
+/// the asynchronous runtime.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_provider::{
+///     AsyncLocalisationProviderTrait, BlockingProvider, ComponentDetails, IdentifierDetails,
+///     LocalisationProviderTrait, ProviderError, RepositoryDetails,
+/// };
+/// use i18n_utility::LanguageTag;
+/// use std::sync::Arc;
+///
+/// struct Constant;
+///
+/// impl LocalisationProviderTrait for Constant {
+///     fn string(
+///         &self, _component: &str, _identifier: &str, language_tag: &Arc<LanguageTag>,
+///     ) -> Result<Option<(String, Arc<LanguageTag>)>, ProviderError> {
+///         Ok( Some( ( "Constant".to_string(), Arc::clone( language_tag ) ) ) )
+///     }
+///     fn string_exact_match(
+///         &self, component: &str, identifier: &str, language_tag: &Arc<LanguageTag>,
+///     ) -> Result<Option<(String, Arc<LanguageTag>)>, ProviderError> {
+///         self.string( component, identifier, language_tag )
+///     }
+///     fn strings(
+///         &self, component: &str, identifier: &str, language_tag: &Arc<LanguageTag>,
+///     ) -> Result<Vec<(String, Arc<LanguageTag>)>, ProviderError> {
+///         Ok( self.string( component, identifier, language_tag )?.into_iter().collect() )
+///     }
+///     fn identifiers( &self, _component: &str ) -> Result<Vec<String>, ProviderError> {
+///         Ok( vec![ "constant".to_string() ] )
+///     }
+///     fn identifier_details(
+///         &self, component: &str, _identifier: &str,
+///     ) -> Result<IdentifierDetails, ProviderError> {
+///         Err( ProviderError::ComponentNotFound( component.to_string() ) )
+///     }
+///     fn component_details(
+///         &self, component: &str,
+///     ) -> Result<Arc<ComponentDetails>, ProviderError> {
+///         Err( ProviderError::ComponentNotFound( component.to_string() ) )
+///     }
+///     fn repository_details( &self ) -> Result<Arc<RepositoryDetails>, ProviderError> {
+///         Err( ProviderError::ComponentNotFound( String::new() ) )
+///     }
+/// }
+///
+/// let provider = BlockingProvider::new( Constant, 2 );
+///
+/// // The future is usually awaited within an asynchronous runtime.
+/// let future = provider.identifiers( "component" );
+/// ```
+pub struct BlockingProvider<P> {
+    provider: RefCount<P>,
+    pool: BlockingPool,
+}
+
+impl<P: LocalisationProviderTrait + Send + Sync + 'static> BlockingProvider<P> {
+    /// Creates a `BlockingProvider` for the synchronous `provider`, with a [`BlockingPool`] of `threads` threads
+    /// (at least one).
+    pub fn new(provider: P, threads: usize) -> Self {
+        BlockingProvider {
+            provider: RefCount::new(provider),
+            pool: BlockingPool::new(threads),
+        }
+    }
+
+    /// Obtain the wrapped synchronous provider.
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+}
+
+impl<P: LocalisationProviderTrait + Send + Sync + 'static> AsyncLocalisationProviderTrait
+    for BlockingProvider<P>
+{
+    fn string<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+        language_tag: &'a RefCount<LanguageTag>,
+    ) -> ProviderFuture<'a, Option<(String, RefCount<LanguageTag>)>> {
+        let provider = RefCount::clone(&self.provider);
+        let (component, identifier) = (component.to_string(), identifier.to_string());
+        let language_tag = RefCount::clone(language_tag);
+        self.pool
+            .spawn(move || provider.string(&component, &identifier, &language_tag))
+    }
+
+    fn string_exact_match<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+        language_tag: &'a RefCount<LanguageTag>,
+    ) -> ProviderFuture<'a, Option<(String, RefCount<LanguageTag>)>> {
+        let provider = RefCount::clone(&self.provider);
+        let (component, identifier) = (component.to_string(), identifier.to_string());
+        let language_tag = RefCount::clone(language_tag);
+        self.pool.spawn(move || {
+            provider.string_exact_match(&component, &identifier, &language_tag)
+        })
+    }
+
+    fn strings<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+        language_tag: &'a RefCount<LanguageTag>,
+    ) -> ProviderFuture<'a, Vec<(String, RefCount<LanguageTag>)>> {
+        let provider = RefCount::clone(&self.provider);
+        let (component, identifier) = (component.to_string(), identifier.to_string());
+        let language_tag = RefCount::clone(language_tag);
+        self.pool
+            .spawn(move || provider.strings(&component, &identifier, &language_tag))
+    }
+
+    fn identifiers<'a>(&'a self, component: &'a str) -> ProviderFuture<'a, Vec<String>> {
+        let provider = RefCount::clone(&self.provider);
+        let component = component.to_string();
+        self.pool.spawn(move || provider.identifiers(&component))
+    }
+
+    fn identifier_details<'a>(
+        &'a self,
+        component: &'a str,
+        identifier: &'a str,
+    ) -> ProviderFuture<'a, IdentifierDetails> {
+        let provider = RefCount::clone(&self.provider);
+        let (component, identifier) = (component.to_string(), identifier.to_string());
+        self.pool
+            .spawn(move || provider.identifier_details(&component, &identifier))
+    }
+
+    fn component_details<'a>(
+        &'a self,
+        component: &'a str,
+    ) -> ProviderFuture<'a, RefCount<ComponentDetails>> {
+        let provider = RefCount::clone(&self.provider);
+        let component = component.to_string();
+        self.pool.spawn(move || provider.component_details(&component))
+    }
+
+    fn repository_details(&self) -> ProviderFuture<'_, RefCount<RepositoryDetails>> {
+        let provider = RefCount::clone(&self.provider);
+        self.pool.spawn(move || provider.repository_details())
+    }
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A fixed size pool of threads for running blocking calls, where each call is awaited as a [`ProviderFuture`].
+///
+/// The pool is independent of any asynchronous runtime. Should a call panic, the panic is resumed when the future is
+/// polled. The threads are stopped when the pool is dropped, after completing the queued calls.
+pub struct BlockingPool {
+    sender: Mutex<Option<Sender<Job>>>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl BlockingPool {
+    /// Creates a `BlockingPool` of `threads` threads, though at least one thread.
+    pub fn new(threads: usize) -> Self {
+        let (sender, receiver) = channel::<Job>();
+        let receiver = RefCount::new(Mutex::new(receiver));
+        let threads = (0..threads.max(1))
+            .map(|_| {
+                let receiver = RefCount::clone(&receiver);
+                thread::spawn(move || worker(&receiver))
+            })
+            .collect::<Vec<JoinHandle<()>>>();
+        BlockingPool {
+            sender: Mutex::new(Some(sender)),
+            threads,
+        }
+    }
+
+    /// Run the blocking `call` on a thread of the pool, returning the future of the call's result.
+    pub fn spawn<T, F>(&self, call: F) -> ProviderFuture<'static, T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> Result<T, ProviderError> + Send + 'static,
+    {
+        let slot = RefCount::new(Mutex::new(Slot::<T> {
+            result: None,
+            waker: None,
+        }));
+        let completion = RefCount::clone(&slot);
+        let job: Job = Box::new(move || {
+            let result = catch_unwind(AssertUnwindSafe(call));
+            let mut slot = completion.lock().unwrap();
+            slot.result = Some(result);
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+        });
+        let rejected = match self.sender.lock().unwrap().as_ref() {
+            Some(sender) => sender.send(job).err().map(|error| error.0),
+            None => Some(job),
+        };
+
+        // Should the pool no longer accept calls, the call is done immediately.
+        if let Some(job) = rejected {
+            job();
+        }
+        Box::pin(BlockingFuture { slot })
+    }
+}
+
+impl Drop for BlockingPool {
+    fn drop(&mut self) {
+        // Closing the channel stops the threads, once the queued calls are done.
+        self.sender.lock().unwrap().take();
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn worker(receiver: &Mutex<Receiver<Job>>) {
+    loop {
+        let job = match receiver.lock().unwrap().recv() {
+            Ok(job) => job,
+            Err(_) => return, // Channel closed.
+        };
+        job();
+    }
+}
+
+// The result of a call, and the waker of the task awaiting the result.
+struct Slot<T> {
+    result: Option<thread::Result<Result<T, ProviderError>>>,
+    waker: Option<Waker>,
+}
+
+struct BlockingFuture<T> {
+    slot: RefCount<Mutex<Slot<T>>>,
+}
+
+impl<T> Future for BlockingFuture<T> {
+    type Output = Result<T, ProviderError>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.slot.lock().unwrap();
+        match slot.result.take() {
+            Some(Ok(result)) => Poll::Ready(result),
+            Some(Err(payload)) => {
+                drop(slot);
+                resume_unwind(payload)
+            }
+            None => {
+                slot.waker = Some(context.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
@@ -0,0 +1,251 @@
+// This file is part of `i18n_provider-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_provider-rizzen-yazston` crate.
+
+use crate::{LocalisationProviderTrait, ProviderError};
+use i18n_utility::{json_string, LanguageTag};
+
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as RefCount;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::Arc as RefCount;
+
+/// The `CoverageReport` contains the translation coverage of every component of a provider's repository, for each of
+/// the requested languages. Each identifier of a component is placed into one of the following lists for a language:
+///
+/// * `translated`: An exact match exists, and the string differs from the component's default language string,
+///
+/// * `identical`: An exact match exists, though the string is an untouched copy of the default language string,
+///
+/// * `fallback`: No exact match exists, though a string is available via the provider's subtag fallback,
+///
+/// * `missing`: No string is available for the language at all.
+///
+/// The report can be exported as JSON ([`CoverageReport::to_json()`]) or as CSV ([`CoverageReport::to_csv()`]).
+///
+/// # Examples
+///
+/// For an example, see the `i18n_provider_sqlite3-rizzen-yazston` crate's tests.
+#[derive(Debug)]
+pub struct CoverageReport {
+    pub components: Vec<ComponentCoverage>, // The coverage of each component, sorted by component name.
+}
+
+/// The coverage of a single component for each language of the report.
+#[derive(Debug)]
+pub struct ComponentCoverage {
+    pub component: String,                // The name of the component.
+    pub default: RefCount<LanguageTag>,   // The default language of the component.
+    pub identifiers: usize,               // The total number of identifiers of the component.
+    pub languages: Vec<LanguageCoverage>, // The coverage of each language, sorted by language tag.
+}
+
+/// The lists of identifiers for a language of a component. All identifier lists are sorted.
+#[derive(Debug)]
+pub struct LanguageCoverage {
+    pub language_tag: RefCount<LanguageTag>, // The language of this coverage.
+    pub translated: Vec<String>, // Exact match, and differs from the default language string.
+    pub identical: Vec<String>,  // Exact match, though identical to the default language string.
+    pub fallback: Vec<(String, RefCount<LanguageTag>)>, // Identifier and the language tag that was used.
+    pub missing: Vec<String>,                           // No string is available.
+}
+
+impl CoverageReport {
+    /// Creates the coverage report for all the components of the provider's repository.
+    ///
+    /// If `languages` is `None`, then all the languages of the repository (see [`RepositoryDetails`]) are used.
+    ///
+    /// Return of [`ProviderError`] indicates there was an error in accessing the data repository.
+    ///
+    /// [`RepositoryDetails`]: crate::RepositoryDetails
+    pub fn try_new(
+        provider: &dyn LocalisationProviderTrait,
+        languages: Option<&[RefCount<LanguageTag>]>,
+    ) -> Result<Self, ProviderError> {
+        let repository = provider.repository_details()?;
+        let mut tags = match languages {
+            None => repository
+                .languages
+                .keys()
+                .map(RefCount::clone)
+                .collect::<Vec<RefCount<LanguageTag>>>(),
+            Some(value) => value.to_vec(),
+        };
+        tags.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        tags.dedup();
+        let mut component_names = repository.components.clone();
+        component_names.sort();
+        let mut components = Vec::<ComponentCoverage>::new();
+        for component in component_names.iter() {
+            components.push(ComponentCoverage::try_new(
+                provider,
+                component.as_str(),
+                &tags,
+            )?);
+        }
+        Ok(CoverageReport { components })
+    }
+
+    /// Exports the report as a JSON string.
+    ///
+    /// The `translated` identifiers are only provided as a count, while `identical`, `fallback` and `missing` are
+    /// provided as lists of identifiers.
+    pub fn to_json(&self) -> String {
+        let mut json = "{\"components\":[".to_string();
+        for (index, component) in self.components.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"component\":");
+            json.push_str(json_string(component.component.as_str()).as_str());
+            json.push_str(",\"default\":");
+            json.push_str(json_string(component.default.as_str()).as_str());
+            json.push_str(",\"identifiers\":");
+            json.push_str(component.identifiers.to_string().as_str());
+            json.push_str(",\"languages\":[");
+            for (index, language) in component.languages.iter().enumerate() {
+                if index > 0 {
+                    json.push(',');
+                }
+                json.push_str("{\"language_tag\":");
+                json.push_str(json_string(language.language_tag.as_str()).as_str());
+                json.push_str(",\"translated\":");
+                json.push_str(language.translated.len().to_string().as_str());
+                json.push_str(",\"identical\":");
+                json.push_str(json_list(&language.identical).as_str());
+                json.push_str(",\"fallback\":[");
+                for (index, (identifier, tag)) in language.fallback.iter().enumerate() {
+                    if index > 0 {
+                        json.push(',');
+                    }
+                    json.push_str("{\"identifier\":");
+                    json.push_str(json_string(identifier.as_str()).as_str());
+                    json.push_str(",\"language_tag\":");
+                    json.push_str(json_string(tag.as_str()).as_str());
+                    json.push('}');
+                }
+                json.push_str("],\"missing\":");
+                json.push_str(json_list(&language.missing).as_str());
+                json.push('}');
+            }
+            json.push_str("]}");
+        }
+        json.push_str("]}");
+        json
+    }
+
+    /// Exports the report as a CSV string (RFC 4180), having the header row:
+    /// `component,language_tag,status,identifier,fallback_tag`.
+    ///
+    /// Only the identifiers requiring attention are exported, thus `status` is one of `identical`, `fallback` or
+    /// `missing`. The `fallback_tag` column is only filled for the `fallback` status.
+    pub fn to_csv(&self) -> String {
+        let mut csv = "component,language_tag,status,identifier,fallback_tag\r\n".to_string();
+        for component in self.components.iter() {
+            for language in component.languages.iter() {
+                let prefix = format!(
+                    "{},{},",
+                    csv_field(component.component.as_str()),
+                    csv_field(language.language_tag.as_str())
+                );
+                for identifier in language.identical.iter() {
+                    csv.push_str(
+                        format!("{}identical,{},\r\n", prefix, csv_field(identifier)).as_str(),
+                    );
+                }
+                for (identifier, tag) in language.fallback.iter() {
+                    csv.push_str(
+                        format!(
+                            "{}fallback,{},{}\r\n",
+                            prefix,
+                            csv_field(identifier),
+                            csv_field(tag.as_str())
+                        )
+                        .as_str(),
+                    );
+                }
+                for identifier in language.missing.iter() {
+                    csv.push_str(
+                        format!("{}missing,{},\r\n", prefix, csv_field(identifier)).as_str(),
+                    );
+                }
+            }
+        }
+        csv
+    }
+}
+
+impl ComponentCoverage {
+    /// Creates the coverage of a single component for the provided languages.
+    ///
+    /// Return of [`ProviderError`] indicates there was an error in accessing the data repository.
+    pub fn try_new(
+        provider: &dyn LocalisationProviderTrait,
+        component: &str,
+        languages: &[RefCount<LanguageTag>],
+    ) -> Result<Self, ProviderError> {
+        let details = provider.component_details(component)?;
+        let mut identifiers = provider.identifiers(component)?;
+        identifiers.sort();
+        let mut defaults = Vec::<Option<String>>::new();
+        for identifier in identifiers.iter() {
+            defaults.push(
+                provider
+                    .string_exact_match(component, identifier, &details.default)?
+                    .map(|value| value.0),
+            );
+        }
+        let mut coverages = Vec::<LanguageCoverage>::new();
+        for language_tag in languages.iter() {
+            let mut coverage = LanguageCoverage {
+                language_tag: RefCount::clone(language_tag),
+                translated: Vec::<String>::new(),
+                identical: Vec::<String>::new(),
+                fallback: Vec::<(String, RefCount<LanguageTag>)>::new(),
+                missing: Vec::<String>::new(),
+            };
+            for (identifier, default) in identifiers.iter().zip(defaults.iter()) {
+                if let Some((string, _)) =
+                    provider.string_exact_match(component, identifier, language_tag)?
+                {
+                    if *language_tag != details.default && Some(&string) == default.as_ref() {
+                        coverage.identical.push(identifier.clone());
+                    } else {
+                        coverage.translated.push(identifier.clone());
+                    }
+                    continue;
+                }
+                match provider.string(component, identifier, language_tag)? {
+                    Some((_, tag)) => coverage.fallback.push((identifier.clone(), tag)),
+                    None => coverage.missing.push(identifier.clone()),
+                }
+            }
+            coverages.push(coverage);
+        }
+        Ok(ComponentCoverage {
+            component: component.to_string(),
+            default: RefCount::clone(&details.default),
+            identifiers: identifiers.len(),
+            languages: coverages,
+        })
+    }
+}
+
+// Internal functions.
+
+fn json_list(list: &[String]) -> String {
+    let strings = list
+        .iter()
+        .map(|value| json_string(value))
+        .collect::<Vec<String>>();
+    format!("[{}]", strings.join(","))
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
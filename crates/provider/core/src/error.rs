@@ -17,8 +17,13 @@ use std::rc::Rc as RefCount;
 #[cfg(target_has_atomic = "ptr")]
 use std::sync::Arc as RefCount;
 
+#[cfg(not(feature = "sync"))]
 pub trait ProviderErrorTrait: LocalisationTrait + Error + Display {}
 
+/// With feature `sync`, the provider errors are sent between threads.
+#[cfg(feature = "sync")]
+pub trait ProviderErrorTrait: LocalisationTrait + Error + Display + Send + Sync {}
+
 /// Contains the possible errors that may occur within the provider.
 ///
 /// The `ProviderError` type consists of the follow:
@@ -3,7 +3,12 @@
 
 //! Welcome to the **`i18n_provider`** crate of the *Internationalisation* (i18n) project.
 //!
-//! This crate consists of two modules:
+//! This crate consists of four modules:
+//!
+//! * `async_provider`: The asynchronous localisation provider trait, and an adapter for synchronous providers (using
+//!   feature `async`),
+//!
+//! * [`coverage`]: Translation coverage report of a localisation provider's repository,
 //!
 //! * [`error`]: Contains the enum for common errors regardless of the implementation of the localisation provider trait,
 //!
@@ -16,6 +21,9 @@
 //! * `sync`: Allow for rust's concurrency capabilities to be used. Use of [`Arc`] and [`Mutex`] instead [`Rc`] and
 //!   [`RefCell`].
 //!
+//! * `async`: Provides the `AsyncLocalisationProviderTrait` trait, and the `BlockingProvider` adapter that runs a
+//!   synchronous provider on a pool of threads. Enables the `sync` feature.
+//!
 //! # Modules
 //!
 //! ## `provider`: A localisation string provider.
@@ -23,12 +31,19 @@
 //! A trait for retrieving localisation strings from a data repository via a provider that implements the trait
 //! methods: `strings()` and `string()`. In addition, there are other trait methods for retrieve the default
 //! language for a component, and supported languages for entire data repository, component and identifier
-//! respectively.
+//! respectively. The optional `reload()` trait method lets a provider pick up changes to its data repository without
+//! being recreated.
 //!
 //! ### Examples
 //!
 //! For an implementation example, see the `i18n_provider_sqlite3-rizzen-yazston` crate, which uses Sqlite3 for its
 //! data store.
+//!
+//! ## `coverage`: Translation coverage report.
+//!
+//! The `CoverageReport` struct uses the provider trait methods to produce, for each component and language, the lists
+//! of identifiers that are missing, only available via subtag fallback, or are untouched copies of the component's
+//! default language string. The report can be exported as JSON or CSV for translation managers.
 
 #[cfg(doc)]
 use std::sync::{Arc, Mutex};
@@ -46,3 +61,11 @@ pub mod provider;
 pub use provider::*;
 pub mod error;
 pub use error::*;
+pub mod coverage;
+pub use coverage::*;
+
+#[cfg(feature = "async")]
+pub mod async_provider;
+
+#[cfg(feature = "async")]
+pub use async_provider::*;
@@ -97,6 +97,18 @@ pub trait LocalisationProviderTrait {
     ) -> Result<Vec<TaggedString>, ProviderError>;
     */
 
+    /// Obtain a list of all the identifiers of a component, which have at least one string in any language.
+    ///
+    /// The default implementation is for providers that are unable to list the identifiers, and only checks the
+    /// component exists using `component_details()`, returning an empty [`Vec`]. Thus the coverage report and the
+    /// pattern checker find no identifiers for the component. Providers should implement this method.
+    ///
+    /// Return of [`ProviderError`] indicates there was an error in accessing the data repository.
+    fn identifiers(&self, component: &str) -> Result<Vec<String>, ProviderError> {
+        self.component_details(component)?;
+        Ok(Vec::<String>::new())
+    }
+
     /// Obtain the information details [`IdentifierDetails`] of an identifier within a component.
     ///
     /// Return of [`ProviderError`] indicates there was an error in accessing the data repository.
@@ -118,6 +130,18 @@ pub trait LocalisationProviderTrait {
     ///
     /// Return of [`ProviderError`] indicates there was an error in accessing the data repository.
     fn repository_details(&self) -> Result<RefCount<RepositoryDetails>, ProviderError>;
+
+    /// Check the data repository for changes since it was last read, and if there are changes replace the provider's
+    /// data with the current data of the repository. The components whose strings may have changed are returned, thus
+    /// an empty [`Vec`] indicates no changes were detected.
+    ///
+    /// The default implementation is for data repositories that do not change, and always returns an empty `Vec`.
+    ///
+    /// Return of [`ProviderError`] indicates there was an error in accessing the data repository, and the provider's
+    /// data is left unchanged.
+    fn reload(&self) -> Result<Vec<String>, ProviderError> {
+        Ok(Vec::<String>::new())
+    }
 }
 
 /// Contains a list of available languages for an identifier of a component in the provider's repository, where there
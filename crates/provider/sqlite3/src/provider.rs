@@ -27,7 +27,10 @@ use std::cell::{OnceCell as OnceMut, RefCell as MutCell};
 #[cfg(target_has_atomic = "ptr")]
 use std::sync::{Arc as RefCount, Mutex as MutCell, OnceLock as OnceMut};
 
-use std::path::PathBuf;
+use std::fs::File;
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 /// `LocalisationProviderSqlite3` struct is an implementation of the [`LocalisationProviderTrait`] trait, and uses
 /// Sqlite3 as the data store for localisation data repository. As the directory path of the data store is embedded in
@@ -79,19 +82,8 @@ use std::path::PathBuf;
 pub struct LocalisationProviderSqlite3 {
     language_tag_registry: RefCount<LanguageTagRegistry>,
     queries: MutCell<HashMap<String, String>>,
-    components: HashMap<String, (bool, bool)>, // 1st: in all_in_one, 2nd: as own sqlite3 file.
-
-    #[cfg(not(feature = "sync"))]
-    connections: HashMap<String, (RefCount<Connection>, String)>, // ( ..., String) is schema_version
-
-    #[cfg(feature = "sync")]
-    // Due to the Connection containing a RefCell field, instead the path to
-    // the verified database file is stored.
-    connections: HashMap<String, (PathBuf, String)>, // ( ..., String) is schema_version
-
-    // Cached data (long running sql queries)
-    repository_details: OnceMut<RefCount<RepositoryDetails>>,
-    component_details: OnceMut<HashMap<String, RefCount<ComponentDetails>>>,
+    directory: PathBuf,
+    repository: MutCell<RefCount<Repository>>,
     #[allow(dead_code)]
     use_database_cache: bool, //TODO: This feature still to be implemented, once database schema is stabilised.
 }
@@ -125,167 +117,71 @@ impl LocalisationProviderSqlite3 {
 
             return Err(ProviderSqlite3Error::NotDirectory(directory));
         }
-        let mut components = HashMap::<String, (bool, bool)>::new();
-
-        #[cfg(not(feature = "sync"))]
-        let mut connections = HashMap::<String, (RefCount<Connection>, String)>::new();
-
-        #[cfg(feature = "sync")]
-        let mut connections = HashMap::<String, (PathBuf, String)>::new();
-
-        let iterator = directory.read_dir()?; // If IO error is returned, usually it is a permission issue.
-        for entry in iterator {
-            let entry_data = entry?; // If IO error is returned, usually it is a permission issue.
-            if let Some(extension) = entry_data.path().extension() {
-                if extension == "sqlite3" {
-                    let path = entry_data.path();
-                    let component = path.file_stem().unwrap().to_str().unwrap().to_string();
-
-                    #[cfg(logging)]
-                    trace!("Sqlite3 file: {}", component);
-
-                    if component.as_str().cmp("__all_in_one__") == Ordering::Equal {
-                        match Connection::open_with_flags(
-                            path.clone(),
-                            OpenFlags::SQLITE_OPEN_READ_ONLY
-                                | OpenFlags::SQLITE_OPEN_NO_MUTEX
-                                | OpenFlags::SQLITE_OPEN_URI,
-                        ) {
-                            Err(_error) => {
-                                #[cfg(feature = "logging")]
-                                error!("Unable to connect to {}: {}.", path.display(), _error);
-                            }
-                            Ok(connection) => {
-                                let schema_version = verify_schema(&connection)?;
-                                {
-                                    let mut statement = connection
-                                        .prepare_cached("SELECT identifier FROM component")?;
-                                    let mut rows = statement.query([])?;
-                                    while let Some(row) = rows.next()? {
-                                        let component: String = row.get(0)?;
-
-                                        #[cfg(logging)]
-                                        trace!(
-                                            "__all_in_one__.sqlite3 has component: {}",
-                                            component
-                                        );
-
-                                        if let std::collections::hash_map::Entry::Vacant(e) =
-                                            components.entry(component.clone())
-                                        {
-                                            e.insert((true, false));
-                                        } else {
-                                            let value = components.get_mut(&component).unwrap();
-                                            value.0 = true;
-                                        }
-                                    }
-                                }
-
-                                #[cfg(not(feature = "sync"))]
-                                connections.insert(
-                                    "__all_in_one__".to_string(),
-                                    (RefCount::new(connection), schema_version),
-                                );
-
-                                #[cfg(feature = "sync")]
-                                connections
-                                    .insert("__all_in_one__".to_string(), (path, schema_version));
-                            }
-                        }
-                    } else {
-                        match Connection::open_with_flags(
-                            path.clone(),
-                            OpenFlags::SQLITE_OPEN_READ_ONLY
-                                | OpenFlags::SQLITE_OPEN_NO_MUTEX
-                                | OpenFlags::SQLITE_OPEN_URI,
-                        ) {
-                            Err(_error) => {
-                                #[cfg(feature = "logging")]
-                                error!("Unable to connect to {}: {}.", path.display(), _error);
-                            }
-                            Ok(connection) => {
-                                let schema_version = verify_schema(&connection)?;
-
-                                #[cfg(logging)]
-                                trace!("Added component: {}", component);
-
-                                if components.contains_key(&component) {
-                                    let value = components.get_mut(&component).unwrap();
-                                    value.1 = true;
-                                } else {
-                                    components.insert(component.clone(), (false, true));
-                                }
-
-                                #[cfg(not(feature = "sync"))]
-                                connections
-                                    .insert(component, (RefCount::new(connection), schema_version));
-
-                                #[cfg(feature = "sync")]
-                                connections.insert(component, (path, schema_version));
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        if components.is_empty() {
-            #[cfg(feature = "logging")]
-            error!("No sqlite3 files are found in {}.", directory.display());
-
-            return Err(ProviderSqlite3Error::NoSqlite3Files(directory));
-        }
+        let repository = Repository::try_new(&directory)?;
         Ok(LocalisationProviderSqlite3 {
             language_tag_registry: RefCount::clone(language_tag_registry),
             queries: MutCell::new(HashMap::<String, String>::new()),
-            components,
-            connections,
-            repository_details: OnceMut::new(),
-            component_details: OnceMut::new(),
+            directory,
+            repository: MutCell::new(RefCount::new(repository)),
             use_database_cache,
         })
     }
 
     // Internal functions.
 
+    // Obtain the current repository. The repository is obtained once per request, so that a concurrent reload does
+    // not mix the connections of the old and new repository.
+    fn repository(&self) -> RefCount<Repository> {
+        #[cfg(not(feature = "sync"))]
+        let binding = self.repository.borrow();
+
+        #[cfg(feature = "sync")]
+        let binding = self.repository.lock().unwrap();
+
+        RefCount::clone(&binding)
+    }
+
     #[cfg(not(feature = "sync"))]
-    fn connection(
+    fn connection<'a>(
         &self,
+        repository: &'a Repository,
         component: &str,
         all_in_one: bool,
-    ) -> Result<(RefCount<Connection>, &str), ProviderError> {
+    ) -> Result<(RefCount<Connection>, &'a str), ProviderError> {
         #[cfg(feature = "logging")]
         debug!("Getting database connection for component '{}'.", component,);
 
         #[allow(unused_variables)]
-        let Some(value) = self.components.get(component) else {
+        let Some(value) = repository.components.get(component) else {
             return Err(ProviderError::ComponentNotFound(component.to_string()));
         };
         if all_in_one && value.0 {
-            let result = self.connections.get("__all_in_one__").unwrap();
+            let result = repository.connections.get("__all_in_one__").unwrap();
             return Ok((RefCount::clone(&result.0), result.1.as_str()));
         }
         if value.1 {
-            let result = self.connections.get(component).unwrap();
+            let result = repository.connections.get(component).unwrap();
             return Ok((RefCount::clone(&result.0), result.1.as_str()));
         }
         Err(ProviderError::ComponentNotFound(component.to_string()))
     }
 
     #[cfg(feature = "sync")]
-    fn connection_sync(
+    fn connection_sync<'a>(
         &self,
+        repository: &'a Repository,
         component: &str,
         all_in_one: bool,
-    ) -> Result<(Connection, &str), ProviderError> {
+    ) -> Result<(Connection, &'a str), ProviderError> {
         #[cfg(feature = "logging")]
         debug!("Getting database connection for component '{}'.", component,);
 
         #[allow(unused_variables)]
-        let Some(value) = self.components.get(component) else {
+        let Some(value) = repository.components.get(component) else {
             return Err(ProviderError::ComponentNotFound(component.to_string()));
         };
         if all_in_one && value.0 {
-            let result = self.connections.get("__all_in_one__").unwrap();
+            let result = repository.connections.get("__all_in_one__").unwrap();
             match Connection::open_with_flags(
                 &result.0,
                 OpenFlags::SQLITE_OPEN_READ_ONLY
@@ -303,7 +199,7 @@ impl LocalisationProviderSqlite3 {
             }
         }
         if value.1 {
-            let result = self.connections.get(component).unwrap();
+            let result = repository.connections.get(component).unwrap();
             match Connection::open_with_flags(
                 &result.0,
                 OpenFlags::SQLITE_OPEN_READ_ONLY
@@ -324,8 +220,10 @@ impl LocalisationProviderSqlite3 {
     }
 
     // Fallback to <component>.sqlite3 is handled by caller.
+    #[allow(clippy::too_many_arguments)]
     fn find_strings(
         &self,
+        repository: &Repository,
         component: &str,
         identifier: &str,
         language_tag: &RefCount<LanguageTag>,
@@ -342,10 +240,11 @@ impl LocalisationProviderSqlite3 {
 
         // Get connection and schema_version.
         #[cfg(not(feature = "sync"))]
-        let (connection, schema_version) = self.connection(component, all_in_one)?;
+        let (connection, schema_version) = self.connection(repository, component, all_in_one)?;
 
         #[cfg(feature = "sync")]
-        let (connection, schema_version) = self.connection_sync(component, all_in_one)?;
+        let (connection, schema_version) =
+            self.connection_sync(repository, component, all_in_one)?;
 
         // Construct query identifier and get query statement.
         let mut query_identifier = "Pattern".to_string();
@@ -462,6 +361,7 @@ impl LocalisationProviderSqlite3 {
     // Fallback to <component>.sqlite3 is handled by caller.
     fn languages(
         &self,
+        repository: &Repository,
         component: &str,
         all_in_one: bool,
     ) -> Result<Vec<RefCount<LanguageTag>>, ProviderError> {
@@ -470,10 +370,11 @@ impl LocalisationProviderSqlite3 {
 
         // Get connection and schema_version.
         #[cfg(not(feature = "sync"))]
-        let (connection, schema_version) = self.connection(component, all_in_one)?;
+        let (connection, schema_version) = self.connection(repository, component, all_in_one)?;
 
         #[cfg(feature = "sync")]
-        let (connection, schema_version) = self.connection_sync(component, all_in_one)?;
+        let (connection, schema_version) =
+            self.connection_sync(repository, component, all_in_one)?;
 
         // Construct query identifier and get query statement.
         let mut query_identifier = "Languages".to_string();
@@ -556,6 +457,7 @@ impl LocalisationProviderSqlite3 {
     // Fallback to <component>.sqlite3 is handled by caller.
     fn identifier_languages(
         &self,
+        repository: &Repository,
         component: &str,
         identifier: &str,
         all_in_one: bool,
@@ -568,10 +470,11 @@ impl LocalisationProviderSqlite3 {
 
         // Get connection and schema_version.
         #[cfg(not(feature = "sync"))]
-        let (connection, schema_version) = self.connection(component, all_in_one)?;
+        let (connection, schema_version) = self.connection(repository, component, all_in_one)?;
 
         #[cfg(feature = "sync")]
-        let (connection, schema_version) = self.connection_sync(component, all_in_one)?;
+        let (connection, schema_version) =
+            self.connection_sync(repository, component, all_in_one)?;
 
         // Construct query identifier and get query statement.
         let mut query_identifier = "Identifier".to_string();
@@ -652,9 +555,105 @@ impl LocalisationProviderSqlite3 {
         Ok(languages)
     }
 
+    // Fallback to <component>.sqlite3 is handled by caller.
+    fn component_identifiers(
+        &self,
+        repository: &Repository,
+        component: &str,
+        all_in_one: bool,
+    ) -> Result<Vec<String>, ProviderError> {
+        #[cfg(feature = "logging")]
+        debug!("Get identifiers for component '{}'.", component);
+
+        // Get connection and schema_version.
+        #[cfg(not(feature = "sync"))]
+        let (connection, schema_version) = self.connection(repository, component, all_in_one)?;
+
+        #[cfg(feature = "sync")]
+        let (connection, schema_version) =
+            self.connection_sync(repository, component, all_in_one)?;
+
+        // Construct query identifier and get query statement.
+        let mut query_identifier = "Identifiers".to_string();
+        if all_in_one {
+            query_identifier.push_str("Aio");
+        }
+        let mut _query: Option<String> = None;
+        {
+            #[cfg(not(feature = "sync"))]
+            let borrow = self.queries.borrow();
+
+            #[cfg(feature = "sync")]
+            let borrow = self.queries.lock().unwrap();
+
+            _query = borrow.get(&query_identifier).cloned();
+        }
+
+        #[cfg(not(feature = "sync"))]
+        if _query.is_none() {
+            {
+                self.queries
+                    .borrow_mut()
+                    .insert(query_identifier.clone(), query_identifiers(schema_version));
+            }
+            let borrow = self.queries.borrow();
+            _query = borrow.get(&query_identifier).cloned();
+        }
+
+        #[cfg(feature = "sync")]
+        if _query.is_none() {
+            {
+                self.queries
+                    .lock()
+                    .unwrap()
+                    .insert(query_identifier.clone(), query_identifiers(schema_version));
+            }
+            let borrow = self.queries.lock().unwrap();
+            _query = borrow.get(&query_identifier).cloned();
+        }
+
+        let mut statement = match connection.prepare_cached(_query.unwrap().as_str()) {
+            Ok(value) => value,
+            Err(error) => {
+                return Err(ProviderError::Custom(RefCount::new(Box::new(
+                    ProviderSqlite3Error::Sqlite3(RefCount::new(error)),
+                ))))
+            }
+        };
+        let mut identifiers = Vec::<String>::new();
+        let mut rows = match statement.query([component]) {
+            Ok(value) => value,
+            Err(error) => {
+                return Err(ProviderError::Custom(RefCount::new(Box::new(
+                    ProviderSqlite3Error::Sqlite3(RefCount::new(error)),
+                ))))
+            }
+        };
+        while let Some(row) = match rows.next() {
+            Ok(value) => value,
+            Err(error) => {
+                return Err(ProviderError::Custom(RefCount::new(Box::new(
+                    ProviderSqlite3Error::Sqlite3(RefCount::new(error)),
+                ))))
+            }
+        } {
+            let identifier: String = match row.get(0) {
+                Ok(value) => value,
+                Err(error) => {
+                    return Err(ProviderError::Custom(RefCount::new(Box::new(
+                        ProviderSqlite3Error::Sqlite3(RefCount::new(error)),
+                    ))))
+                }
+            };
+            identifiers.push(identifier);
+        }
+        Ok(identifiers)
+    }
+
     // Fallback to <component>.sqlite3 is handled by caller.
     fn contributors(
         &self,
+        repository: &Repository,
         component: &str,
         language_tag: &RefCount<LanguageTag>,
         all_in_one: bool,
@@ -668,10 +667,11 @@ impl LocalisationProviderSqlite3 {
 
         // Get connection and schema_version.
         #[cfg(not(feature = "sync"))]
-        let (connection, schema_version) = self.connection(component, all_in_one)?;
+        let (connection, schema_version) = self.connection(repository, component, all_in_one)?;
 
         #[cfg(feature = "sync")]
-        let (connection, schema_version) = self.connection_sync(component, all_in_one)?;
+        let (connection, schema_version) =
+            self.connection_sync(repository, component, all_in_one)?;
 
         // Construct query identifier and get query statement.
         let mut query_identifier = "Contributors".to_string();
@@ -753,6 +753,7 @@ impl LocalisationProviderSqlite3 {
     // Fallback to <component>.sqlite3 is handled by caller.
     fn count(
         &self,
+        repository: &Repository,
         component: &str,
         language_tag: &RefCount<LanguageTag>,
         all_in_one: bool,
@@ -766,10 +767,11 @@ impl LocalisationProviderSqlite3 {
 
         // Get connection and schema_version.
         #[cfg(not(feature = "sync"))]
-        let (connection, schema_version) = self.connection(component, all_in_one)?;
+        let (connection, schema_version) = self.connection(repository, component, all_in_one)?;
 
         #[cfg(feature = "sync")]
-        let (connection, schema_version) = self.connection_sync(component, all_in_one)?;
+        let (connection, schema_version) =
+            self.connection_sync(repository, component, all_in_one)?;
 
         // Construct query identifier and get query statement.
         let mut query_identifier = "Count".to_string();
@@ -850,6 +852,7 @@ impl LocalisationProviderSqlite3 {
     // Fallback to <component>.sqlite3 is handled by caller.
     fn default_language(
         &self,
+        repository: &Repository,
         component: &str,
         all_in_one: bool,
     ) -> Result<Option<RefCount<LanguageTag>>, ProviderError> {
@@ -858,10 +861,11 @@ impl LocalisationProviderSqlite3 {
 
         // Get connection and schema_version.
         #[cfg(not(feature = "sync"))]
-        let (connection, schema_version) = self.connection(component, all_in_one)?;
+        let (connection, schema_version) = self.connection(repository, component, all_in_one)?;
 
         #[cfg(feature = "sync")]
-        let (connection, schema_version) = self.connection_sync(component, all_in_one)?;
+        let (connection, schema_version) =
+            self.connection_sync(repository, component, all_in_one)?;
 
         // Construct query identifier and get query statement.
         let mut query_identifier = "Default".to_string();
@@ -941,7 +945,7 @@ impl LocalisationProviderSqlite3 {
     }
 
     // If __all_in_one__.sqlite3 fails, fallback to <component>.sqlite3
-    fn build_cache(&self) -> Result<(), ProviderError> {
+    fn build_cache(&self, repository: &Repository) -> Result<(), ProviderError> {
         #[cfg(feature = "logging")]
         debug!("Building details cache.");
 
@@ -955,7 +959,7 @@ impl LocalisationProviderSqlite3 {
         };
 
         // Get details info per component
-        let components_iterator = self.components.iter();
+        let components_iterator = repository.components.iter();
         for component in components_iterator {
             repository_details.components.push(component.0.to_string());
             let mut component_details = ComponentDetails {
@@ -968,7 +972,7 @@ impl LocalisationProviderSqlite3 {
             let mut languages = Vec::<RefCount<LanguageTag>>::new();
             if component.1 .0 {
                 // In __all_in_one__.sqlite3
-                languages = self.languages(component.0, true)?;
+                languages = self.languages(repository, component.0, true)?;
                 let languages_iterator = languages.iter();
                 for language in languages_iterator {
                     component_details.languages.insert(
@@ -993,7 +997,7 @@ impl LocalisationProviderSqlite3 {
             }
             if component.1 .1 {
                 // Has own <component>.sqlite3
-                let languages_separate = self.languages(component.0, false)?;
+                let languages_separate = self.languages(repository, component.0, false)?;
                 let languages_iterator = languages_separate.iter();
                 for language in languages_iterator {
                     if !languages.contains(language) {
@@ -1026,10 +1030,10 @@ impl LocalisationProviderSqlite3 {
             // Get default language
             let mut language = None;
             if component.1 .0 {
-                language = self.default_language(component.0, true)?;
+                language = self.default_language(repository, component.0, true)?;
             }
             if language.is_none() && component.1 .1 {
-                language = self.default_language(component.0, false)?;
+                language = self.default_language(repository, component.0, false)?;
             }
             if language.is_none() {
                 return Err(ProviderError::DefaultLanguage(component.0.to_string()));
@@ -1055,7 +1059,8 @@ impl LocalisationProviderSqlite3 {
             for language in languages_iterator {
                 let language_data = component_details.languages.get_mut(language).unwrap();
                 if component.1 .0 {
-                    language_data.contributors = self.contributors(component.0, language, true)?;
+                    language_data.contributors =
+                        self.contributors(repository, component.0, language, true)?;
                     let contributors_iterator = language_data.contributors.iter();
                     for contributor in contributors_iterator {
                         if !repository_details.contributors.contains(contributor) {
@@ -1066,7 +1071,8 @@ impl LocalisationProviderSqlite3 {
                     }
                 }
                 if component.1 .1 {
-                    let contributors_separate = self.contributors(component.0, language, false)?;
+                    let contributors_separate =
+                        self.contributors(repository, component.0, language, false)?;
                     let contributors_iterator = contributors_separate.iter();
                     for contributor in contributors_iterator {
                         if !language_data.contributors.contains(contributor) {
@@ -1081,10 +1087,10 @@ impl LocalisationProviderSqlite3 {
                 }
 
                 if component.1 .0 {
-                    language_data.count = self.count(component.0, language, true)?;
+                    language_data.count = self.count(repository, component.0, language, true)?;
                 }
                 if component.1 .1 {
-                    language_data.count += self.count(component.0, language, false)?;
+                    language_data.count += self.count(repository, component.0, language, false)?;
                 }
                 let repository_language = repository_details.languages.get_mut(language).unwrap();
                 repository_language.count += language_data.count;
@@ -1113,13 +1119,10 @@ impl LocalisationProviderSqlite3 {
         #[cfg(feature = "logging")]
         debug!("Components done.");
 
-        if repository_details.default.is_some() {
+        if let Some(default) = repository_details.default.as_ref() {
             let mut _count = 0usize;
             {
-                let default_language_data = repository_details
-                    .languages
-                    .get(repository_details.default.as_ref().unwrap())
-                    .unwrap();
+                let default_language_data = repository_details.languages.get(default).unwrap();
                 _count = default_language_data.count;
             }
             let languages_iterator = repository_details.languages.iter_mut();
@@ -1127,8 +1130,8 @@ impl LocalisationProviderSqlite3 {
                 language_data.1.ratio = language_data.1.count as f32 / _count as f32;
             }
         }
-        let _ = self.component_details.set(components_details);
-        let _ = self
+        let _ = repository.component_details.set(components_details);
+        let _ = repository
             .repository_details
             .set(RefCount::new(repository_details));
         Ok(())
@@ -1189,7 +1192,8 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
             language_tag.as_str()
         );
 
-        let Some(component_files) = self.components.get(component) else {
+        let repository = self.repository();
+        let Some(component_files) = repository.components.get(component) else {
             return Err(ProviderError::ComponentNotFound(component.to_string()));
         };
 
@@ -1198,8 +1202,15 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
             #[cfg(feature = "logging")]
             debug!("Trying the '__all_in_one__.sqlite3' for string.");
 
-            let mut strings =
-                self.find_strings(component, identifier, language_tag, true, true, false)?;
+            let mut strings = self.find_strings(
+                &repository,
+                component,
+                identifier,
+                language_tag,
+                true,
+                true,
+                false,
+            )?;
             if !strings.is_empty() {
                 return Ok(strings.pop());
             }
@@ -1210,8 +1221,15 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
         debug!("Trying the component sqlite3 file for string.");
 
         if component_files.1 {
-            let mut strings =
-                self.find_strings(component, identifier, language_tag, false, true, false)?;
+            let mut strings = self.find_strings(
+                &repository,
+                component,
+                identifier,
+                language_tag,
+                false,
+                true,
+                false,
+            )?;
             if !strings.is_empty() {
                 return Ok(strings.pop());
             }
@@ -1272,7 +1290,8 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
             language_tag.as_str()
         );
 
-        let Some(component_files) = self.components.get(component) else {
+        let repository = self.repository();
+        let Some(component_files) = repository.components.get(component) else {
             return Err(ProviderError::ComponentNotFound(component.to_string()));
         };
 
@@ -1281,8 +1300,15 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
             #[cfg(feature = "logging")]
             debug!("Trying the '__all_in_one__.sqlite3' for exact match string.");
 
-            let mut strings =
-                self.find_strings(component, identifier, language_tag, true, true, true)?;
+            let mut strings = self.find_strings(
+                &repository,
+                component,
+                identifier,
+                language_tag,
+                true,
+                true,
+                true,
+            )?;
             if !strings.is_empty() {
                 return Ok(strings.pop());
             }
@@ -1293,8 +1319,15 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
         debug!("Trying the component sqlite3 file for exact match string.");
 
         if component_files.1 {
-            let mut strings =
-                self.find_strings(component, identifier, language_tag, false, true, true)?;
+            let mut strings = self.find_strings(
+                &repository,
+                component,
+                identifier,
+                language_tag,
+                false,
+                true,
+                true,
+            )?;
             if !strings.is_empty() {
                 return Ok(strings.pop());
             }
@@ -1355,7 +1388,8 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
             language_tag.as_str()
         );
 
-        let Some(component_files) = self.components.get(component) else {
+        let repository = self.repository();
+        let Some(component_files) = repository.components.get(component) else {
             return Err(ProviderError::ComponentNotFound(component.to_string()));
         };
         let mut strings = Vec::<(String, RefCount<LanguageTag>)>::new();
@@ -1365,7 +1399,15 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
             #[cfg(feature = "logging")]
             debug!("Trying the '__all_in_one__.sqlite3' for strings.");
 
-            strings = self.find_strings(component, identifier, language_tag, true, false, false)?;
+            strings = self.find_strings(
+                &repository,
+                component,
+                identifier,
+                language_tag,
+                true,
+                false,
+                false,
+            )?;
             if !strings.is_empty() {
                 return Ok(strings);
             }
@@ -1376,12 +1418,69 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
         debug!("Trying the component sqlite3 file for strings.");
 
         if component_files.1 {
-            strings =
-                self.find_strings(component, identifier, language_tag, false, false, false)?;
+            strings = self.find_strings(
+                &repository,
+                component,
+                identifier,
+                language_tag,
+                false,
+                false,
+                false,
+            )?;
         }
         Ok(strings)
     }
 
+    /// Obtain a list of all the identifiers of a component, which have at least one string in any language.
+    ///
+    /// Return of [`ProviderError`] indicates there was an error in accessing the data repository. The
+    /// `ProviderError` contains the actual error [`ProviderSqlite3Error`], usually indicates
+    /// there was a Sqlite3 error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+    /// use i18n_provider::LocalisationProviderTrait;
+    /// use i18n_utility::LanguageTagRegistry;
+    /// use std::rc::Rc;
+    /// use std::error::Error;
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let path = "./l10n/";
+    ///     let registry = Rc::new( LanguageTagRegistry::new() );
+    ///     let provider = LocalisationProviderSqlite3::try_new(
+    ///         path,
+    ///         &registry,
+    ///         false
+    ///     )?;
+    ///     let identifiers = provider.identifiers( "application" )?;
+    ///     assert!( identifiers.contains( &"example".to_string() ), "Should contain example." );
+    ///     Ok( () )
+    /// }
+    /// ```
+    fn identifiers(&self, component: &str) -> Result<Vec<String>, ProviderError> {
+        #[cfg(feature = "logging")]
+        debug!("Getting identifiers of component '{}'.", component);
+
+        let repository = self.repository();
+        let Some(component_files) = repository.components.get(component) else {
+            return Err(ProviderError::ComponentNotFound(component.to_string()));
+        };
+        let mut identifiers = Vec::<String>::new();
+        if component_files.0 {
+            identifiers = self.component_identifiers(&repository, component, true)?;
+        }
+        if component_files.1 {
+            for identifier in self.component_identifiers(&repository, component, false)? {
+                if !identifiers.contains(&identifier) {
+                    identifiers.push(identifier);
+                }
+            }
+        }
+        identifiers.sort();
+        Ok(identifiers)
+    }
+
     /// Obtain the information details [`IdentifierDetails`] of an identifier within a component.
     ///
     /// Return of [`ProviderError`] indicates there was an error in accessing the data repository. The
@@ -1424,19 +1523,22 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
             identifier, component
         );
 
-        let components = match self.component_details.get() {
+        let repository = self.repository();
+        let components = match repository.component_details.get() {
             None => {
-                self.build_cache()?;
-                self.component_details.get().unwrap()
+                self.build_cache(&repository)?;
+                repository.component_details.get().unwrap()
             }
             Some(value) => value,
         };
         match components.get(component) {
             None => Err(ProviderError::ComponentNotFound(component.to_string())),
             Some(component_details) => {
-                let mut languages = self.identifier_languages(component, identifier, true)?;
+                let mut languages =
+                    self.identifier_languages(&repository, component, identifier, true)?;
                 if languages.is_empty() {
-                    languages = self.identifier_languages(component, identifier, false)?;
+                    languages =
+                        self.identifier_languages(&repository, component, identifier, false)?;
                 }
                 Ok(IdentifierDetails {
                     languages,
@@ -1484,10 +1586,11 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
         #[cfg(feature = "logging")]
         debug!("Getting component details for '{}'.", component);
 
-        let components = match self.component_details.get() {
+        let repository = self.repository();
+        let components = match repository.component_details.get() {
             None => {
-                self.build_cache()?;
-                self.component_details.get().unwrap()
+                self.build_cache(&repository)?;
+                repository.component_details.get().unwrap()
             }
             Some(value) => value,
         };
@@ -1532,14 +1635,321 @@ impl LocalisationProviderTrait for LocalisationProviderSqlite3 {
         #[cfg(feature = "logging")]
         debug!("Getting repository details.");
 
-        match self.repository_details.get() {
+        let repository = self.repository();
+        match repository.repository_details.get() {
             None => {
-                self.build_cache()?;
-                return Ok(RefCount::clone(self.repository_details.get().unwrap()));
+                self.build_cache(&repository)?;
+                Ok(RefCount::clone(
+                    repository.repository_details.get().unwrap(),
+                ))
             }
             Some(value) => Ok(RefCount::clone(value)),
         }
     }
+
+    /// Check the directory for added, removed or modified `.sqlite3` files, by comparing the modification times,
+    /// lengths and file change counters of the files, and the lengths and salts of their `-wal` files (WAL mode), with
+    /// those when the directory was last read. If there are changes, the directory is read
+    /// again and the new connections and details replace the old as a whole. The components affected by the changed
+    /// files are returned, where a change of `__all_in_one__.sqlite3` affects all the components it contained before
+    /// and after the change.
+    ///
+    /// As detection is by polling, the application decides when to call `reload()`, such as on a timer or on user
+    /// request.
+    ///
+    /// Return of [`ProviderError`] indicates there was an error in reading the directory or the Sqlite3 files, such as
+    /// the directory no longer containing `.sqlite3` files, and the current data remains in use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_provider_sqlite3::LocalisationProviderSqlite3;
+    /// use i18n_provider::LocalisationProviderTrait;
+    /// use i18n_utility::LanguageTagRegistry;
+    /// use std::rc::Rc;
+    /// use std::error::Error;
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let path = "./l10n/";
+    ///     let registry = Rc::new( LanguageTagRegistry::new() );
+    ///     let provider = LocalisationProviderSqlite3::try_new(
+    ///         path,
+    ///         &registry,
+    ///         false
+    ///     )?;
+    ///     let changed = provider.reload()?;
+    ///     assert!( changed.is_empty(), "Should be no changes." );
+    ///     Ok( () )
+    /// }
+    /// ```
+    fn reload(&self) -> Result<Vec<String>, ProviderError> {
+        #[cfg(feature = "logging")]
+        debug!("Checking '{}' for changes.", self.directory.display());
+
+        let repository = self.repository();
+        if file_stamps(&self.directory)? == repository.files {
+            return Ok(Vec::<String>::new());
+        }
+        let reloaded = Repository::try_new(&self.directory)?;
+        let changed = repository.changed_components(&reloaded);
+
+        #[cfg(feature = "logging")]
+        debug!(
+            "Reloaded '{}', changed components: {:?}.",
+            self.directory.display(),
+            changed
+        );
+
+        // The query strings depend on the schema version of the files, thus are built again as needed.
+        #[cfg(not(feature = "sync"))]
+        {
+            *self.repository.borrow_mut() = RefCount::new(reloaded);
+            self.queries.borrow_mut().clear();
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            let mut binding = self.repository.lock().unwrap();
+            *binding = RefCount::new(reloaded);
+            self.queries.lock().unwrap().clear();
+        }
+
+        Ok(changed)
+    }
+}
+
+// The state of the directory at the time it was read. The whole repository is replaced when the directory is
+// reloaded, thus the connections and the cached details are always of the same Sqlite3 files.
+struct Repository {
+    components: HashMap<String, (bool, bool)>, // 1st: in all_in_one, 2nd: as own sqlite3 file.
+
+    #[cfg(not(feature = "sync"))]
+    connections: HashMap<String, (RefCount<Connection>, String)>, // ( ..., String) is schema_version
+
+    #[cfg(feature = "sync")]
+    // Due to the Connection containing a RefCell field, instead the path to
+    // the verified database file is stored.
+    connections: HashMap<String, (PathBuf, String)>, // ( ..., String) is schema_version
+
+    files: HashMap<String, FileStamp>, // Key is the file stem of the sqlite3 file.
+
+    // Cached data (long running sql queries)
+    repository_details: OnceMut<RefCount<RepositoryDetails>>,
+    component_details: OnceMut<HashMap<String, RefCount<ComponentDetails>>>,
+}
+
+impl Repository {
+    fn try_new(directory: &Path) -> Result<Self, ProviderSqlite3Error> {
+        // Files are stamped before being opened, thus a file modified while being read is detected on next reload.
+        let files = file_stamps(directory)?;
+        let mut components = HashMap::<String, (bool, bool)>::new();
+
+        #[cfg(not(feature = "sync"))]
+        let mut connections = HashMap::<String, (RefCount<Connection>, String)>::new();
+
+        #[cfg(feature = "sync")]
+        let mut connections = HashMap::<String, (PathBuf, String)>::new();
+
+        let iterator = directory.read_dir()?; // If IO error is returned, usually it is a permission issue.
+        for entry in iterator {
+            let entry_data = entry?; // If IO error is returned, usually it is a permission issue.
+            if let Some(extension) = entry_data.path().extension() {
+                if extension == "sqlite3" {
+                    let path = entry_data.path();
+                    let component = path.file_stem().unwrap().to_str().unwrap().to_string();
+
+                    #[cfg(logging)]
+                    trace!("Sqlite3 file: {}", component);
+
+                    if component.as_str().cmp("__all_in_one__") == Ordering::Equal {
+                        match Connection::open_with_flags(
+                            path.clone(),
+                            OpenFlags::SQLITE_OPEN_READ_ONLY
+                                | OpenFlags::SQLITE_OPEN_NO_MUTEX
+                                | OpenFlags::SQLITE_OPEN_URI,
+                        ) {
+                            Err(_error) => {
+                                #[cfg(feature = "logging")]
+                                error!("Unable to connect to {}: {}.", path.display(), _error);
+                            }
+                            Ok(connection) => {
+                                let schema_version = verify_schema(&connection)?;
+                                {
+                                    let mut statement = connection
+                                        .prepare_cached("SELECT identifier FROM component")?;
+                                    let mut rows = statement.query([])?;
+                                    while let Some(row) = rows.next()? {
+                                        let component: String = row.get(0)?;
+
+                                        #[cfg(logging)]
+                                        trace!(
+                                            "__all_in_one__.sqlite3 has component: {}",
+                                            component
+                                        );
+
+                                        if let std::collections::hash_map::Entry::Vacant(e) =
+                                            components.entry(component.clone())
+                                        {
+                                            e.insert((true, false));
+                                        } else {
+                                            let value = components.get_mut(&component).unwrap();
+                                            value.0 = true;
+                                        }
+                                    }
+                                }
+
+                                #[cfg(not(feature = "sync"))]
+                                connections.insert(
+                                    "__all_in_one__".to_string(),
+                                    (RefCount::new(connection), schema_version),
+                                );
+
+                                #[cfg(feature = "sync")]
+                                connections
+                                    .insert("__all_in_one__".to_string(), (path, schema_version));
+                            }
+                        }
+                    } else {
+                        match Connection::open_with_flags(
+                            path.clone(),
+                            OpenFlags::SQLITE_OPEN_READ_ONLY
+                                | OpenFlags::SQLITE_OPEN_NO_MUTEX
+                                | OpenFlags::SQLITE_OPEN_URI,
+                        ) {
+                            Err(_error) => {
+                                #[cfg(feature = "logging")]
+                                error!("Unable to connect to {}: {}.", path.display(), _error);
+                            }
+                            Ok(connection) => {
+                                let schema_version = verify_schema(&connection)?;
+
+                                #[cfg(logging)]
+                                trace!("Added component: {}", component);
+
+                                if components.contains_key(&component) {
+                                    let value = components.get_mut(&component).unwrap();
+                                    value.1 = true;
+                                } else {
+                                    components.insert(component.clone(), (false, true));
+                                }
+
+                                #[cfg(not(feature = "sync"))]
+                                connections
+                                    .insert(component, (RefCount::new(connection), schema_version));
+
+                                #[cfg(feature = "sync")]
+                                connections.insert(component, (path, schema_version));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if components.is_empty() {
+            #[cfg(feature = "logging")]
+            error!("No sqlite3 files are found in {}.", directory.display());
+
+            return Err(ProviderSqlite3Error::NoSqlite3Files(
+                directory.to_path_buf(),
+            ));
+        }
+        Ok(Repository {
+            components,
+            connections,
+            files,
+            repository_details: OnceMut::new(),
+            component_details: OnceMut::new(),
+        })
+    }
+
+    // The components affected by the sqlite3 files that differ between the repositories.
+    fn changed_components(&self, other: &Repository) -> Vec<String> {
+        let mut changed = Vec::<String>::new();
+        let mut stems = self.files.keys().collect::<Vec<&String>>();
+        stems.extend(
+            other
+                .files
+                .keys()
+                .filter(|stem| !self.files.contains_key(*stem)),
+        );
+        for stem in stems {
+            if self.files.get(stem) == other.files.get(stem) {
+                continue;
+            }
+            if stem.as_str() == "__all_in_one__" {
+                for repository in [self, other] {
+                    for (component, files) in repository.components.iter() {
+                        if files.0 && !changed.contains(component) {
+                            changed.push(component.to_string());
+                        }
+                    }
+                }
+            } else if !changed.contains(stem) {
+                changed.push(stem.to_string());
+            }
+        }
+        changed.sort();
+        changed
+    }
+}
+
+// The stamp of a sqlite3 file, used to detect changed files. As the modification time and length of a file may be
+// unchanged after a transaction, the file change counter of the database header is also compared, which every
+// transaction increments in the rollback journal modes. In the WAL mode the transactions are appended to the `-wal`
+// file instead, whose length increases, and whose salts change when the WAL is restarted from its beginning.
+#[derive(PartialEq, Eq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    length: u64,
+    change_counter: Option<[u8; 4]>,
+    wal: Option<(u64, Option<[u8; 8]>)>,
+}
+
+// Stamp all the sqlite3 files of the directory.
+fn file_stamps(directory: &Path) -> Result<HashMap<String, FileStamp>, ProviderSqlite3Error> {
+    let mut files = HashMap::<String, FileStamp>::new();
+    for entry in directory.read_dir()? {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "sqlite3")
+        {
+            let metadata = path.metadata()?;
+            let wal_path = path.with_extension("sqlite3-wal");
+            let wal = match wal_path.metadata() {
+                Ok(wal_metadata) => Some((wal_metadata.len(), read_bytes::<8>(&wal_path, 16)?)),
+                Err(_) => None,
+            };
+            files.insert(
+                path.file_stem().unwrap().to_str().unwrap().to_string(),
+                FileStamp {
+                    modified: metadata.modified().ok(),
+                    length: metadata.len(),
+                    change_counter: read_bytes::<4>(&path, 24)?,
+                    wal,
+                },
+            );
+        }
+    }
+    Ok(files)
+}
+
+// Read the bytes at the offset of the file, where `None` indicates the file is too short or no longer exists.
+fn read_bytes<const N: usize>(
+    path: &Path,
+    offset: u64,
+) -> Result<Option<[u8; N]>, ProviderSqlite3Error> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+    file.seek(SeekFrom::Start(offset))?;
+    let mut bytes = [0u8; N];
+    match file.read_exact(&mut bytes) {
+        Ok(()) => Ok(Some(bytes)),
+        Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(None),
+        Err(error) => Err(error.into()),
+    }
 }
 
 /// Database schema verification. The schema version is return if successfully verified.
@@ -2193,6 +2603,13 @@ fn query_identifier_languages(schema_version: &str) -> String {
     }
 }
 
+fn query_identifiers(schema_version: &str) -> String {
+    match schema_version {
+        "1.0" => "SELECT DISTINCT identifier FROM pattern WHERE component = ?1".to_string(),
+        &_ => todo!(),
+    }
+}
+
 fn query_contributors(schema_version: &str) -> String {
     match schema_version {
         "1.0" => {
@@ -3,9 +3,10 @@
 
 //! Testing string(s) retrieval and various details`.
 
-use i18n_provider::LocalisationProviderTrait;
+use i18n_provider::{CoverageReport, LocalisationProviderTrait};
 use i18n_provider_sqlite3::LocalisationProviderSqlite3;
 use i18n_utility::LanguageTagRegistry;
+use rusqlite::Connection;
 
 #[cfg(not(feature = "sync"))]
 use std::rc::Rc as RefCount;
@@ -14,7 +15,9 @@ use std::rc::Rc as RefCount;
 #[cfg(target_has_atomic = "ptr")]
 use std::sync::Arc as RefCount;
 
+use std::env::temp_dir;
 use std::error::Error;
+use std::fs::{copy, create_dir_all, metadata, remove_dir_all, remove_file};
 
 #[test] // positive
 fn strings_for_en() -> Result<(), Box<dyn Error>> {
@@ -53,10 +56,10 @@ fn strings_for_en_za_u_ca_julian() -> Result<(), Box<dyn Error>> {
 */
 
 #[test] // negative (private use subtag)
-fn strings_for_qz() -> Result<(), Box<dyn Error>> {
+fn strings_for_qaa() -> Result<(), Box<dyn Error>> {
     let path = "./l10n/";
     let registry = RefCount::new(LanguageTagRegistry::new());
-    let tag = registry.tag("qz")?;
+    let tag = registry.tag("qaa")?;
     let provider = LocalisationProviderSqlite3::try_new(path, &registry, false)?;
     let strings = provider.strings("i18n_provider_sqlite3", "path_conversion", &tag)?;
     assert_eq!(strings.len(), 0, "There should be 0 string.");
@@ -111,7 +114,7 @@ fn identifier_details() -> Result<(), Box<dyn Error>> {
     let provider = LocalisationProviderSqlite3::try_new(path, &registry, false)?;
     let details = provider.identifier_details("application", "example")?;
     assert_eq!(details.default, registry.tag("en-US")?, "Should be en-US.");
-    assert_eq!(details.languages.iter().count(), 2, "Should be 2 languages");
+    assert_eq!(details.languages.len(), 2, "Should be 2 languages");
     Ok(())
 }
 
@@ -122,7 +125,7 @@ fn component_details() -> Result<(), Box<dyn Error>> {
     let provider = LocalisationProviderSqlite3::try_new(path, &registry, false)?;
     let details = provider.component_details("i18n_provider_sqlite3")?;
     assert_eq!(details.default, registry.tag("en-ZA")?, "Should be en-ZA.");
-    assert_eq!(details.languages.iter().count(), 2, "Should be 2 languages");
+    assert_eq!(details.languages.len(), 2, "Should be 2 languages");
     assert_eq!(
         details.total_strings, 26,
         "Should be 26 strings for component"
@@ -141,20 +144,182 @@ fn repository_details() -> Result<(), Box<dyn Error>> {
         &registry.tag("en-US")?,
         "Should be en-US."
     );
-    assert_eq!(details.languages.iter().count(), 3, "Should be 3 languages");
+    assert_eq!(details.languages.len(), 3, "Should be 3 languages");
     assert_eq!(
         details.total_strings, 30,
         "Should be 30 strings for repository"
     );
+    assert_eq!(details.components.len(), 2, "Should be 2 components");
+    assert_eq!(details.contributors.len(), 2, "Should be contributors");
+    Ok(())
+}
+
+#[test]
+fn identifiers() -> Result<(), Box<dyn Error>> {
+    let path = "./l10n/";
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let provider = LocalisationProviderSqlite3::try_new(path, &registry, false)?;
+    let identifiers = provider.identifiers("application")?;
+    assert_eq!(
+        identifiers,
+        vec!["example".to_string(), "example_string".to_string()],
+        "Should be example and example_string."
+    );
+    assert_eq!(
+        provider.identifiers("i18n_provider_sqlite3")?.len(),
+        13,
+        "Should be 13 identifiers"
+    );
+    Ok(())
+}
+
+#[test]
+fn coverage_report() -> Result<(), Box<dyn Error>> {
+    let path = "./l10n/";
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let provider = LocalisationProviderSqlite3::try_new(path, &registry, false)?;
+    let languages = vec![
+        registry.tag("it")?,
+        registry.tag("en-ZA")?,
+        registry.tag("de")?,
+    ];
+    let report = CoverageReport::try_new(&provider, Some(&languages))?;
+    assert_eq!(report.components.len(), 2, "Should be 2 components");
+    let application = &report.components[0];
+    assert_eq!(
+        application.component, "application",
+        "Should be application."
+    );
+    assert_eq!(application.identifiers, 2, "Should be 2 identifiers");
+    assert_eq!(application.languages.len(), 3, "Should be 3 languages");
+
+    // Languages are sorted: de, en-ZA, it.
+    let de = &application.languages[0];
+    assert_eq!(de.missing.len(), 2, "Should be 2 missing for de");
+    let en_za = &application.languages[1];
+    assert_eq!(en_za.fallback.len(), 2, "Should be 2 fallback for en-ZA");
+    assert_eq!(
+        en_za.fallback[0].1,
+        registry.tag("en-US")?,
+        "Should be en-US."
+    );
+    let it = &application.languages[2];
+    assert_eq!(it.translated.len(), 2, "Should be 2 translated for it");
+    assert!(it.identical.is_empty(), "Should be no identical for it");
+    assert!(
+        report.to_json().starts_with(
+            "{\"components\":[{\"component\":\"application\",\"default\":\"en-US\",\"identifiers\":2,"
+        ),
+        "Not correct JSON."
+    );
+    let csv = report.to_csv();
+    assert!(
+        csv.contains("application,en-ZA,fallback,example,en-US\r\n"),
+        "Not correct CSV."
+    );
+    assert!(
+        csv.contains("i18n_provider_sqlite3,de,missing,schema_version,\r\n"),
+        "Not correct CSV."
+    );
+    Ok(())
+}
+
+#[test]
+fn reload() -> Result<(), Box<dyn Error>> {
+    let directory = temp_dir().join(format!("i18n_provider_sqlite3_{}", std::process::id()));
+    create_dir_all(&directory)?;
+    for file in ["application", "i18n_provider_sqlite3"] {
+        copy(
+            format!("./l10n/{}.sqlite3", file),
+            directory.join(format!("{}.sqlite3", file)),
+        )?;
+    }
+    let registry = RefCount::new(LanguageTagRegistry::new());
+    let tag = registry.tag("en-ZA")?;
+    let provider = LocalisationProviderSqlite3::try_new(directory.as_path(), &registry, false)?;
+    let unchanged = provider.reload()?;
+    let before = provider.string("i18n_provider_sqlite3", "path_conversion", &tag)?;
+
+    // Modify the component's strings in place, where the file length is unchanged.
+    let path = directory.join("i18n_provider_sqlite3.sqlite3");
+    let length = metadata(&path)?.len();
+    {
+        let connection = Connection::open(&path)?;
+        connection.execute(
+            "UPDATE pattern SET string = 'Conversion to {`PathBuf`} fails.' WHERE identifier = 'path_conversion' \
+            AND languageTag = 'en-ZA'",
+            [],
+        )?;
+        connection.execute(
+            "INSERT INTO pattern (component, identifier, languageTag, string) VALUES ('i18n_provider_sqlite3', \
+            'reloaded', 'en-ZA', 'Reloaded.')",
+            [],
+        )?;
+    }
+    let same_length = metadata(&path)?.len() == length;
+    let changed = provider.reload()?;
+    let after = provider.string("i18n_provider_sqlite3", "path_conversion", &tag)?;
+    let identifiers = provider.identifiers("i18n_provider_sqlite3")?;
+
+    // In the WAL mode the transactions of an open connection are only in the `-wal` file.
+    let connection = Connection::open(&path)?;
+    connection.query_row("PRAGMA journal_mode = WAL", [], |_row| Ok(()))?;
+    let wal_mode = provider.reload()?;
+    connection.execute(
+        "UPDATE pattern SET string = 'Conversion to {`PathBuf`} faild.' WHERE identifier = 'path_conversion' \
+        AND languageTag = 'en-ZA'",
+        [],
+    )?;
+    let wal_changed = provider.reload()?;
+    let wal_after = provider.string("i18n_provider_sqlite3", "path_conversion", &tag)?;
+    let wal_unchanged = provider.reload()?;
+    drop(connection);
+
+    // Reloading fails when the directory has no sqlite3 files.
+    remove_file(directory.join("application.sqlite3"))?;
+    remove_file(directory.join("i18n_provider_sqlite3.sqlite3"))?;
+    let empty = provider.reload();
+    remove_dir_all(&directory)?;
+    assert!(unchanged.is_empty(), "Should be no changes.");
     assert_eq!(
-        details.components.iter().count(),
-        2,
-        "Should be 2 components"
+        before.unwrap().0.as_str(),
+        "Conversion to {`PathBuf`} error.",
+        "Not correct string."
+    );
+    assert!(same_length, "Should be the same file length.");
+    assert_eq!(
+        changed,
+        vec!["i18n_provider_sqlite3".to_string()],
+        "Should be changed."
+    );
+    assert_eq!(
+        after.unwrap().0.as_str(),
+        "Conversion to {`PathBuf`} fails.",
+        "Not reloaded string."
     );
     assert_eq!(
-        details.contributors.iter().count(),
-        2,
-        "Should be contributors"
+        wal_mode,
+        vec!["i18n_provider_sqlite3".to_string()],
+        "Should be changed to WAL mode."
+    );
+    assert_eq!(
+        wal_changed,
+        vec!["i18n_provider_sqlite3".to_string()],
+        "Should be changed in WAL mode."
+    );
+    assert_eq!(
+        wal_after.unwrap().0.as_str(),
+        "Conversion to {`PathBuf`} faild.",
+        "Not reloaded string of WAL mode."
+    );
+    assert!(
+        wal_unchanged.is_empty(),
+        "Should be no changes in WAL mode."
+    );
+    assert!(
+        identifiers.contains(&"reloaded".to_string()),
+        "Should have reloaded."
     );
+    assert!(empty.is_err(), "Should fail without sqlite3 files.");
     Ok(())
 }
@@ -0,0 +1,264 @@
+// This file is part of `i18n_utility-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_utility-rizzen-yazston` crate.
+
+use crate::{LanguageTag, LanguageTagRegistry, RegistryError};
+
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as RefCount;
+
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::Arc as RefCount;
+
+#[cfg(doc)]
+use std::sync::Arc;
+
+#[cfg(doc)]
+use std::rc::Rc;
+
+/// Parses the value of a HTTP `Accept-Language` header ([RFC 9110]), and returns the language tags ordered by their
+/// quality values (the `q` weight), highest first. Language tags of equal weight retain the order of the header.
+///
+/// The language tags are canonicalised through the [`LanguageTagRegistry`], thus are [`Rc`]`<`[`LanguageTag`]`>` or
+/// [`Arc`]`<LanguageTag>` (using feature `sync`). Duplicate language tags (after canonicalisation) are only included
+/// once with the highest weight, and language tags with a weight of `0` (not acceptable) are excluded. The wildcard
+/// `*` is excluded, as it matches any language not already listed, thus is simply the application's default language.
+///
+/// When `strict` is `true`, a malformed entry results in the error [`RegistryError::AcceptLanguage`] containing the
+/// position (starting at 1) of the entry in the header, or the registry's error for a language tag that is not
+/// well-formed. When `strict` is `false`, malformed entries are skipped, unknown parameters are ignored, and weights
+/// outside the range `0` to `1` are clamped. Weights that are not finite numbers (such as `NaN` and `inf`) are always
+/// malformed.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_utility::{accept_language, LanguageTagRegistry};
+///
+/// let registry = LanguageTagRegistry::new();
+/// let tags = accept_language( &registry, "fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5", true )
+///     .expect( "Failed to parse header." );
+/// let tags = tags.iter().map( |tag| tag.as_str() ).collect::<Vec<&str>>();
+/// assert_eq!( tags, vec![ "fr-CH", "fr", "en", "de" ], "Check order of language tags." );
+/// ```
+///
+/// [RFC 9110]: https://www.rfc-editor.org/rfc/rfc9110#name-accept-language
+pub fn accept_language(
+    registry: &LanguageTagRegistry,
+    header: &str,
+    strict: bool,
+) -> Result<Vec<RefCount<LanguageTag>>, RegistryError> {
+    Ok(accept_language_weighted(registry, header, strict)?
+        .into_iter()
+        .map(|(tag, _)| tag)
+        .collect())
+}
+
+/// Similar to [`accept_language()`], except the weights are included with the language tags. The result can be
+/// passed directly to the `negotiate()` method of the `Localiser` in the `i18n_localiser` crate.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_utility::{accept_language_weighted, LanguageTagRegistry};
+///
+/// let registry = LanguageTagRegistry::new();
+/// let tags = accept_language_weighted( &registry, "en;q=0.5, it, xx_;q=0.7, de;q=0", false )
+///     .expect( "Failed to parse header." );
+/// assert_eq!( tags.len(), 2, "Check malformed and unacceptable entries are excluded." );
+/// assert_eq!( tags[ 0 ].0.as_str(), "it", "Check first language tag." );
+/// assert_eq!( tags[ 1 ].1, 0.5, "Check weight of second language tag." );
+/// ```
+pub fn accept_language_weighted(
+    registry: &LanguageTagRegistry,
+    header: &str,
+    strict: bool,
+) -> Result<Vec<(RefCount<LanguageTag>, f32)>, RegistryError> {
+    let mut tags = Vec::<(RefCount<LanguageTag>, f32)>::new();
+    for (index, entry) in header.split(',').enumerate() {
+        let entry = entry.trim();
+
+        // Empty list elements are permitted by the list syntax of RFC 9110.
+        if entry.is_empty() {
+            continue;
+        }
+        let Some((range, weight)) = parse_entry(entry, strict) else {
+            if strict {
+                return Err(RegistryError::AcceptLanguage(index + 1));
+            }
+            continue;
+        };
+        if range == "*" || weight <= 0.0 {
+            continue;
+        }
+        let tag = match registry.tag(range) {
+            Ok(tag) => tag,
+            Err(error) => {
+                if strict {
+                    return Err(error);
+                }
+                continue;
+            }
+        };
+        match tags.iter_mut().find(|(existing, _)| *existing == tag) {
+            Some(existing) => existing.1 = existing.1.max(weight),
+            None => tags.push((tag, weight)),
+        }
+    }
+
+    // Stable sort, retaining the header order for equal weights.
+    tags.sort_by(|a, b| b.1.total_cmp(&a.1));
+    Ok(tags)
+}
+
+// Internal functions.
+
+// Returns the language range and the weight, or `None` if the entry is malformed.
+fn parse_entry(entry: &str, strict: bool) -> Option<(&str, f32)> {
+    let mut parts = entry.split(';');
+    let range = parts.next()?.trim();
+    if !is_language_range(range, strict) {
+        return None;
+    }
+    let mut weight = 1.0f32;
+    for parameter in parts {
+        let parameter = parameter.trim();
+        let (name, value) = match parameter.split_once('=') {
+            Some((name, value)) => (name.trim(), value.trim()),
+            None => (parameter, ""),
+        };
+        if !name.eq_ignore_ascii_case("q") {
+            if strict {
+                return None;
+            }
+            continue;
+        }
+        weight = match strict {
+            true => parse_strict_weight(value)?,
+            false => parse_lenient_weight(value)?,
+        };
+    }
+    Some((range, weight))
+}
+
+// `language-range = (1*8ALPHA *("-" 1*8alphanum)) / "*"`, leniently also allowing `_` as a separator.
+fn is_language_range(range: &str, strict: bool) -> bool {
+    if range == "*" {
+        return true;
+    }
+    let mut subtags = match strict {
+        true => range.split('-').collect::<Vec<&str>>(),
+        false => range.split(['-', '_']).collect::<Vec<&str>>(),
+    };
+    let first = subtags.remove(0);
+    (1..=8).contains(&first.len())
+        && first.bytes().all(|byte| byte.is_ascii_alphabetic())
+        && subtags.iter().all(|subtag| {
+            (1..=8).contains(&subtag.len())
+                && subtag.bytes().all(|byte| byte.is_ascii_alphanumeric())
+        })
+}
+
+// Any finite number, clamped to the range `0` to `1`. The non-finite `NaN` and `inf` are malformed.
+fn parse_lenient_weight(value: &str) -> Option<f32> {
+    let weight = value.parse::<f32>().ok()?;
+    match weight.is_finite() {
+        true => Some(weight.clamp(0.0, 1.0)),
+        false => None,
+    }
+}
+
+// `qvalue = ( "0" [ "." 0*3DIGIT ] ) / ( "1" [ "." 0*3("0") ] )`
+fn parse_strict_weight(value: &str) -> Option<f32> {
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if fraction.len() > 3 || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    match integer {
+        "0" => value.parse::<f32>().ok(),
+        "1" if fraction.bytes().all(|byte| byte == b'0') => Some(1.0),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn ordering() -> Result<(), Box<dyn Error>> {
+        let registry = LanguageTagRegistry::new();
+        let tags = accept_language(&registry, "de;q=0.7, en-GB;q=0.8, fr, it;q=0.8", true)?;
+        let tags = tags.iter().map(|tag| tag.as_str()).collect::<Vec<&str>>();
+        assert_eq!(tags, vec!["fr", "en-GB", "it", "de"], "Check ordering.");
+        Ok(())
+    }
+
+    #[test]
+    fn duplicates() -> Result<(), Box<dyn Error>> {
+        let registry = LanguageTagRegistry::new();
+        let tags = accept_language_weighted(&registry, "en-us;q=0.2, en-US;q=0.9, en", true)?;
+        assert_eq!(tags.len(), 2, "Check duplicates removed.");
+        assert_eq!(tags[1].0.as_str(), "en-US", "Check canonicalised.");
+        assert_eq!(tags[1].1, 0.9, "Check highest weight kept.");
+        Ok(())
+    }
+
+    #[test]
+    fn strict() -> Result<(), Box<dyn Error>> {
+        let registry = LanguageTagRegistry::new();
+        for (header, position) in [
+            ("en, fr;q=1.5", 2),
+            ("en;q=0.1234", 1),
+            ("en;level=1", 1),
+            ("en, , en_ZA", 3),
+            ("123", 1),
+        ] {
+            match accept_language(&registry, header, true) {
+                Err(RegistryError::AcceptLanguage(found)) => {
+                    assert_eq!(found, position, "Check position for ‘{}’.", header)
+                }
+                _ => panic!("Must fail as ‘{}’ is malformed.", header),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn lenient() -> Result<(), Box<dyn Error>> {
+        let registry = LanguageTagRegistry::new();
+        let tags = accept_language_weighted(
+            &registry,
+            "en_ZA;level=1, fr;q=1.5, de;q=abc, 123, *, it;q=0.00",
+            false,
+        )?;
+        let tags = tags
+            .iter()
+            .map(|(tag, weight)| (tag.as_str(), *weight))
+            .collect::<Vec<(&str, f32)>>();
+        assert_eq!(tags, vec![("en-ZA", 1.0), ("fr", 1.0)], "Check lenient.");
+        Ok(())
+    }
+
+    #[test]
+    fn non_finite() -> Result<(), Box<dyn Error>> {
+        let registry = LanguageTagRegistry::new();
+        let tags = accept_language_weighted(
+            &registry,
+            "en;q=0.5, fr;q=NaN, de;q=inf, it;q=-infinity",
+            false,
+        )?;
+        let tags = tags
+            .iter()
+            .map(|(tag, weight)| (tag.as_str(), *weight))
+            .collect::<Vec<(&str, f32)>>();
+        assert_eq!(tags, vec![("en", 0.5)], "Check non-finite weights skipped.");
+        let result = accept_language(&registry, "en, fr;q=NaN", true);
+        assert!(
+            matches!(result, Err(RegistryError::AcceptLanguage(2))),
+            "Check non-finite weight is malformed."
+        );
+        Ok(())
+    }
+}
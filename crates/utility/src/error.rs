@@ -5,6 +5,7 @@
 
 use crate::{LocalisationData, LocalisationErrorTrait, LocalisationTrait, PlaceholderValue};
 use core::fmt::{Display, Formatter, Result};
+use icu_locid::subtags::{Language, Region, Script};
 use icu_locid::ParserError;
 use std::{
     collections::HashMap,
@@ -15,16 +16,51 @@ use std::{
 ///
 /// * `LanguageIdentifier`: Wraps the ICU's [`ParserError`],
 ///
-/// else if cargo feature `extend` is enabled:
+/// else if cargo feature `icu_extended` is enabled:
 ///
-/// * `Locale`: Wraps the ICU's `ParserError`.
+/// * `Locale`: Wraps the ICU's `ParserError`,
+///
+/// * `AcceptLanguage`: Indicates the entry at the position (starting at 1) of the `Accept-Language` header is
+///   malformed,
+///
+/// * `Invalid`: Indicates the language tag is well-formed, though contains a subtag that is not valid.
 #[derive(Debug, PartialEq, Copy, Clone)]
 #[non_exhaustive]
 pub enum RegistryError {
-    #[cfg(not(feature = "extend"))]
+    #[cfg(not(feature = "icu_extended"))]
     LanguageIdentifier(ParserError),
-    #[cfg(feature = "extend")]
+    #[cfg(feature = "icu_extended")]
     Locale(ParserError),
+    AcceptLanguage(usize),
+    Invalid(InvalidSubtag),
+}
+
+/// The subtag of a language tag that is not valid, as reported by [`RegistryError::Invalid`].
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum InvalidSubtag {
+    Language(Language),
+    Script(Script),
+    Region(Region),
+}
+
+impl Display for InvalidSubtag {
+    fn fmt(&self, formatter: &mut Formatter) -> Result {
+        match self {
+            InvalidSubtag::Language(ref language) => {
+                write!(
+                    formatter,
+                    "The language subtag ‘{}’ is not valid.",
+                    language
+                )
+            }
+            InvalidSubtag::Script(ref script) => {
+                write!(formatter, "The script subtag ‘{}’ is not valid.", script)
+            }
+            InvalidSubtag::Region(ref region) => {
+                write!(formatter, "The region subtag ‘{}’ is not valid.", region)
+            }
+        }
+    }
 }
 
 impl LocalisationErrorTrait for RegistryError {}
@@ -33,7 +69,7 @@ impl LocalisationTrait for RegistryError {
     fn localisation_data(&self) -> LocalisationData {
         let type_string = PlaceholderValue::String("IcuError".to_string());
         match self {
-            #[cfg(not(feature = "extend"))]
+            #[cfg(not(feature = "icu_extended"))]
             RegistryError::LanguageIdentifier(error) => {
                 // Currently no localisation is available for this error type: ParserError.
                 let mut values = HashMap::<String, PlaceholderValue>::new();
@@ -53,7 +89,7 @@ impl LocalisationTrait for RegistryError {
                 }
             }
 
-            #[cfg(feature = "extend")]
+            #[cfg(feature = "icu_extended")]
             RegistryError::Locale(error) => {
                 // Currently no localisation is available for this error type: ParserError.
                 let mut values = HashMap::<String, PlaceholderValue>::new();
@@ -72,6 +108,45 @@ impl LocalisationTrait for RegistryError {
                     values: Some(values),
                 }
             }
+            RegistryError::AcceptLanguage(position) => {
+                // Currently no localisation is available for this error.
+                let mut values = HashMap::<String, PlaceholderValue>::new();
+                values.insert("type".to_string(), type_string);
+                values.insert(
+                    "variant".to_string(),
+                    PlaceholderValue::String("AcceptLanguage".to_string()),
+                );
+                values.insert(
+                    "error".to_string(),
+                    PlaceholderValue::String(format!(
+                        "The entry {} of the Accept-Language header is malformed.",
+                        position
+                    )),
+                );
+                LocalisationData {
+                    component: "i18n_localiser".to_string(),
+                    identifier: "error_format_enum_embedded".to_string(),
+                    values: Some(values),
+                }
+            }
+            RegistryError::Invalid(subtag) => {
+                // Currently no localisation is available for this error.
+                let mut values = HashMap::<String, PlaceholderValue>::new();
+                values.insert("type".to_string(), type_string);
+                values.insert(
+                    "variant".to_string(),
+                    PlaceholderValue::String("Invalid".to_string()),
+                );
+                values.insert(
+                    "error".to_string(),
+                    PlaceholderValue::String(subtag.to_string()),
+                );
+                LocalisationData {
+                    component: "i18n_localiser".to_string(),
+                    identifier: "error_format_enum_embedded".to_string(),
+                    values: Some(values),
+                }
+            }
         }
     }
 }
@@ -79,15 +154,23 @@ impl LocalisationTrait for RegistryError {
 impl Display for RegistryError {
     fn fmt(&self, formatter: &mut Formatter) -> Result {
         match self {
-            #[cfg(not(feature = "extend"))]
+            #[cfg(not(feature = "icu_extended"))]
             RegistryError::LanguageIdentifier(ref error) => {
                 write!(formatter, "RegistryError::LanguageIdentifier: [{}].", error)
             }
 
-            #[cfg(feature = "extend")]
+            #[cfg(feature = "icu_extended")]
             RegistryError::Locale(ref error) => {
                 write!(formatter, "RegistryError::Locale: [{}].", error)
             }
+            RegistryError::AcceptLanguage(position) => write!(
+                formatter,
+                "RegistryError::AcceptLanguage: The entry {} of the Accept-Language header is malformed.",
+                position
+            ),
+            RegistryError::Invalid(ref subtag) => {
+                write!(formatter, "RegistryError::Invalid: {}", subtag)
+            }
         }
     }
 }
@@ -96,12 +179,12 @@ impl Display for RegistryError {
 impl Error for RegistryError {}
 
 impl From<ParserError> for RegistryError {
-    #[cfg(not(feature = "extend"))]
+    #[cfg(not(feature = "icu_extended"))]
     fn from(error: ParserError) -> RegistryError {
         RegistryError::LanguageIdentifier(error)
     }
 
-    #[cfg(feature = "extend")]
+    #[cfg(feature = "icu_extended")]
     fn from(error: ParserError) -> RegistryError {
         RegistryError::Locale(error)
     }
@@ -0,0 +1,32 @@
+// This file is part of `i18n_utility-rizzen-yazston` crate. For the terms of use, please see the file
+// called `LICENSE-BSD-3-Clause` at the top level of the `i18n_utility-rizzen-yazston` crate.
+
+/// Returns the string as a quoted JSON string ([RFC 8259]), escaping the quotation mark, the reverse solidus and the
+/// control characters.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_utility::json_string;
+///
+/// assert_eq!( json_string( "A \"quoted\"\tstring." ), "\"A \\\"quoted\\\"\\tstring.\"", "Check escaping." );
+/// ```
+///
+/// [RFC 8259]: https://www.rfc-editor.org/rfc/rfc8259#section-7
+pub fn json_string(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len() + 2);
+    escaped.push('"');
+    for character in string.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(format!("\\u{:04x}", c as u32).as_str()),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
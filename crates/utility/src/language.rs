@@ -5,10 +5,10 @@
 
 use crate::RegistryError;
 
-#[cfg(not(feature = "extend"))]
+#[cfg(not(feature = "icu_extended"))]
 use icu_locid::LanguageIdentifier as IcuLanguage;
 
-#[cfg(feature = "extend")]
+#[cfg(feature = "icu_extended")]
 use icu_locid::Locale as IcuLanguage;
 
 #[cfg(not(feature = "sync"))]
@@ -24,6 +24,15 @@ use std::sync::{Arc as RefCount, Mutex as MutCell};
 use std::collections::HashMap;
 use std::iter::FromIterator;
 
+#[cfg(feature = "icu_compiled_data")]
+use crate::{languages::LANGUAGES, InvalidSubtag};
+
+#[cfg(feature = "icu_compiled_data")]
+use icu_locid::Locale as IcuLocale;
+
+#[cfg(feature = "icu_compiled_data")]
+use icu_locid_transform::{LocaleCanonicalizer, LocaleExpander};
+
 #[cfg(doc)]
 use icu_locid::LanguageIdentifier;
 
@@ -43,9 +52,10 @@ use std::cell::RefCell;
 /// If the cargo feature `icu_extended` is enabled, the [`Locale::canonicalize()`] method will
 /// be used instead.
 ///
-/// Currently the `canonicalize()` method (internally the `try_from_bytes()` method) only checks
-/// that the language tag is __well-formed__. In future the method will also do the __valid__
-/// and the __canonical__ checks.
+/// The `canonicalize()` method (internally the `try_from_bytes()` method) only checks that the
+/// language tag is __well-formed__. When the feature `icu_compiled_data` is enabled,
+/// the [`LanguageTagRegistry`] also replaces the aliases (such as `iw` to `he`, and the
+/// deprecated region `SU` to `RU`), and checks that the language tag is __valid__.
 ///
 /// [BCP 47 Language Tag]: https://www.rfc-editor.org/rfc/bcp/bcp47.txt
 /// [`LanguageIdentifier::canonicalize()`]: icu_locid::LanguageIdentifier::try_from_bytes()
@@ -113,6 +123,19 @@ pub struct LanguageTagRegistry {
 
     // The optional cache of `LanguageIdentifier` or `Locale` instances.
     icu: MutCell<HashMap<RefCount<LanguageTag>, RefCount<IcuLanguage>>>,
+
+    // The caches of the maximised and minimised language tags.
+    #[cfg(feature = "icu_compiled_data")]
+    maximised: MutCell<HashMap<RefCount<LanguageTag>, RefCount<LanguageTag>>>,
+
+    #[cfg(feature = "icu_compiled_data")]
+    minimised: MutCell<HashMap<RefCount<LanguageTag>, RefCount<LanguageTag>>>,
+
+    #[cfg(feature = "icu_compiled_data")]
+    expander: LocaleExpander,
+
+    #[cfg(feature = "icu_compiled_data")]
+    canonicalizer: LocaleCanonicalizer,
 }
 
 impl LanguageTagRegistry {
@@ -122,6 +145,18 @@ impl LanguageTagRegistry {
             deprecated: MutCell::new(HashMap::<String, RefCount<LanguageTag>>::new()),
 
             icu: MutCell::new(HashMap::<RefCount<LanguageTag>, RefCount<IcuLanguage>>::new()),
+
+            #[cfg(feature = "icu_compiled_data")]
+            maximised: MutCell::new(HashMap::<RefCount<LanguageTag>, RefCount<LanguageTag>>::new()),
+
+            #[cfg(feature = "icu_compiled_data")]
+            minimised: MutCell::new(HashMap::<RefCount<LanguageTag>, RefCount<LanguageTag>>::new()),
+
+            #[cfg(feature = "icu_compiled_data")]
+            expander: LocaleExpander::new_extended(),
+
+            #[cfg(feature = "icu_compiled_data")]
+            canonicalizer: LocaleCanonicalizer::new(),
         }
     }
 
@@ -131,6 +166,17 @@ impl LanguageTagRegistry {
     /// An error will be returned if the querying tag is malformed, that is does not conform
     /// to the [BCP 47 Language Tag] specification for being _Well-formed_.
     ///
+    /// When the feature `icu_compiled_data` is enabled, the aliases of the CLDR data
+    /// are replaced (such as `iw` to `he`, `in` to `id`, and the deprecated regions `SU` to
+    /// `RU` and `BU` to `MM`), and the error [`RegistryError::Invalid`] is returned if the
+    /// language, script or region subtag is not valid. The language subtag must be a language
+    /// of the IANA Language Subtag Registry (the ISO 639-1, ISO 639-3 and ISO 639-5 codes,
+    /// such as `tlh` and `sux`) or a private use language `qaa` to `qtz`. The script subtag
+    /// must be an ISO 15924 script (including private use scripts `Qaaa` to `Qabx`), and the
+    /// region subtag must be either an ISO 3166-1 region (including the private use regions
+    /// `AA`, `QM` to `QZ`, `XA` to `XZ` and `ZZ`) or a numeric UN M.49 region.
+    /// Variant subtags and extensions are not checked.
+    ///
     /// However deprecated tags (containing deprecated subtag(s) and/or the deprecated
     /// underscore (_)) may still produce a valid BCP 47 Language Tag during the ICU4X locale
     /// canonicalise process of the querying tag. Thus will be stored in the registry with both
@@ -180,6 +226,9 @@ impl LanguageTagRegistry {
         // Requested tag is not in registry, validate the supplied tag.
         let new_tag = IcuLanguage::canonicalize(language_tag)?;
 
+        #[cfg(feature = "icu_compiled_data")]
+        let new_tag = replace_aliases_and_validate(&self.canonicalizer, new_tag.as_str())?;
+
         // Check if requested tag is just another deprecated tag of already registered tag.
         {
             #[cfg(not(feature = "sync"))]
@@ -285,6 +334,121 @@ impl LanguageTagRegistry {
         icu_language
     }
 
+    /// Obtain the maximised language tag, by adding the likely subtags using the ICU4X
+    /// [`LocaleExpander`] (for example `en` becomes `en-Latn-US`, and `zh-TW` becomes
+    /// `zh-Hant-TW`). The language tag is returned unchanged if there are no likely subtags.
+    ///
+    /// The maximised language tag is registered and cached, thus subsequent requests for the
+    /// language tag are simply a look up. Comparing maximised language tags allows for matching
+    /// strings of equivalent language tags, such as `zh-TW` and `zh-Hant`.
+    ///
+    /// Requires the feature `icu_compiled_data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_utility::LanguageTagRegistry;
+    ///
+    /// let registry = LanguageTagRegistry::new();
+    /// let tag = registry.tag( "en" ).expect( "Failed to parse language tag." );
+    /// let maximised = registry.maximize( &tag ).expect( "Failed to maximise language tag." );
+    /// assert_eq!( maximised.as_str(), "en-Latn-US", "Check maximised language tag." );
+    ///
+    /// let traditional = registry.tag( "zh-Hant" ).expect( "Failed to parse language tag." );
+    /// let taiwan = registry.tag( "zh-TW" ).expect( "Failed to parse language tag." );
+    /// assert_eq!(
+    ///     registry.maximize( &traditional ).expect( "Failed to maximise language tag." ),
+    ///     registry.maximize( &taiwan ).expect( "Failed to maximise language tag." ),
+    ///     "Check equivalent language tags."
+    /// );
+    /// ```
+    ///
+    /// [`LocaleExpander`]: icu_locid_transform::LocaleExpander
+    #[cfg(feature = "icu_compiled_data")]
+    pub fn maximize(
+        &self,
+        language_tag: &RefCount<LanguageTag>,
+    ) -> Result<RefCount<LanguageTag>, RegistryError> {
+        #[cfg(not(feature = "sync"))]
+        if let Some(result) = self.maximised.borrow().get(language_tag) {
+            return Ok(RefCount::clone(result));
+        }
+
+        #[cfg(feature = "sync")]
+        if let Some(result) = self.maximised.lock().unwrap().get(language_tag) {
+            return Ok(RefCount::clone(result));
+        }
+
+        let mut icu_language = language_tag.icu_language();
+        self.expander.maximize(&mut icu_language);
+        let maximised = self.tag(icu_language.to_string().as_str())?;
+
+        #[cfg(not(feature = "sync"))]
+        self.maximised
+            .borrow_mut()
+            .insert(RefCount::clone(language_tag), RefCount::clone(&maximised));
+
+        #[cfg(feature = "sync")]
+        self.maximised
+            .lock()
+            .unwrap()
+            .insert(RefCount::clone(language_tag), RefCount::clone(&maximised));
+
+        Ok(maximised)
+    }
+
+    /// Obtain the minimised language tag, by removing the subtags that would be added by
+    /// [`maximize()`](Self::maximize) (for example `en-Latn-US` becomes `en`, and `zh-Hant`
+    /// becomes `zh-TW`).
+    ///
+    /// The minimised language tag is registered and cached, thus subsequent requests for the
+    /// language tag are simply a look up.
+    ///
+    /// Requires the feature `icu_compiled_data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use i18n_utility::LanguageTagRegistry;
+    ///
+    /// let registry = LanguageTagRegistry::new();
+    /// let tag = registry.tag( "zh-Hant" ).expect( "Failed to parse language tag." );
+    /// let minimised = registry.minimize( &tag ).expect( "Failed to minimise language tag." );
+    /// assert_eq!( minimised.as_str(), "zh-TW", "Check minimised language tag." );
+    /// ```
+    #[cfg(feature = "icu_compiled_data")]
+    pub fn minimize(
+        &self,
+        language_tag: &RefCount<LanguageTag>,
+    ) -> Result<RefCount<LanguageTag>, RegistryError> {
+        #[cfg(not(feature = "sync"))]
+        if let Some(result) = self.minimised.borrow().get(language_tag) {
+            return Ok(RefCount::clone(result));
+        }
+
+        #[cfg(feature = "sync")]
+        if let Some(result) = self.minimised.lock().unwrap().get(language_tag) {
+            return Ok(RefCount::clone(result));
+        }
+
+        let mut icu_language = language_tag.icu_language();
+        self.expander.minimize(&mut icu_language);
+        let minimised = self.tag(icu_language.to_string().as_str())?;
+
+        #[cfg(not(feature = "sync"))]
+        self.minimised
+            .borrow_mut()
+            .insert(RefCount::clone(language_tag), RefCount::clone(&minimised));
+
+        #[cfg(feature = "sync")]
+        self.minimised
+            .lock()
+            .unwrap()
+            .insert(RefCount::clone(language_tag), RefCount::clone(&minimised));
+
+        Ok(minimised)
+    }
+
     /// Returns a vector list of all the registered language tags of the [BCP 47 Language Tag]
     /// specification.
     ///
@@ -402,7 +566,108 @@ impl Default for LanguageTagRegistry {
     }
 }
 
+// Internal functions.
+
+// Replaces the aliases of the well-formed language tag, and checks the language, script and region subtags are valid.
+#[cfg(feature = "icu_compiled_data")]
+fn replace_aliases_and_validate(
+    canonicalizer: &LocaleCanonicalizer,
+    tag: &str,
+) -> Result<String, RegistryError> {
+    let mut locale = IcuLocale::try_from_bytes(tag.as_bytes())?;
+    canonicalizer.canonicalize(&mut locale);
+    let language = locale.id.language;
+    let code = language.as_str();
+    let valid = language.is_empty()
+        || (code.len() == 3 && ("qaa"..="qtz").contains(&code))
+        || LANGUAGES.binary_search(&code).is_ok();
+    if !valid {
+        return Err(RegistryError::Invalid(InvalidSubtag::Language(language)));
+    }
+    if let Some(script) = locale.id.script {
+        if !("Qaaa"..="Qabx").contains(&script.as_str())
+            && SCRIPTS.binary_search(&script.as_str()).is_err()
+        {
+            return Err(RegistryError::Invalid(InvalidSubtag::Script(script)));
+        }
+    }
+    if let Some(region) = locale.id.region {
+        let code = region.as_str();
+        if region.is_alphabetic()
+            && !("QM"..="QZ").contains(&code)
+            && !("XA"..="XZ").contains(&code)
+            && REGIONS.binary_search(&code).is_err()
+        {
+            return Err(RegistryError::Invalid(InvalidSubtag::Region(region)));
+        }
+    }
+    Ok(canonical_string(&locale))
+}
+
+// ISO 15924 script codes, excluding the private use range `Qaaa`..`Qabx`. Must remain sorted.
+#[cfg(feature = "icu_compiled_data")]
+#[rustfmt::skip]
+const SCRIPTS: &[&str] = &[
+    "Adlm", "Afak", "Aghb", "Ahom", "Arab", "Aran", "Armi", "Armn", "Avst", "Bali", "Bamu", "Bass", "Batk", "Beng",
+    "Bhks", "Blis", "Bopo", "Brah", "Brai", "Bugi", "Buhd", "Cakm", "Cans", "Cari", "Cham", "Cher", "Chis", "Chrs",
+    "Cirt", "Copt", "Cpmn", "Cprt", "Cyrl", "Cyrs", "Deva", "Diak", "Dogr", "Dsrt", "Dupl", "Egyd", "Egyh", "Egyp",
+    "Elba", "Elym", "Ethi", "Gara", "Geok", "Geor", "Glag", "Gong", "Gonm", "Goth", "Gran", "Grek", "Gujr", "Gukh",
+    "Guru", "Hanb", "Hang", "Hani", "Hano", "Hans", "Hant", "Hatr", "Hebr", "Hira", "Hluw", "Hmng", "Hmnp", "Hrkt",
+    "Hung", "Inds", "Ital", "Jamo", "Java", "Jpan", "Jurc", "Kali", "Kana", "Kawi", "Khar", "Khmr", "Khoj", "Kitl",
+    "Kits", "Knda", "Kore", "Kpel", "Krai", "Kthi", "Lana", "Laoo", "Latf", "Latg", "Latn", "Leke", "Lepc", "Limb",
+    "Lina", "Linb", "Lisu", "Loma", "Lyci", "Lydi", "Mahj", "Maka", "Mand", "Mani", "Marc", "Maya", "Medf", "Mend",
+    "Merc", "Mero", "Mlym", "Modi", "Mong", "Moon", "Mroo", "Mtei", "Mult", "Mymr", "Nagm", "Nand", "Narb", "Nbat",
+    "Newa", "Nkdb", "Nkgb", "Nkoo", "Nshu", "Ogam", "Olck", "Onao", "Orkh", "Orya", "Osge", "Osma", "Ougr", "Palm",
+    "Pauc", "Pcun", "Pelm", "Perm", "Phag", "Phli", "Phlp", "Phlv", "Phnx", "Piqd", "Plrd", "Prti", "Psin", "Ranj",
+    "Rjng", "Rohg", "Roro", "Runr", "Samr", "Sara", "Sarb", "Saur", "Sgnw", "Shaw", "Shrd", "Shui", "Sidd", "Sidt",
+    "Sind", "Sinh", "Sogd", "Sogo", "Sora", "Soyo", "Sund", "Sunu", "Sylo", "Syrc", "Syre", "Syrj", "Syrn", "Tagb",
+    "Takr", "Tale", "Talu", "Taml", "Tang", "Tavt", "Tayo", "Telu", "Teng", "Tfng", "Tglg", "Thaa", "Thai", "Tibt",
+    "Tirh", "Tnsa", "Todr", "Tols", "Toto", "Tutg", "Ugar", "Vaii", "Visp", "Vith", "Wara", "Wcho", "Wole", "Xpeo",
+    "Xsux", "Yezi", "Yiii", "Zanb", "Zinh", "Zmth", "Zsye", "Zsym", "Zxxx", "Zyyy", "Zzzz",
+];
+
+// ISO 3166-1 alpha-2 region codes, including the exceptional reservations used by CLDR and the macro regions `EU`,
+// `EZ`, `UN`, `QO` and `ZZ`, but excluding the private use ranges `QM`..`QZ` and `XA`..`XZ` (`XK` is included as
+// it is used for Kosovo). Deprecated region codes are absent, as they are replaced before validation. Must remain
+// sorted.
+#[cfg(feature = "icu_compiled_data")]
+#[rustfmt::skip]
+const REGIONS: &[&str] = &[
+    "AA", "AC", "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT",
+    "AU", "AW", "AX", "AZ", "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL",
+    "BM", "BN", "BO", "BQ", "BR", "BS", "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD",
+    "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN", "CO", "CP", "CQ", "CR", "CU", "CV",
+    "CW", "CX", "CY", "CZ", "DE", "DG", "DJ", "DK", "DM", "DO", "DZ", "EA", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "EU", "EZ", "FI", "FJ", "FK", "FM", "FO", "FR", "GA",
+    "GB", "GD", "GE", "GF", "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS",
+    "GT", "GU", "GW", "GY", "HK", "HM", "HN", "HR", "HT", "HU", "IC", "ID", "IE", "IL",
+    "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM", "JO", "JP", "KE", "KG", "KH",
+    "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC", "LI", "LK", "LR",
+    "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK", "ML",
+    "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ",
+    "NA", "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA",
+    "PE", "PF", "PG", "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA",
+    "QO", "RE", "RO", "RS", "RU", "RW", "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI",
+    "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS", "ST", "SV", "SX", "SY", "SZ", "TA",
+    "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO", "TR", "TT", "TV",
+    "TW", "TZ", "UA", "UG", "UM", "UN", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "XK", "YE", "YT", "ZA", "ZM", "ZW", "ZZ",
+];
+
+#[cfg(feature = "icu_compiled_data")]
+#[cfg(not(feature = "icu_extended"))]
+fn canonical_string(locale: &IcuLocale) -> String {
+    locale.id.to_string()
+}
+
+#[cfg(feature = "icu_compiled_data")]
+#[cfg(feature = "icu_extended")]
+fn canonical_string(locale: &IcuLocale) -> String {
+    locale.to_string()
+}
+
 #[cfg(test)]
+#[allow(clippy::iter_count)]
 mod tests {
     use super::*;
     use std::error::Error;
@@ -459,6 +724,74 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "icu_compiled_data")]
+    #[test]
+    fn maximize_minimize() -> Result<(), Box<dyn Error>> {
+        let registry = LanguageTagRegistry::new();
+        let tag = registry.tag("zh-TW")?;
+        let maximised = registry.maximize(&tag)?;
+        assert_eq!(maximised.as_str(), "zh-Hant-TW", "Check maximised.");
+        assert!(
+            RefCount::ptr_eq(&maximised, &registry.maximize(&tag)?),
+            "Check cached."
+        );
+        let minimised = registry.minimize(&registry.tag("zh-Hant")?)?;
+        assert!(RefCount::ptr_eq(&minimised, &tag), "Check registered.");
+        let tag = registry.tag("und")?;
+        assert_eq!(
+            registry.maximize(&tag)?.as_str(),
+            "en-Latn-US",
+            "Check undetermined."
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "icu_compiled_data")]
+    #[test]
+    fn validity() -> Result<(), Box<dyn Error>> {
+        let registry = LanguageTagRegistry::new();
+        for (tag, expected) in [
+            ("iw", "he"),
+            ("in-ID", "id-ID"),
+            ("ru-SU", "ru-RU"),
+            ("my-BU", "my-MM"),
+            ("sr-Latn-RS", "sr-Latn-RS"),
+            ("es-419", "es-419"),
+            ("qaa-Qaaa-QM", "qaa-Qaaa-QM"),
+            ("und-Zyyy", "und-Zyyy"),
+            ("de-CH-1996", "de-CH-1996"),
+            ("kpe-Kpel-LR", "kpe-Kpel-LR"),
+            ("en-Dsrt-US", "en-Dsrt-US"),
+            ("tlh", "tlh"),
+            ("sux-Xsux", "sux-Xsux"),
+            ("mul", "mul"),
+            ("zxx", "zxx"),
+            ("sla", "sla"),
+        ] {
+            assert_eq!(registry.tag(tag)?.as_str(), expected, "Check ‘{}’.", tag);
+        }
+        assert_eq!(registry.list_deprecated().len(), 4, "Check aliases.");
+        assert!(
+            LANGUAGES.windows(2).all(|pair| pair[0] < pair[1]),
+            "Check sorted languages."
+        );
+        assert!(
+            SCRIPTS.windows(2).all(|pair| pair[0] < pair[1]),
+            "Check sorted scripts."
+        );
+        assert!(
+            REGIONS.windows(2).all(|pair| pair[0] < pair[1]),
+            "Check sorted regions."
+        );
+        for tag in ["xx-YY", "en-Xyzw", "en-YY", "qz", "zzz", "xyz-Latn"] {
+            match registry.tag(tag) {
+                Err(RegistryError::Invalid(_)) => {}
+                _ => panic!("Must fail as ‘{}’ is not valid.", tag),
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn invalid_tag() -> Result<(), Box<dyn Error>> {
         let registry = LanguageTagRegistry::new();
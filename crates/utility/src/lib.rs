@@ -3,10 +3,14 @@
 
 //! Welcome to the **`i18n_utility`** crate of the *Internationalisation* (i18n) project.
 //!
-//! This crate consists of five modules:
+//! This crate consists of seven modules:
+//!
+//! * [`accept_language`]: Parser of the HTTP `Accept-Language` header,
 //!
 //! * [`error`]: Contains the error enum for the language registry,
 //!
+//! * [`json`]: Escaping of JSON strings for the reports of the other crates,
+//!
 //! * [`language`]: Registry of language tags with ICU4X's [`LanguageIdentifier`] or [`Locale`] instances,
 //!
 //! * [`tagged_string`]: Simple tagged string type,
@@ -19,13 +23,33 @@
 //!
 //! Available features for `i18n_utility` crate:
 //!
+//! * `icu_compiled_data`: Allow for the internal data of the ICU4X `LocaleExpander`, used by the
+//!   `LanguageTagRegistry` methods `maximize()` and `minimize()`.
+//!
 //! * `icu_extended`: Use the more detailed ICU information structs, types, and methods.
 //!
 //! * `sync`: Allow for rust's concurrency capabilities to be used. Use of [`Arc`] and [`Mutex`] instead [`Rc`] and
-//!   [`RefCell`].
+//!   [`RefCell`]. The [`RefCount`] alias is either `Rc` or `Arc`, for the crates that pass reference counted values to
+//!   the other crates of the project without enabling their `sync` features.
 //!
 //! # Modules
 //!
+//! ## `accept_language`: Parser of the HTTP `Accept-Language` header.
+//!
+//! Parses the `Accept-Language` header, handling the quality values (`q` weights), the wildcard and malformed entries,
+//! and returns the language tags canonicalised through the `LanguageTagRegistry`, ordered by their weights. The
+//! parser can be strict, failing on any malformed entry, or lenient, skipping the malformed entries.
+//!
+//! ### Examples
+//!
+//! ```
+//! use i18n_utility::{accept_language, LanguageTagRegistry};
+//!
+//! let registry = LanguageTagRegistry::new();
+//! let tags = accept_language( &registry, "de;q=0.5, en-ZA, en;q=0.8", false ).expect( "Failed to parse header." );
+//! assert_eq!( tags[ 0 ].as_str(), "en-ZA", "Check the preferred language tag." );
+//! ```
+//!
 //! ## `language`: Registry for holding ICU4X `Locale` objects.
 //!
 //! Registry for holding the validated [BCP 47 Language Tag] strings, and optionally holding the
@@ -92,6 +116,8 @@ use std::rc::Rc;
 #[cfg(doc)]
 use std::cell::RefCell;
 
+pub mod accept_language;
+pub use accept_language::*;
 pub mod tagged_string;
 pub use tagged_string::*;
 pub mod language;
@@ -104,3 +130,21 @@ pub mod traits;
 pub use traits::*;
 pub mod error;
 pub use error::*;
+pub mod json;
+pub use json::*;
+
+#[cfg(feature = "icu_compiled_data")]
+mod languages;
+
+/// The reference counting pointer of the project's crates, being [`Rc`], or [`Arc`] with the feature `sync`. As the
+/// `sync` feature of every crate of the project enables the `sync` feature of this crate, the alias always matches the
+/// pointer expected by the other crates.
+#[cfg(not(feature = "sync"))]
+pub use std::rc::Rc as RefCount;
+
+/// The reference counting pointer of the project's crates, being [`Rc`], or [`Arc`] with the feature `sync`. As the
+/// `sync` feature of every crate of the project enables the `sync` feature of this crate, the alias always matches the
+/// pointer expected by the other crates.
+#[cfg(feature = "sync")]
+#[cfg(target_has_atomic = "ptr")]
+pub use std::sync::Arc as RefCount;
@@ -5,6 +5,7 @@ use crate::{LanguageTag, TaggedString};
 use fixed_decimal::FixedDecimal;
 use icu_calendar::{types::Time, Date, DateTime, Iso};
 use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
 
 #[cfg(not(feature = "sync"))]
 use std::rc::Rc as RefCount;
@@ -28,6 +29,75 @@ pub struct LocalisationData {
     pub values: Option<HashMap<String, PlaceholderValue>>,
 }
 
+/// A date and time of a time zone, consisting of the local date and time, and the time zone details.
+///
+/// `date_time`: Is the local date and time (wall clock) in the time zone,
+///
+/// `offset`: Is the optional UTC offset in seconds (east of UTC is positive) applicable to the `date_time`,
+///
+/// `time_zone`: Is the optional IANA time zone identifier, such as `Africa/Johannesburg`,
+///
+/// `daylight`: Is the optional indicator of whether daylight saving time is in effect.
+///
+/// When the IANA time zone is supplied, the missing UTC offset and daylight saving time indicator are obtained from
+/// the time zone database bundled with the `i18n_localiser` crate when formatting, thus works offline. Without the
+/// `time_zone` only the supplied offset can be displayed.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_utility::ZonedDateTime;
+/// use icu_calendar::DateTime;
+///
+/// let date_time = DateTime::try_new_iso_datetime(2024, 7, 5, 17, 7, 0).unwrap();
+/// let zoned = ZonedDateTime::from_time_zone(date_time, "Europe/Berlin");
+/// assert_eq!(zoned.offset, None, "Check offset.");
+/// let zoned = ZonedDateTime::new(date_time, 7200).with_daylight(true);
+/// assert_eq!(zoned.offset, Some(7200), "Check offset.");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ZonedDateTime {
+    pub date_time: DateTime<Iso>,
+    pub offset: Option<i32>,
+    pub time_zone: Option<String>,
+    pub daylight: Option<bool>,
+}
+
+impl ZonedDateTime {
+    /// Creates a zoned date and time from the local date and time, and the UTC offset in seconds.
+    pub fn new(date_time: DateTime<Iso>, offset: i32) -> Self {
+        ZonedDateTime {
+            date_time,
+            offset: Some(offset),
+            time_zone: None,
+            daylight: None,
+        }
+    }
+
+    /// Creates a zoned date and time from the local date and time, and the IANA time zone identifier, where the UTC
+    /// offset and daylight saving time indicator are obtained from the bundled time zone database when formatting.
+    pub fn from_time_zone<T: Into<String>>(date_time: DateTime<Iso>, time_zone: T) -> Self {
+        ZonedDateTime {
+            date_time,
+            offset: None,
+            time_zone: Some(time_zone.into()),
+            daylight: None,
+        }
+    }
+
+    /// Sets the IANA time zone identifier.
+    pub fn with_time_zone<T: Into<String>>(mut self, time_zone: T) -> Self {
+        self.time_zone = Some(time_zone.into());
+        self
+    }
+
+    /// Sets whether daylight saving time is in effect.
+    pub fn with_daylight(mut self, daylight: bool) -> Self {
+        self.daylight = Some(daylight);
+        self
+    }
+}
+
 /// An enum consists of a selection of Rust primitives, ICU4X types, and [`TaggedString`] for messages.
 /// The following are types are available:
 ///
@@ -45,6 +115,8 @@ pub struct LocalisationData {
 ///
 /// * DateTime( [`DateTime`]`<`[`Iso`]`>` ),
 ///
+/// * ZonedDateTime( [`ZonedDateTime`] ),
+///
 /// * Date( [`Date`]`<`[`Iso`]`>` ),
 ///
 /// * Time( [`Time`] ),
@@ -61,6 +133,189 @@ pub enum PlaceholderValue {
     LocalisationData(LocalisationData),
     FixedDecimal(FixedDecimal),
     DateTime(DateTime<Iso>),
+    ZonedDateTime(ZonedDateTime),
     Date(Date<Iso>),
     Time(Time),
 }
+
+/// Marker of the text types converted into a [`PlaceholderValue`], for placeholders having no keyword or the `select`
+/// keyword.
+///
+/// Used by the compile time checked `l10n!` macro, so that values of the wrong type are rejected by the compiler. The
+/// conversion is done by the `From` implementation.
+pub trait StringPlaceholder: Into<PlaceholderValue> {}
+
+impl StringPlaceholder for &str {}
+impl StringPlaceholder for String {}
+impl StringPlaceholder for &String {}
+impl StringPlaceholder for char {}
+impl StringPlaceholder for TaggedString {}
+
+/// Marker of the numeric types converted into a [`PlaceholderValue`], for placeholders having the `decimal`, `plural`
+/// or `ordinal` keyword.
+///
+/// Used by the compile time checked `l10n!` macro, so that values of the wrong type are rejected by the compiler. The
+/// conversion is done by the `From` implementation.
+pub trait NumberPlaceholder: Into<PlaceholderValue> {}
+
+macro_rules! from_number {
+    ( $variant: ident, $target: ty, $( $source: ty ),* ) => {
+        $(
+            impl From<$source> for PlaceholderValue {
+                fn from(value: $source) -> Self {
+                    PlaceholderValue::$variant(value as $target)
+                }
+            }
+
+            impl NumberPlaceholder for $source {}
+        )*
+    };
+}
+
+from_number!(Integer, i128, i8, i16, i32, i64, i128, isize);
+from_number!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+from_number!(Float, f64, f32, f64);
+
+impl NumberPlaceholder for FixedDecimal {}
+
+/// Marker of the ICU4X date and time types converted into a [`PlaceholderValue`], for placeholders having the
+/// `date_time` keyword.
+///
+/// Used by the compile time checked `l10n!` macro, so that values of the wrong type are rejected by the compiler. The
+/// conversion is done by the `From` implementation.
+pub trait DateTimePlaceholder: Into<PlaceholderValue> {}
+
+impl DateTimePlaceholder for DateTime<Iso> {}
+impl DateTimePlaceholder for ZonedDateTime {}
+impl DateTimePlaceholder for Date<Iso> {}
+impl DateTimePlaceholder for Time {}
+
+impl From<&str> for PlaceholderValue {
+    fn from(value: &str) -> Self {
+        PlaceholderValue::String(value.to_string())
+    }
+}
+
+impl From<String> for PlaceholderValue {
+    fn from(value: String) -> Self {
+        PlaceholderValue::String(value)
+    }
+}
+
+impl From<&String> for PlaceholderValue {
+    fn from(value: &String) -> Self {
+        PlaceholderValue::String(value.clone())
+    }
+}
+
+impl From<char> for PlaceholderValue {
+    fn from(value: char) -> Self {
+        PlaceholderValue::String(value.to_string())
+    }
+}
+
+impl From<TaggedString> for PlaceholderValue {
+    fn from(value: TaggedString) -> Self {
+        PlaceholderValue::TaggedString(value)
+    }
+}
+
+impl From<LocalisationData> for PlaceholderValue {
+    fn from(value: LocalisationData) -> Self {
+        PlaceholderValue::LocalisationData(value)
+    }
+}
+
+impl From<FixedDecimal> for PlaceholderValue {
+    fn from(value: FixedDecimal) -> Self {
+        PlaceholderValue::FixedDecimal(value)
+    }
+}
+
+impl From<DateTime<Iso>> for PlaceholderValue {
+    fn from(value: DateTime<Iso>) -> Self {
+        PlaceholderValue::DateTime(value)
+    }
+}
+
+impl From<ZonedDateTime> for PlaceholderValue {
+    fn from(value: ZonedDateTime) -> Self {
+        PlaceholderValue::ZonedDateTime(value)
+    }
+}
+
+impl From<Date<Iso>> for PlaceholderValue {
+    fn from(value: Date<Iso>) -> Self {
+        PlaceholderValue::Date(value)
+    }
+}
+
+impl From<Time> for PlaceholderValue {
+    fn from(value: Time) -> Self {
+        PlaceholderValue::Time(value)
+    }
+}
+
+/// A builder of the placeholder values map, where the values are converted into [`PlaceholderValue`] using the
+/// `From` implementations.
+///
+/// `Values` dereferences to `HashMap<String, PlaceholderValue>`, thus `&Values` can be passed to the `Localiser`
+/// methods `format()` and `format_with_defaults()`.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_utility::{PlaceholderValue, Values};
+///
+/// let values = Values::new().with( "count", 3 ).with( "name", "Ann" );
+/// assert_eq!( values.len(), 2, "Check number of values." );
+/// assert!(
+///     matches!( values.get( "count" ), Some( PlaceholderValue::Integer( 3 ) ) ),
+///     "Check integer conversion."
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Values {
+    values: HashMap<String, PlaceholderValue>,
+}
+
+impl Values {
+    /// Creates an empty placeholder values map.
+    pub fn new() -> Self {
+        Values {
+            values: HashMap::<String, PlaceholderValue>::new(),
+        }
+    }
+
+    /// Adds the value for the placeholder `name`, replacing any existing value.
+    pub fn with<T: Into<PlaceholderValue>>(mut self, name: &str, value: T) -> Self {
+        self.values.insert(name.to_string(), value.into());
+        self
+    }
+}
+
+impl Deref for Values {
+    type Target = HashMap<String, PlaceholderValue>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.values
+    }
+}
+
+impl DerefMut for Values {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.values
+    }
+}
+
+impl From<HashMap<String, PlaceholderValue>> for Values {
+    fn from(values: HashMap<String, PlaceholderValue>) -> Self {
+        Values { values }
+    }
+}
+
+impl From<Values> for HashMap<String, PlaceholderValue> {
+    fn from(values: Values) -> Self {
+        values.values
+    }
+}